@@ -0,0 +1,148 @@
+//! 강요 PIN (미끼 금고)
+//!
+//! 보안 헤더에는 항상 두 번째 키 자리(`spare`)가 있습니다. 강요 PIN을 정하지
+//! 않았으면 같은 길이의 랜덤 바이트로 채워 두므로 `security.json`만 보고는
+//! 미끼 금고가 있는지 알 수 없습니다. 강요 PIN으로 풀면 따로 만든 미끼 데이터
+//! 키가 나오고, 그 키로 열리지 않는 암호화 노트는 없는 것처럼 숨깁니다.
+//!
+//! 강요 PIN 자리는 키파일 없이 PIN만으로 유도합니다 (빼앗긴 키파일이 없어도,
+//! 아무 키파일을 내줘도 열리도록).
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use super::{base64_decode, base64_encode, CryptoManager, EncryptedHeader, KEY_SIZE, NONCE_SIZE};
+use crate::error::Result;
+
+/// AEAD 태그 크기 (16 bytes)
+const TAG_SIZE: usize = 16;
+
+/// 봉한 내용: 데이터 키 + 플래그 1바이트
+const PAYLOAD_SIZE: usize = KEY_SIZE + 1;
+
+/// 봉한 자리 크기 (빈 자리도 같은 길이로 채움)
+const SEALED_SIZE: usize = NONCE_SIZE + PAYLOAD_SIZE + TAG_SIZE;
+
+/// 플래그: 강요 PIN을 쓰면 진짜 금고의 키를 없앰
+const DESTROY_REAL: u8 = 1;
+
+/// 두 번째 키 자리
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpareSlot {
+    /// 솔트 (hex)
+    pub salt: String,
+    /// 강요 PIN 키로 봉한 미끼 데이터 키 + 플래그 (hex, 빈 자리면 랜덤)
+    pub sealed: String,
+}
+
+impl SpareSlot {
+    /// 빈 자리 (봉한 자리와 구별되지 않는 랜덤 바이트)
+    pub fn filler() -> Self {
+        let mut sealed = [0u8; SEALED_SIZE];
+        rand::thread_rng().fill_bytes(&mut sealed);
+        Self {
+            salt: base64_encode(&CryptoManager::generate_salt()),
+            sealed: base64_encode(&sealed),
+        }
+    }
+
+    /// 미끼 데이터 키를 강요 PIN으로 봉함
+    pub fn seal(decoy_key: &CryptoManager, pin: &str, destroy_real: bool) -> Result<Self> {
+        let salt = CryptoManager::generate_salt();
+        let kek = CryptoManager::from_pin(pin, &salt)?;
+
+        let mut payload = [0u8; PAYLOAD_SIZE];
+        payload[..KEY_SIZE].copy_from_slice(&decoy_key.key);
+        payload[KEY_SIZE] = if destroy_real { DESTROY_REAL } else { 0 };
+        let sealed = kek.encrypt(&payload);
+        payload.zeroize();
+
+        Ok(Self {
+            salt: base64_encode(&salt),
+            sealed: base64_encode(&sealed?),
+        })
+    }
+
+    /// 강요 PIN으로 열기, 반환: (미끼 데이터 키, 진짜 금고 파기 여부)
+    ///
+    /// 빈 자리이거나 PIN이 틀리면 None (둘은 구별되지 않음).
+    pub fn open(&self, pin: &str) -> Result<Option<(CryptoManager, bool)>> {
+        let kek = CryptoManager::from_pin(pin, &base64_decode(&self.salt)?)?;
+        let Ok(mut payload) = kek.decrypt(&base64_decode(&self.sealed)?) else {
+            return Ok(None);
+        };
+        if payload.len() != PAYLOAD_SIZE {
+            return Ok(None);
+        }
+
+        let mut key = [0u8; KEY_SIZE];
+        key.copy_from_slice(&payload[..KEY_SIZE]);
+        let destroy_real = payload[KEY_SIZE] & DESTROY_REAL != 0;
+        payload.zeroize();
+        Ok(Some((CryptoManager { key }, destroy_real)))
+    }
+}
+
+impl EncryptedHeader {
+    /// 진짜 금고 자리를 같은 길이의 랜덤으로 덮어씀 (어떤 PIN으로도 열리지 않음)
+    pub(super) fn scramble(&mut self) -> Result<()> {
+        self.salt = random_hex_like(&self.salt)?;
+        self.verify_data = random_hex_like(&self.verify_data)?;
+        if let Some(wrapped) = &self.wrapped_key {
+            self.wrapped_key = Some(random_hex_like(wrapped)?);
+        }
+        Ok(())
+    }
+}
+
+/// 같은 길이의 랜덤 hex
+fn random_hex_like(hex: &str) -> Result<String> {
+    let mut bytes = base64_decode(hex)?;
+    rand::thread_rng().fill_bytes(&mut bytes);
+    Ok(base64_encode(&bytes))
+}
+
+/// 잠금 해제 결과
+pub struct Unlocked {
+    /// 데이터 키 (미끼 금고면 미끼 데이터 키)
+    pub crypto: CryptoManager,
+    /// 강요 PIN으로 열림
+    pub decoy: bool,
+    /// 강요 PIN 설정에 따라 진짜 금고를 파기해야 함
+    pub destroy_real: bool,
+}
+
+impl Unlocked {
+    /// 진짜 금고
+    pub fn vault(crypto: CryptoManager) -> Self {
+        Self {
+            crypto,
+            decoy: false,
+            destroy_real: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spare_slot_looks_like_filler() {
+        let decoy_key = CryptoManager::generate_data_key();
+        let sealed = SpareSlot::seal(&decoy_key, "654321", true).unwrap();
+        let filler = SpareSlot::filler();
+        assert_eq!(sealed.salt.len(), filler.salt.len());
+        assert_eq!(sealed.sealed.len(), filler.sealed.len());
+
+        let (opened, destroy_real) = sealed.open("654321").unwrap().unwrap();
+        assert_eq!(opened.key_id(), decoy_key.key_id());
+        assert!(destroy_real);
+        assert!(sealed.open("123456").unwrap().is_none());
+        assert!(filler.open("654321").unwrap().is_none());
+
+        let keep = SpareSlot::seal(&decoy_key, "654321", false).unwrap();
+        assert!(!keep.open("654321").unwrap().unwrap().1);
+    }
+}
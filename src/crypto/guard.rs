@@ -0,0 +1,239 @@
+//! PIN 시도 제한과 감사 로그
+//!
+//! 연속 실패 횟수는 `pin_attempts.json`에 저장해 재시작해도 유지합니다.
+//! 무료 시도를 넘기면 실패할 때마다 대기 시간이 두 배로 늘어납니다.
+//! 모든 시도는 `security_audit.jsonl`에 한 줄씩 남깁니다.
+//!
+//! 두 파일 모두 백업 대상이 아닙니다 (복원으로 카운터가 되돌아가지 않도록).
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::error::Result;
+
+/// 대기 없이 틀릴 수 있는 횟수
+pub const FREE_ATTEMPTS: u32 = 3;
+
+/// 삭제 정책의 최소 횟수 (오타 몇 번에 지워지지 않도록)
+pub const MIN_WIPE_AFTER: u32 = FREE_ATTEMPTS;
+
+/// 첫 대기 시간 (초), 이후 실패마다 두 배
+const BASE_DELAY_SECS: u64 = 30;
+
+/// 최대 대기 시간 (하루)
+const MAX_DELAY_SECS: u64 = 24 * 60 * 60;
+
+/// 감사 로그 최대 크기 (넘으면 `.1`로 넘기고 새로 시작)
+const MAX_AUDIT_BYTES: u64 = 1024 * 1024;
+
+const ATTEMPTS_FILE: &str = "pin_attempts.json";
+const AUDIT_FILE: &str = "security_audit.jsonl";
+
+/// 저장되는 시도 상태
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Attempts {
+    /// 연속 실패 횟수 (성공하면 0)
+    failures: u32,
+    last_failure: Option<DateTime<Utc>>,
+}
+
+/// 감사 로그 한 줄
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub time: DateTime<Utc>,
+    /// `failed`, `blocked`, `success`, `wipe`, `policy`
+    pub event: String,
+    /// 어떤 요청이었는지 (`unlock`, `set-pin`, `recover` 등)
+    pub action: String,
+    /// 그 시점의 연속 실패 횟수
+    pub failures: u32,
+}
+
+/// PIN 시도 제한기
+pub struct PinGuard {
+    attempts_path: PathBuf,
+    audit_path: PathBuf,
+    attempts: Attempts,
+}
+
+impl PinGuard {
+    /// 데이터 디렉토리에서 열기 (파일이 없으면 실패 0회)
+    pub fn open(data_dir: &Path) -> Result<Self> {
+        let attempts_path = data_dir.join(ATTEMPTS_FILE);
+        let attempts = if attempts_path.exists() {
+            let content = fs::read_to_string(&attempts_path)?;
+            serde_json::from_str(&content)?
+        } else {
+            Attempts::default()
+        };
+
+        Ok(Self {
+            attempts_path,
+            audit_path: data_dir.join(AUDIT_FILE),
+            attempts,
+        })
+    }
+
+    /// 연속 실패 횟수
+    pub fn failures(&self) -> u32 {
+        self.attempts.failures
+    }
+
+    /// 실패 횟수에 따른 대기 시간 (초)
+    pub fn delay_secs(failures: u32) -> u64 {
+        if failures < FREE_ATTEMPTS {
+            return 0;
+        }
+        let doublings = (failures - FREE_ATTEMPTS).min(32);
+        BASE_DELAY_SECS
+            .saturating_mul(1u64 << doublings)
+            .min(MAX_DELAY_SECS)
+    }
+
+    /// 다음 시도까지 남은 시간 (초, 지금 시도할 수 있으면 None)
+    pub fn retry_after(&self, now: DateTime<Utc>) -> Option<u64> {
+        let last = self.attempts.last_failure?;
+        let delay = Self::delay_secs(self.attempts.failures) as i64;
+        let remaining = (last + chrono::Duration::seconds(delay) - now).num_seconds();
+        (remaining > 0).then_some(remaining as u64)
+    }
+
+    /// 실패 기록, 반환: 연속 실패 횟수
+    pub fn record_failure(&mut self, action: &str, now: DateTime<Utc>) -> Result<u32> {
+        self.attempts.failures = self.attempts.failures.saturating_add(1);
+        self.attempts.last_failure = Some(now);
+        self.save()?;
+        self.audit("failed", action)?;
+        tracing::warn!(
+            "PIN 검증 실패 ({}): 연속 {}회",
+            action,
+            self.attempts.failures
+        );
+        Ok(self.attempts.failures)
+    }
+
+    /// 대기 중에 들어온 시도 (검증하지 않고 거절, 횟수는 늘리지 않음)
+    pub fn record_blocked(&self, action: &str) -> Result<()> {
+        self.audit("blocked", action)
+    }
+
+    /// 성공 기록 (카운터 초기화)
+    pub fn record_success(&mut self, action: &str) -> Result<()> {
+        self.audit("success", action)?;
+        self.reset()
+    }
+
+    /// 기타 보안 이벤트 기록 (금고 삭제, 정책 변경 등)
+    pub fn record_event(&self, event: &str, action: &str) -> Result<()> {
+        self.audit(event, action)
+    }
+
+    /// 카운터 초기화
+    pub fn reset(&mut self) -> Result<()> {
+        if self.attempts.failures == 0 && self.attempts.last_failure.is_none() {
+            return Ok(());
+        }
+        self.attempts = Attempts::default();
+        self.save()
+    }
+
+    /// 최근 감사 로그 (최신순)
+    pub fn audit_log(&self, limit: usize) -> Result<Vec<AuditEvent>> {
+        if !self.audit_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let reader = BufReader::new(File::open(&self.audit_path)?);
+        let mut events: Vec<AuditEvent> = reader
+            .lines()
+            .map_while(|line| line.ok())
+            .filter_map(|line| serde_json::from_str(&line).ok())
+            .collect();
+        events.reverse();
+        events.truncate(limit);
+        Ok(events)
+    }
+
+    /// 시도 상태 저장 (fsync, 끊겨도 카운터가 줄지 않도록)
+    fn save(&self) -> Result<()> {
+        let content = serde_json::to_string_pretty(&self.attempts)?;
+        let temp = self.attempts_path.with_extension("tmp");
+        let mut file = File::create(&temp)?;
+        file.write_all(content.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&temp, &self.attempts_path)?;
+        Ok(())
+    }
+
+    /// 감사 로그에 한 줄 추가
+    fn audit(&self, event: &str, action: &str) -> Result<()> {
+        let size = fs::metadata(&self.audit_path).map(|m| m.len()).unwrap_or(0);
+        if size > MAX_AUDIT_BYTES {
+            fs::rename(&self.audit_path, self.audit_path.with_extension("jsonl.1"))?;
+        }
+
+        let entry = AuditEvent {
+            time: Utc::now(),
+            event: event.to_string(),
+            action: action.to_string(),
+            failures: self.attempts.failures,
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.audit_path)?;
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_backoff_survives_restart() {
+        let tmp = TempDir::new().unwrap();
+        let now = Utc::now();
+        {
+            let mut guard = PinGuard::open(tmp.path()).unwrap();
+            for _ in 0..FREE_ATTEMPTS - 1 {
+                guard.record_failure("unlock", now).unwrap();
+            }
+            assert_eq!(guard.retry_after(now), None);
+            guard.record_failure("unlock", now).unwrap();
+            assert_eq!(guard.retry_after(now), Some(BASE_DELAY_SECS));
+        }
+
+        // 재시작해도 유지
+        let mut guard = PinGuard::open(tmp.path()).unwrap();
+        assert_eq!(guard.failures(), FREE_ATTEMPTS);
+        assert!(guard.retry_after(now).is_some());
+        let later = now + chrono::Duration::seconds(BASE_DELAY_SECS as i64);
+        assert_eq!(guard.retry_after(later), None);
+
+        // 실패할수록 두 배, 상한 있음
+        guard.record_failure("unlock", later).unwrap();
+        assert_eq!(guard.retry_after(later), Some(BASE_DELAY_SECS * 2));
+        assert_eq!(PinGuard::delay_secs(100), MAX_DELAY_SECS);
+
+        guard.record_success("unlock").unwrap();
+        assert_eq!(guard.failures(), 0);
+        assert_eq!(guard.retry_after(later), None);
+
+        let log = guard.audit_log(10).unwrap();
+        assert_eq!(log.len(), FREE_ATTEMPTS as usize + 2);
+        assert_eq!(log[0].event, "success");
+        assert_eq!(log[1].event, "failed");
+        assert_eq!(log[1].failures, FREE_ATTEMPTS + 1);
+    }
+}
@@ -1,19 +1,38 @@
 //! 암호화 모듈
 //!
 //! XChaCha20-Poly1305 + Argon2id
+//!
+//! 봉투 암호화: 노트/백업은 랜덤 데이터 키로 암호화하고, 데이터 키는
+//! PIN(+키파일)에서 유도한 키로 감싸 `security.json`에 둡니다. PIN을 바꾸면
+//! 데이터 키를 다시 감싸기만 하므로 기존 데이터를 그대로 열 수 있습니다.
 
 use argon2::{Algorithm, Argon2, Params, Version};
 use chacha20poly1305::{aead::Aead, KeyInit, XChaCha20Poly1305, XNonce};
 use rand::RngCore;
+use zeroize::Zeroize;
 
 use crate::error::{LazarusError, Result};
 
+pub mod duress;
+pub mod guard;
+pub mod recovery;
+
+pub use duress::{SpareSlot, Unlocked};
+pub use guard::{AuditEvent, PinGuard};
+pub use recovery::RecoveryHeader;
+
 /// 솔트 크기 (16 bytes)
 const SALT_SIZE: usize = 16;
 
 /// Nonce 크기 (24 bytes for XChaCha20)
 const NONCE_SIZE: usize = 24;
 
+/// 키 크기 (32 bytes)
+const KEY_SIZE: usize = 32;
+
+/// 기본 자동 잠금 유휴 시간 (분)
+pub const DEFAULT_IDLE_LOCK_MINS: u32 = 15;
+
 /// 암호화 매니저
 #[derive(Clone)]
 pub struct CryptoManager {
@@ -21,6 +40,13 @@ pub struct CryptoManager {
     key: [u8; 32],
 }
 
+impl Drop for CryptoManager {
+    /// 잠글 때 키가 메모리에 남지 않도록 0으로 덮어씀
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
 impl CryptoManager {
     /// PIN에서 암호화 키 유도
     pub fn from_pin(pin: &str, salt: &[u8]) -> Result<Self> {
@@ -35,6 +61,27 @@ impl CryptoManager {
         Ok(Self { key })
     }
 
+    /// 새 랜덤 데이터 키
+    pub fn generate_data_key() -> Self {
+        let mut key = [0u8; KEY_SIZE];
+        rand::thread_rng().fill_bytes(&mut key);
+        Self { key }
+    }
+
+    /// 이 키를 다른 키(PIN 키)로 감싸기
+    fn wrap_with(&self, kek: &CryptoManager) -> Result<Vec<u8>> {
+        kek.encrypt(&self.key)
+    }
+
+    /// 감싼 키 풀기 (PIN 키가 틀리면 Decryption)
+    fn unwrap_with(kek: &CryptoManager, wrapped: &[u8]) -> Result<Self> {
+        let key = kek
+            .decrypt(wrapped)?
+            .try_into()
+            .map_err(|_| LazarusError::Decryption)?;
+        Ok(Self { key })
+    }
+
     /// 새 솔트 생성
     pub fn generate_salt() -> [u8; SALT_SIZE] {
         let mut salt = [0u8; SALT_SIZE];
@@ -88,6 +135,36 @@ impl CryptoManager {
         Ok(plaintext)
     }
 
+    /// 키 식별자 (키에서 유도한 짧은 해시, 키 자체는 드러나지 않음)
+    ///
+    /// 어떤 키로 암호화된 데이터인지 구분할 때 사용합니다.
+    pub fn key_id(&self) -> String {
+        use sha2::{Digest, Sha256};
+        let mut hasher = Sha256::new();
+        hasher.update(b"lazarus-key-id");
+        hasher.update(self.key);
+        format!("{:x}", hasher.finalize())[..16].to_string()
+    }
+
+    /// 내용 해시용 HMAC-SHA256 (데이터 키에서 HKDF로 유도한 별도 키)
+    ///
+    /// 암호화 백업의 청크 이름과 파일 해시에 씁니다. 평문 SHA-256과 달리 키가
+    /// 없으면 백업에 어떤 파일이 들어 있는지 맞춰볼 수 없습니다.
+    pub fn content_mac(&self) -> Result<hkdf::hmac::Hmac<sha2::Sha256>> {
+        use hkdf::hmac::{Hmac, Mac};
+        use hkdf::Hkdf;
+        use sha2::Sha256;
+
+        let mut mac_key = [0u8; KEY_SIZE];
+        Hkdf::<Sha256>::new(None, &self.key)
+            .expand(b"lazarus-content-mac-v1", &mut mac_key)
+            .map_err(|_| LazarusError::Encryption)?;
+        let mac =
+            <Hmac<Sha256> as Mac>::new_from_slice(&mac_key).map_err(|_| LazarusError::Encryption);
+        mac_key.zeroize();
+        mac
+    }
+
     /// PIN 검증 (테스트용 데이터로 확인)
     pub fn verify_pin(&self, test_data: &[u8], expected: &[u8]) -> bool {
         match self.decrypt(test_data) {
@@ -107,6 +184,16 @@ pub struct EncryptedHeader {
     /// 키파일 사용 여부
     #[serde(default)]
     pub keyfile_enabled: bool,
+    /// PIN 키로 감싼 데이터 키 (hex)
+    ///
+    /// 없으면 예전 형식: PIN 키로 데이터를 바로 암호화 (잠금 해제 시 전환)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrapped_key: Option<String>,
+    /// 두 번째 키 자리 (강요 PIN, 없으면 랜덤으로 채움)
+    ///
+    /// 예전 파일에는 없으며 다음 잠금 해제 때 채워집니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spare: Option<SpareSlot>,
 }
 
 impl EncryptedHeader {
@@ -115,24 +202,32 @@ impl EncryptedHeader {
         Self::new_with_keyfile(pin, None)
     }
 
-    /// 새 헤더 생성 (PIN + 선택적 키파일)
+    /// 새 헤더 생성 (PIN + 선택적 키파일, 새 데이터 키)
     pub fn new_with_keyfile(pin: &str, keyfile: Option<&[u8]>) -> Result<Self> {
-        let salt = CryptoManager::generate_salt();
+        Self::wrap(&CryptoManager::generate_data_key(), pin, keyfile)
+    }
 
-        let crypto = match keyfile {
-            Some(kf) => CryptoManager::from_pin_and_keyfile(pin, &salt, kf)?,
-            None => CryptoManager::from_pin(pin, &salt)?,
-        };
+    /// 데이터 키를 PIN(+키파일)로 감싼 헤더 생성
+    pub fn wrap(data_key: &CryptoManager, pin: &str, keyfile: Option<&[u8]>) -> Result<Self> {
+        let salt = CryptoManager::generate_salt();
+        let kek = derive_kek(pin, &salt, keyfile)?;
 
         let test_data = b"LAZARUS_PIN_OK";
-        let verify_data = crypto.encrypt(test_data)?;
+        let verify_data = kek.encrypt(test_data)?;
 
         Ok(Self {
             salt: base64_encode(&salt),
             verify_data: base64_encode(&verify_data),
             keyfile_enabled: keyfile.is_some(),
+            wrapped_key: Some(base64_encode(&data_key.wrap_with(&kek)?)),
+            spare: Some(SpareSlot::filler()),
         })
     }
+
+    /// 예전 형식 (데이터 키 없이 PIN 키로 바로 암호화)
+    pub fn is_legacy(&self) -> bool {
+        self.wrapped_key.is_none()
+    }
     /// PIN 검증 (키파일 선택적)
     pub fn verify(&self, pin: &str) -> Result<bool> {
         self.verify_with_keyfile(pin, None)
@@ -142,13 +237,25 @@ impl EncryptedHeader {
     pub fn verify_with_keyfile(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<bool> {
         let salt = base64_decode(&self.salt)?;
         let verify_data = base64_decode(&self.verify_data)?;
+        let kek = derive_kek(pin, &salt, keyfile)?;
 
-        let crypto = match keyfile {
-            Some(kf) => CryptoManager::from_pin_and_keyfile(pin, &salt, kf)?,
-            None => CryptoManager::from_pin(pin, &salt)?,
-        };
+        Ok(kek.verify_pin(&verify_data, b"LAZARUS_PIN_OK"))
+    }
 
-        Ok(crypto.verify_pin(&verify_data, b"LAZARUS_PIN_OK"))
+    /// 진짜 금고 열기 (PIN이 틀리면 None)
+    pub fn open(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<Option<CryptoManager>> {
+        let salt = base64_decode(&self.salt)?;
+        let kek = derive_kek(pin, &salt, keyfile)?;
+        if !kek.verify_pin(&base64_decode(&self.verify_data)?, b"LAZARUS_PIN_OK") {
+            return Ok(None);
+        }
+        match &self.wrapped_key {
+            Some(wrapped) => Ok(Some(CryptoManager::unwrap_with(
+                &kek,
+                &base64_decode(wrapped)?,
+            )?)),
+            None => Ok(Some(kek)),
+        }
     }
 
     /// CryptoManager 생성
@@ -156,19 +263,28 @@ impl EncryptedHeader {
         self.get_crypto_with_keyfile(pin, None)
     }
 
-    /// CryptoManager 생성 (키파일 포함)
+    /// CryptoManager 생성 (키파일 포함, 데이터 키를 풀어서)
     pub fn get_crypto_with_keyfile(
         &self,
         pin: &str,
         keyfile: Option<&[u8]>,
     ) -> Result<CryptoManager> {
         let salt = base64_decode(&self.salt)?;
-        match keyfile {
-            Some(kf) => CryptoManager::from_pin_and_keyfile(pin, &salt, kf),
-            None => CryptoManager::from_pin(pin, &salt),
+        let kek = derive_kek(pin, &salt, keyfile)?;
+        match &self.wrapped_key {
+            Some(wrapped) => CryptoManager::unwrap_with(&kek, &base64_decode(wrapped)?),
+            None => Ok(kek),
         }
     }
 }
+
+/// PIN(+키파일)에서 키 암호화 키 유도
+fn derive_kek(pin: &str, salt: &[u8], keyfile: Option<&[u8]>) -> Result<CryptoManager> {
+    match keyfile {
+        Some(kf) => CryptoManager::from_pin_and_keyfile(pin, salt, kf),
+        None => CryptoManager::from_pin(pin, salt),
+    }
+}
 /// Base64 인코딩
 fn base64_encode(data: &[u8]) -> String {
     use std::fmt::Write;
@@ -257,6 +373,85 @@ mod tests {
 
         assert_ne!(salt1, salt2);
     }
+
+    #[test]
+    fn test_change_pin_keeps_data_key() {
+        let mut security = SecurityConfig::default();
+        let data_key = security.change_pin(None, "123456", None).unwrap().crypto;
+        let encrypted = data_key.encrypt(b"secret").unwrap();
+
+        // 현재 PIN 없이 바꿀 수 없음
+        assert!(security.change_pin(None, "654321", None).is_err());
+
+        let keyfile = CryptoManager::generate_keyfile();
+        security
+            .change_pin(Some(("123456", None)), "654321", Some(&keyfile))
+            .unwrap();
+        assert!(!security.verify_pin("123456").unwrap());
+        assert!(security.requires_keyfile());
+
+        let reopened = security
+            .get_crypto_with_keyfile("654321", Some(&keyfile))
+            .unwrap()
+            .unwrap();
+        assert_eq!(reopened.key_id(), data_key.key_id());
+        assert_eq!(reopened.decrypt(&encrypted).unwrap(), b"secret");
+        assert!(security.get_crypto_with_keyfile("654321", None).is_err());
+    }
+
+    #[test]
+    fn test_recover_with_recovery_key() {
+        let mut security = SecurityConfig::default();
+        let data_key = security.change_pin(None, "123456", None).unwrap().crypto;
+        let key = security.create_recovery_key("123456", None).unwrap();
+
+        // PIN을 바꿔도 복구 키는 유효
+        security
+            .change_pin(Some(("123456", None)), "654321", None)
+            .unwrap();
+        assert!(security.recover("AAAA-BBBB", "abcdef", None).is_err());
+
+        let recovered = security.recover(&key, "abcdef", None).unwrap();
+        assert_eq!(recovered.key_id(), data_key.key_id());
+        assert!(security.verify_pin("abcdef").unwrap());
+        assert!(!security.verify_pin("654321").unwrap());
+
+        security.remove_pin();
+        assert!(!security.has_recovery_key());
+    }
+
+    #[test]
+    fn test_legacy_header_migration() {
+        // 예전 형식: PIN 키로 바로 암호화
+        let salt = CryptoManager::generate_salt();
+        let legacy_key = CryptoManager::from_pin("123456", &salt).unwrap();
+        let encrypted = legacy_key.encrypt(b"old note").unwrap();
+        let mut security = SecurityConfig {
+            pin_enabled: true,
+            header: Some(EncryptedHeader {
+                salt: base64_encode(&salt),
+                verify_data: base64_encode(&legacy_key.encrypt(b"LAZARUS_PIN_OK").unwrap()),
+                keyfile_enabled: false,
+                wrapped_key: None,
+                spare: None,
+            }),
+            recovery: None,
+            wipe_after: None,
+            idle_lock_mins: None,
+        };
+
+        assert!(security.migrate("000000", None).is_err());
+        assert!(security.migrate("123456", None).unwrap());
+        assert!(!security.migrate("123456", None).unwrap());
+        assert!(!security.header.as_ref().unwrap().is_legacy());
+
+        // 전환 후 PIN을 바꿔도 예전 데이터를 열 수 있음
+        security
+            .change_pin(Some(("123456", None)), "abcdef", None)
+            .unwrap();
+        let crypto = security.get_crypto("abcdef").unwrap().unwrap();
+        assert_eq!(crypto.decrypt(&encrypted).unwrap(), b"old note");
+    }
 }
 
 use std::fs;
@@ -269,6 +464,15 @@ pub struct SecurityConfig {
     pub pin_enabled: bool,
     /// 암호화 헤더 (PIN 설정 시)
     pub header: Option<EncryptedHeader>,
+    /// 복구 키로 감싼 데이터 키 (만들었을 때만)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<RecoveryHeader>,
+    /// 연속으로 이만큼 틀리면 금고 삭제 (없으면 삭제하지 않음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wipe_after: Option<u32>,
+    /// 이만큼(분) 아무 요청이 없으면 자동 잠금 (없으면 기본값, 0이면 끔)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_lock_mins: Option<u32>,
 }
 
 impl SecurityConfig {
@@ -277,6 +481,17 @@ impl SecurityConfig {
         Self {
             pin_enabled: false,
             header: None,
+            recovery: None,
+            wipe_after: None,
+            idle_lock_mins: None,
+        }
+    }
+
+    /// 자동 잠금까지의 유휴 시간 (끄면 None)
+    pub fn idle_timeout(&self) -> Option<chrono::Duration> {
+        match self.idle_lock_mins.unwrap_or(DEFAULT_IDLE_LOCK_MINS) {
+            0 => None,
+            mins => Some(chrono::Duration::minutes(mins as i64)),
         }
     }
 
@@ -296,26 +511,190 @@ impl SecurityConfig {
         Ok(())
     }
 
-    /// PIN 설정
-    pub fn set_pin(&mut self, pin: &str) -> Result<()> {
-        let header = EncryptedHeader::new(pin)?;
+    /// PIN(+키파일) 설정/변경, 반환: 데이터 키
+    ///
+    /// 이미 PIN이 있으면 `current`로 데이터 키를 풀어 새 PIN으로 다시 감싸기만
+    /// 하므로 기존 암호화 노트와 백업을 계속 열 수 있습니다 (예전 형식 헤더도
+    /// 이때 전환). `current`는 호출 측에서 미리 검증해야 합니다.
+    ///
+    /// `current`가 강요 PIN이면 강요 PIN 자리만 새 PIN으로 다시 봉합니다
+    /// (키파일 설정은 진짜 금고 것이라 건드리지 않음).
+    pub fn change_pin(
+        &mut self,
+        current: Option<(&str, Option<&[u8]>)>,
+        new_pin: &str,
+        new_keyfile: Option<&[u8]>,
+    ) -> Result<Unlocked> {
+        let unlocked = match (&self.header, current) {
+            (Some(_), Some((pin, keyfile))) => {
+                self.unlock(pin, keyfile)?.ok_or(LazarusError::Decryption)?
+            }
+            (Some(_), None) => return Err(LazarusError::Decryption),
+            (None, _) => Unlocked::vault(CryptoManager::generate_data_key()),
+        };
+
+        if unlocked.decoy {
+            if let Some(header) = self.header.as_mut() {
+                header.spare = Some(SpareSlot::seal(
+                    &unlocked.crypto,
+                    new_pin,
+                    unlocked.destroy_real,
+                )?);
+            }
+            return Ok(unlocked);
+        }
+
+        self.rewrap(&unlocked.crypto, new_pin, new_keyfile)?;
         self.pin_enabled = true;
+        Ok(unlocked)
+    }
+
+    /// 데이터 키를 새 PIN(+키파일)로 감싼 헤더로 교체 (두 번째 키 자리는 유지)
+    fn rewrap(
+        &mut self,
+        data_key: &CryptoManager,
+        pin: &str,
+        keyfile: Option<&[u8]>,
+    ) -> Result<()> {
+        let mut header = EncryptedHeader::wrap(data_key, pin, keyfile)?;
+        if let Some(spare) = self.header.take().and_then(|h| h.spare) {
+            header.spare = Some(spare);
+        }
         self.header = Some(header);
         Ok(())
     }
 
+    /// PIN(+키파일)으로 잠금 해제, 틀리면 None
+    ///
+    /// 진짜 금고 자리를 먼저 보고, 안 열리면 두 번째 키 자리를 봅니다. 두 번째
+    /// 자리가 비어 있어도 같은 계산을 하므로 걸리는 시간으로는 구별되지 않습니다.
+    pub fn unlock(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<Option<Unlocked>> {
+        let Some(header) = &self.header else {
+            return Ok(None);
+        };
+        if let Some(data_key) = header.open(pin, keyfile)? {
+            return Ok(Some(Unlocked::vault(data_key)));
+        }
+
+        let Some(spare) = &header.spare else {
+            return Ok(None);
+        };
+        Ok(spare.open(pin)?.map(|(crypto, destroy_real)| Unlocked {
+            crypto,
+            decoy: true,
+            destroy_real,
+        }))
+    }
+
+    /// 강요 PIN 설정 (None이면 해제), 미끼 금고는 빈 상태로 새로 시작
+    ///
+    /// `pin`/`keyfile`은 진짜 금고를 여는 값이어야 합니다.
+    pub fn set_duress_pin(
+        &mut self,
+        pin: &str,
+        keyfile: Option<&[u8]>,
+        duress: Option<(&str, bool)>,
+    ) -> Result<()> {
+        let header = self.header.as_mut().ok_or(LazarusError::Decryption)?;
+        if header.open(pin, keyfile)?.is_none() {
+            return Err(LazarusError::Decryption);
+        }
+
+        header.spare = Some(match duress {
+            Some((duress_pin, destroy_real)) => SpareSlot::seal(
+                &CryptoManager::generate_data_key(),
+                duress_pin,
+                destroy_real,
+            )?,
+            None => SpareSlot::filler(),
+        });
+        Ok(())
+    }
+
+    /// 진짜 금고 파기: 진짜 자리를 랜덤으로 덮고 복구 키도 지움
+    ///
+    /// 강요 PIN 자리는 파기 플래그 없이 다시 봉하므로 이후로는 평범한 금고처럼
+    /// 동작합니다. `duress_pin`은 방금 연 강요 PIN이어야 합니다.
+    pub fn destroy_real_vault(&mut self, duress_pin: &str) -> Result<()> {
+        let header = self.header.as_mut().ok_or(LazarusError::Decryption)?;
+        let spare = header.spare.as_ref().ok_or(LazarusError::Decryption)?;
+        let (decoy_key, _) = spare.open(duress_pin)?.ok_or(LazarusError::Decryption)?;
+
+        header.scramble()?;
+        header.spare = Some(SpareSlot::seal(&decoy_key, duress_pin, false)?);
+        self.recovery = None;
+        Ok(())
+    }
+
+    /// 예전 형식 헤더를 봉투 암호화로 전환 (키는 그대로, 전환했으면 true)
+    ///
+    /// PIN 키가 그대로 데이터 키가 되므로 기존 데이터는 다시 암호화하지 않습니다.
+    ///
+    /// 두 번째 키 자리가 없는 예전 헤더도 이때 랜덤으로 채웁니다.
+    pub fn migrate(&mut self, pin: &str, keyfile: Option<&[u8]>) -> Result<bool> {
+        let Some(header) = self.header.as_mut() else {
+            return Ok(false);
+        };
+        let filled = header.spare.is_none();
+        if filled {
+            header.spare = Some(SpareSlot::filler());
+        }
+        if !header.is_legacy() {
+            return Ok(filled);
+        }
+        if !header.verify_with_keyfile(pin, keyfile)? {
+            return Err(LazarusError::Decryption);
+        }
+
+        let data_key = header.get_crypto_with_keyfile(pin, keyfile)?;
+        self.rewrap(&data_key, pin, keyfile)?;
+        Ok(true)
+    }
+
     /// PIN 해제
     pub fn remove_pin(&mut self) {
         self.pin_enabled = false;
         self.header = None;
+        self.recovery = None;
+        self.wipe_after = None;
+    }
+
+    /// 새 복구 키 생성 (이전 복구 키는 무효), 반환: 표시용 복구 키
+    ///
+    /// 데이터 키를 감싸므로 PIN을 바꿔도 복구 키는 계속 유효합니다.
+    /// `pin`/`keyfile`은 호출 측에서 미리 검증해야 합니다.
+    /// 강요 PIN으로는 만들 수 없습니다 (진짜 금고의 복구 키를 덮지 않도록).
+    pub fn create_recovery_key(&mut self, pin: &str, keyfile: Option<&[u8]>) -> Result<String> {
+        let header = self.header.as_ref().ok_or(LazarusError::Decryption)?;
+        let data_key = header.open(pin, keyfile)?.ok_or(LazarusError::Decryption)?;
+        let (recovery, key) = RecoveryHeader::create(&data_key)?;
+        self.recovery = Some(recovery);
+        Ok(key)
+    }
+
+    /// 복구 키로 새 PIN(+키파일) 설정, 반환: 데이터 키
+    pub fn recover(
+        &mut self,
+        recovery_key: &str,
+        new_pin: &str,
+        new_keyfile: Option<&[u8]>,
+    ) -> Result<CryptoManager> {
+        let recovery = self.recovery.as_ref().ok_or(LazarusError::Decryption)?;
+        let data_key = recovery.unwrap(recovery_key)?;
+
+        self.rewrap(&data_key, new_pin, new_keyfile)?;
+        self.pin_enabled = true;
+        Ok(data_key)
+    }
+
+    /// 복구 키가 있는지
+    pub fn has_recovery_key(&self) -> bool {
+        self.pin_enabled && self.recovery.is_some()
     }
 
-    /// PIN 검증
+    /// PIN 검증 (강요 PIN도 맞는 PIN으로 취급)
     pub fn verify_pin(&self, pin: &str) -> Result<bool> {
-        match &self.header {
-            Some(header) => header.verify(pin),
-            None => Ok(true), // PIN 없으면 항상 성공
-        }
+        self.verify_pin_with_keyfile(pin, None)
     }
 
     /// CryptoManager 가져오기
@@ -326,18 +705,21 @@ impl SecurityConfig {
         }
     }
 
-    /// PIN + 키파일 설정
-    pub fn set_pin_with_keyfile(&mut self, pin: &str, keyfile: Option<&[u8]>) -> Result<()> {
-        let header = EncryptedHeader::new_with_keyfile(pin, keyfile)?;
-        self.pin_enabled = true;
-        self.header = Some(header);
-        Ok(())
+    /// PIN + 키파일 검증 (강요 PIN도 맞는 PIN으로 취급)
+    pub fn verify_pin_with_keyfile(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<bool> {
+        match &self.header {
+            Some(_) => Ok(self.unlock(pin, keyfile)?.is_some()),
+            None => Ok(true), // PIN 없으면 항상 성공
+        }
     }
 
-    /// PIN + 키파일 검증
-    pub fn verify_pin_with_keyfile(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<bool> {
+    /// 진짜 금고를 여는 PIN + 키파일인지 (강요 PIN이면 false)
+    ///
+    /// 복구 키 생성이나 PIN 제거처럼 진짜 금고에만 할 수 있는 일에 씁니다.
+    /// 강요 PIN은 틀린 PIN과 똑같이 거절됩니다.
+    pub fn verify_vault_pin(&self, pin: &str, keyfile: Option<&[u8]>) -> Result<bool> {
         match &self.header {
-            Some(header) => header.verify_with_keyfile(pin, keyfile),
+            Some(_) => Ok(self.unlock(pin, keyfile)?.is_some_and(|u| !u.decoy)),
             None => Ok(true),
         }
     }
@@ -0,0 +1,139 @@
+//! 복구 키 (PIN 분실 대비)
+//!
+//! 160비트 랜덤 비밀을 Crockford Base32로 4글자씩 끊어 보여 줍니다
+//! (`ABCD-EFGH-...`, 종이에 적거나 QR로 만들기 쉬운 형태). 복구 키에서
+//! 유도한 키로 데이터 키를 한 번 더 감싸 `security.json`에 둡니다.
+//! 복구 키 자체는 저장하지 않으며 만들 때 한 번만 보여 줍니다.
+
+use rand::RngCore;
+
+use super::{base64_decode, base64_encode, CryptoManager};
+use crate::error::{LazarusError, Result};
+
+/// 복구 비밀 크기 (20 bytes = 160 bits)
+const SECRET_SIZE: usize = 20;
+
+/// Crockford Base32 (I, L, O, U 제외)
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// 표시할 때 한 묶음 글자 수
+const GROUP_SIZE: usize = 4;
+
+/// 복구 키로 감싼 데이터 키
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RecoveryHeader {
+    /// 솔트 (hex)
+    pub salt: String,
+    /// 복구 키로 감싼 데이터 키 (hex)
+    pub wrapped_key: String,
+    /// 만든 시각
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl RecoveryHeader {
+    /// 새 복구 키 생성, 반환: (헤더, 표시용 복구 키)
+    pub fn create(data_key: &CryptoManager) -> Result<(Self, String)> {
+        let mut secret = [0u8; SECRET_SIZE];
+        rand::thread_rng().fill_bytes(&mut secret);
+        let salt = CryptoManager::generate_salt();
+
+        let kek = CryptoManager::from_recovery_secret(&secret, &salt)?;
+        let header = Self {
+            salt: base64_encode(&salt),
+            wrapped_key: base64_encode(&data_key.wrap_with(&kek)?),
+            created_at: chrono::Utc::now(),
+        };
+        Ok((header, format_key(&secret)))
+    }
+
+    /// 복구 키로 데이터 키 풀기 (틀리면 Decryption)
+    pub fn unwrap(&self, recovery_key: &str) -> Result<CryptoManager> {
+        let secret = parse_key(recovery_key).ok_or(LazarusError::Decryption)?;
+        let salt = base64_decode(&self.salt)?;
+        let kek = CryptoManager::from_recovery_secret(&secret, &salt)?;
+        CryptoManager::unwrap_with(&kek, &base64_decode(&self.wrapped_key)?)
+    }
+}
+
+impl CryptoManager {
+    /// 복구 비밀에서 키 유도 (비밀 자체가 충분히 길어 HKDF만 사용)
+    fn from_recovery_secret(secret: &[u8], salt: &[u8]) -> Result<Self> {
+        use hkdf::Hkdf;
+        use sha2::Sha256;
+
+        let hkdf = Hkdf::<Sha256>::new(Some(salt), secret);
+        let mut key = [0u8; 32];
+        hkdf.expand(b"lazarus-recovery-v1", &mut key)
+            .map_err(|_| LazarusError::Encryption)?;
+        Ok(Self { key })
+    }
+}
+
+/// 비밀 → `ABCD-EFGH-...`
+fn format_key(secret: &[u8]) -> String {
+    let mut chars = Vec::with_capacity(secret.len() * 8 / 5);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in secret {
+        buffer = (buffer << 8) | *byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            chars.push(ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+
+    chars
+        .chunks(GROUP_SIZE)
+        .map(|group| group.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// 입력한 복구 키 → 비밀 (대소문자, 공백, `-` 무시, 헷갈리는 글자 보정)
+fn parse_key(input: &str) -> Option<Vec<u8>> {
+    let mut secret = Vec::with_capacity(SECRET_SIZE);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in input.chars() {
+        let c = match c.to_ascii_uppercase() {
+            '-' | ' ' => continue,
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        let value = ALPHABET.iter().position(|&a| a as char == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            secret.push((buffer >> bits) as u8);
+        }
+    }
+
+    (secret.len() == SECRET_SIZE).then_some(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovery_key_roundtrip() {
+        let data_key = CryptoManager::generate_data_key();
+        let (header, key) = RecoveryHeader::create(&data_key).unwrap();
+        assert_eq!(key.len(), 32 + 7);
+
+        let recovered = header.unwrap(&key).unwrap();
+        assert_eq!(recovered.key_id(), data_key.key_id());
+
+        // 소문자, 공백, 헷갈리는 글자도 받아 줌
+        let typed = key.to_lowercase().replace('-', " ").replace('0', "o");
+        assert_eq!(header.unwrap(&typed).unwrap().key_id(), data_key.key_id());
+
+        let (_, other) = RecoveryHeader::create(&data_key).unwrap();
+        assert!(header.unwrap(&other).is_err());
+        assert!(header.unwrap("ABCD-EFGH").is_err());
+        assert!(header.unwrap("복구키").is_err());
+    }
+}
@@ -1,51 +1,300 @@
 //! 자동 백업 시스템
 //!
-//! Rolling Backup: 최근 3개만 유지
+//! 백업 범위: `notes.lazarus`와 같은 디렉토리의 다른 저장소(SRS, 게시판, Q&A,
+//! 보안 설정, 패키지)를 한 매니페스트에 함께 담습니다 (`DATA_FILES`).
+//! 증분 백업: 파일을 청크로 나눠 새 청크만 저장 (`chunk_store`)
+//! 계층별 보존: 최근 N개 + 시간별/일별/주별 대표 백업
 //! 암호화 지원: PIN 설정 시 백업도 암호화
+//!
+//! 예전 gzip 백업(`.gz`, `.gz.enc`)도 계속 읽고 복원할 수 있습니다.
 
 use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::io::{self, BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc};
 use flate2::read::GzDecoder;
-use flate2::write::GzEncoder;
-use flate2::Compression;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
+use super::checkpoint;
+use super::chunk_store::{read_chunk, ChunkStore, ContentHasher, CHUNK_SIZE};
+use super::schedule::BackupSchedule;
+use super::snapshot::BackupSnapshot;
 use crate::crypto::CryptoManager;
 use crate::error::{LazarusError, Result};
 
+/// 보존 정책 파일 이름 (백업 디렉토리 안)
+const RETENTION_FILE: &str = "retention.json";
+
+/// 자동 백업 설정 파일 이름 (백업 디렉토리 안)
+const SCHEDULE_FILE: &str = "schedule.json";
+
+/// 백업에 포함하는 데이터 디렉토리 항목 (파일 또는 디렉토리)
+///
+/// 검색 인덱스, 체크포인트 같은 파생 데이터와 ZIM 파일은 넣지 않습니다.
+pub const DATA_FILES: &[&str] = &[
+    "notes.lazarus",
+    "srs.jsonl",
+    "srs_stats.json",
+    "srs_logs.jsonl",
+    "srs_params.json",
+    "posts.jsonl",
+    "questions.jsonl",
+    "security.json",
+    "packages_index.json",
+    "installed_packages.jsonl",
+    "packages",
+];
+
+/// 노트 DB 파일 (매니페스트 안의 경로)
+const NOTES_FILE: &str = "notes.lazarus";
+
+/// 매니페스트 확장자
+const MANIFEST_EXT: &str = "manifest";
+
+/// 백업 파일 이름의 시각 형식
+const STAMP_FORMAT: &str = "%Y%m%d_%H%M%S";
+
+/// 보존 계층의 구간 키 (같은 키면 같은 시간/일/주)
+type Bucket = fn(&DateTime<Utc>) -> i64;
+
+/// 백업 보존 정책 (계층별)
+///
+/// 최근 `keep_last`개는 무조건 남기고, 그 밖에는 최근 `hourly`시간/`daily`일/
+/// `weekly`주마다 가장 최신 백업 하나씩 남깁니다. 0이면 그 계층은 끕니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupRetention {
+    pub keep_last: usize,
+    pub hourly: usize,
+    pub daily: usize,
+    pub weekly: usize,
+}
+
+impl Default for BackupRetention {
+    fn default() -> Self {
+        Self {
+            keep_last: 3,
+            hourly: 24,
+            daily: 7,
+            weekly: 4,
+        }
+    }
+}
+
+impl BackupRetention {
+    /// 파일에서 로드 (없으면 기본값)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path).map_err(LazarusError::Io)?;
+        serde_json::from_str(&content).map_err(LazarusError::JsonParse)
+    }
+
+    /// 파일에 저장
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(LazarusError::JsonParse)?;
+        fs::write(path, content).map_err(LazarusError::Io)?;
+        Ok(())
+    }
+
+    /// 백업별 보존 여부 (`stamps`는 최신순)
+    pub fn retain(&self, stamps: &[DateTime<Utc>]) -> Vec<bool> {
+        let mut keep: Vec<bool> = (0..stamps.len()).map(|i| i < self.keep_last).collect();
+
+        let tiers: [(usize, Bucket); 3] = [
+            (self.hourly, |t| t.timestamp().div_euclid(3600)),
+            (self.daily, |t| t.timestamp().div_euclid(86_400)),
+            (self.weekly, |t| {
+                let week = t.iso_week();
+                i64::from(week.year()) * 100 + i64::from(week.week())
+            }),
+        ];
+
+        for (limit, bucket) in tiers {
+            let mut last = None;
+            let mut used = 0;
+            for (i, stamp) in stamps.iter().enumerate() {
+                if used >= limit {
+                    break;
+                }
+                let key = bucket(stamp);
+                if last != Some(key) {
+                    // 최신순이라 구간의 첫 백업이 그 구간의 최신 백업
+                    keep[i] = true;
+                    last = Some(key);
+                    used += 1;
+                }
+            }
+        }
+        keep
+    }
+}
+
+/// 증분 백업 매니페스트 (데이터 디렉토리 스냅샷)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub created_at: DateTime<Utc>,
+    /// 백업한 파일들 (데이터 디렉토리 기준 상대 경로 순)
+    pub files: Vec<BackupFile>,
+    /// 청크를 암호화한 키 ID (평문 백업이면 None)
+    pub key_id: Option<String>,
+    /// 청크 이름과 파일 해시가 키 해시(HMAC)인지 (예전 암호화 백업은 SHA-256)
+    #[serde(default)]
+    pub keyed_hashes: bool,
+    /// 이 백업에서 새로 저장한 청크 수
+    pub new_chunks: usize,
+}
+
+/// 매니페스트 안의 파일 하나
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupFile {
+    /// 데이터 디렉토리 기준 경로 (`/` 구분)
+    pub path: String,
+    pub size: u64,
+    /// 파일 전체 내용 해시 (`keyed_hashes`면 HMAC-SHA256)
+    pub sha256: String,
+    /// 백업할 때 원본의 수정 시각 (크기와 함께 같으면 다시 해시하지 않음)
+    #[serde(default)]
+    pub modified: Option<DateTime<Utc>>,
+    /// 청크 해시 (순서대로)
+    pub chunks: Vec<String>,
+}
+
+impl BackupManifest {
+    /// 파일에서 로드
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(LazarusError::Io)?;
+        serde_json::from_str(&content).map_err(LazarusError::JsonParse)
+    }
+
+    /// 전체 크기
+    pub fn size(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+
+    /// 경로로 파일 찾기
+    pub fn file(&self, path: &str) -> Option<&BackupFile> {
+        self.files.iter().find(|f| f.path == path)
+    }
+
+    /// 파일에 저장 (임시 파일 후 rename)
+    fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(LazarusError::JsonParse)?;
+        let temp = path.with_extension("tmp");
+        fs::write(&temp, content).map_err(LazarusError::Io)?;
+        fs::rename(&temp, path).map_err(LazarusError::Io)?;
+        Ok(())
+    }
+}
+
+/// 백업 파일 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupEntry {
+    /// 파일 이름 (API에서 백업을 가리킬 때 사용)
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub created_at: DateTime<Utc>,
+    pub encrypted: bool,
+}
+
 /// 백업 관리자
 pub struct BackupManager {
-    /// 원본 파일 경로
+    /// 원본 파일 경로 (`notes.lazarus`)
     source_path: PathBuf,
+    /// 데이터 디렉토리 (원본 파일이 있는 곳)
+    data_dir: PathBuf,
     /// 백업 디렉토리
     backup_dir: PathBuf,
-    /// 최대 백업 수
-    max_backups: usize,
+    /// 청크 저장소
+    chunks: ChunkStore,
+    /// 보존 정책
+    retention: BackupRetention,
+    /// 자동 백업 설정
+    schedule: BackupSchedule,
     /// 암호화 매니저 (옵션)
     crypto: Option<CryptoManager>,
+    /// 키가 잠겨 있어 백업을 미루는 중 (평문 백업으로 내려가지 않도록)
+    locked: bool,
 }
 
 impl BackupManager {
     /// 새 백업 관리자 생성
     pub fn new<P: AsRef<Path>>(source_path: P, backup_dir: P) -> Self {
+        let backup_dir = backup_dir.as_ref().to_path_buf();
+        let retention =
+            BackupRetention::load(&backup_dir.join(RETENTION_FILE)).unwrap_or_else(|e| {
+                tracing::warn!("백업 보존 정책 로드 실패, 기본값 사용: {}", e);
+                BackupRetention::default()
+            });
+        let schedule = BackupSchedule::load(&backup_dir.join(SCHEDULE_FILE)).unwrap_or_else(|e| {
+            tracing::warn!("자동 백업 설정 로드 실패, 기본값 사용: {}", e);
+            BackupSchedule::default()
+        });
+
+        let source_path = source_path.as_ref().to_path_buf();
+        let data_dir = source_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
         Self {
-            source_path: source_path.as_ref().to_path_buf(),
-            backup_dir: backup_dir.as_ref().to_path_buf(),
-            max_backups: 3,
+            source_path,
+            data_dir,
+            chunks: ChunkStore::new(backup_dir.join("chunks")),
+            backup_dir,
+            retention,
+            schedule,
             crypto: None,
+            locked: false,
         }
     }
 
+    /// 보존 정책
+    pub fn retention(&self) -> BackupRetention {
+        self.retention
+    }
+
+    /// 보존 정책 변경 (저장 후 바로 정리)
+    pub fn set_retention(&mut self, retention: BackupRetention) -> Result<()> {
+        self.ensure_backup_dir()?;
+        retention.save(&self.backup_dir.join(RETENTION_FILE))?;
+        self.retention = retention;
+        self.cleanup_old_backups()
+    }
+
+    /// 자동 백업 설정
+    pub fn schedule(&self) -> &BackupSchedule {
+        &self.schedule
+    }
+
+    /// 자동 백업 설정 변경 (저장)
+    pub fn set_schedule(&mut self, schedule: BackupSchedule) -> Result<()> {
+        self.ensure_backup_dir()?;
+        schedule.save(&self.backup_dir.join(SCHEDULE_FILE))?;
+        self.schedule = schedule;
+        Ok(())
+    }
+
     /// 암호화 매니저 설정
     pub fn with_crypto(mut self, crypto: CryptoManager) -> Self {
         self.crypto = Some(crypto);
         self
     }
 
-    /// 암호화 매니저 업데이트
+    /// 암호화 매니저 업데이트 (None이면 평문 백업, 잠금도 풂)
     pub fn set_crypto(&mut self, crypto: Option<CryptoManager>) {
         self.crypto = crypto;
+        self.locked = false;
+    }
+
+    /// 키 잠금: 키를 지우고 `set_crypto`로 다시 받을 때까지 백업을 건너뜀
+    pub fn lock(&mut self) {
+        self.crypto = None;
+        self.locked = true;
     }
 
     /// 백업 디렉토리 생성
@@ -57,7 +306,16 @@ impl BackupManager {
     }
 
     /// 백업 실행
+    ///
+    /// 데이터 파일을 청크 단위로 읽어 새 청크만 저장하고 매니페스트를 씁니다.
+    /// 메모리에는 한 번에 청크 하나만 올라옵니다. 저장소들이 같은 시점이
+    /// 되도록 호출자가 쓰기를 막아야 합니다 (`AppState::backup_now`).
     pub fn backup(&self) -> Result<Option<PathBuf>> {
+        if self.locked {
+            tracing::debug!("백업 스킵: 키 잠김");
+            return Ok(None);
+        }
+
         // 원본 파일이 없으면 스킵
         if !self.source_path.exists() {
             tracing::debug!("백업 스킵: 원본 파일 없음");
@@ -65,58 +323,76 @@ impl BackupManager {
         }
 
         self.ensure_backup_dir()?;
-
-        // 오늘 날짜로 백업 파일명 생성
-        let today = chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string();
-        let filename = self
-            .source_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .unwrap_or("backup");
-
-        // 암호화 여부에 따라 확장자 변경
-        let ext = if self.crypto.is_some() {
-            "gz.enc"
-        } else {
-            "gz"
-        };
-        let backup_name = format!("{}_{}.{}", filename, today, ext);
-        let backup_path = self.backup_dir.join(&backup_name);
+        let files = self.data_files()?;
 
         // 이미 최근 백업이 있고 내용이 같으면 스킵
         if let Some(latest) = self.get_latest_backup()? {
-            if self.is_same_content(&latest)? {
+            if self.is_same_content(&latest, &files)? {
                 tracing::debug!("백업 스킵: 변경사항 없음");
                 return Ok(None);
             }
         }
 
-        // 원본 데이터 읽기
-        let source_data = fs::read(&self.source_path).map_err(LazarusError::Io)?;
+        let crypto = self.crypto.as_ref();
+        let mut manifest = BackupManifest {
+            created_at: Utc::now(),
+            files: Vec::with_capacity(files.len()),
+            key_id: crypto.map(|c| c.key_id()),
+            keyed_hashes: crypto.is_some(),
+            new_chunks: 0,
+        };
+        let hasher = ContentHasher::new(crypto)?;
 
-        // gzip 압축
-        let mut compressed = Vec::new();
-        {
-            let mut encoder = GzEncoder::new(&mut compressed, Compression::fast());
-            encoder.write_all(&source_data).map_err(LazarusError::Io)?;
-            encoder.finish().map_err(LazarusError::Io)?;
+        let mut buf = Vec::with_capacity(CHUNK_SIZE);
+        for (rel, abs) in &files {
+            let mut source = File::open(abs).map_err(LazarusError::Io)?;
+            let mut file = BackupFile {
+                path: rel.clone(),
+                size: 0,
+                sha256: String::new(),
+                modified: modified_time(&source.metadata().map_err(LazarusError::Io)?),
+                chunks: Vec::new(),
+            };
+            let mut total = hasher.clone();
+            while read_chunk(&mut source, &mut buf)? > 0 {
+                total.update(&buf);
+                let (hash, new) = self.chunks.put(&buf, crypto, &hasher)?;
+                file.size += buf.len() as u64;
+                file.chunks.push(hash);
+                if new {
+                    manifest.new_chunks += 1;
+                }
+            }
+            file.sha256 = total.finalize();
+            manifest.files.push(file);
         }
 
-        // 암호화 (옵션)
-        let final_data = if let Some(ref crypto) = self.crypto {
-            crypto.encrypt(&compressed)?
-        } else {
-            compressed
-        };
-
-        // 파일 저장
-        fs::write(&backup_path, final_data).map_err(LazarusError::Io)?;
+        // 백업 파일명: 원본 이름 + 시각
+        let filename = self
+            .source_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("backup");
+        let backup_name = format!(
+            "{}_{}.{}",
+            filename,
+            manifest.created_at.format(STAMP_FORMAT),
+            MANIFEST_EXT
+        );
+        let backup_path = self.backup_dir.join(&backup_name);
+        manifest.save(&backup_path)?;
 
-        if self.crypto.is_some() {
-            tracing::info!("암호화된 백업 완료: {}", backup_path.display());
-        } else {
-            tracing::info!("백업 완료: {}", backup_path.display());
-        }
+        tracing::info!(
+            "{}백업 완료: {} (파일 {}개, 새 청크 {}개)",
+            if crypto.is_some() {
+                "암호화된 "
+            } else {
+                ""
+            },
+            backup_path.display(),
+            manifest.files.len(),
+            manifest.new_chunks
+        );
 
         // 오래된 백업 정리
         self.cleanup_old_backups()?;
@@ -124,6 +400,21 @@ impl BackupManager {
         Ok(Some(backup_path))
     }
 
+    /// 백업할 데이터 파일 (상대 경로, 절대 경로) - 상대 경로 순
+    fn data_files(&self) -> Result<Vec<(String, PathBuf)>> {
+        let mut files = Vec::new();
+        for name in DATA_FILES {
+            let path = self.data_dir.join(name);
+            if path.is_dir() {
+                collect_files(&path, name, &mut files)?;
+            } else if path.is_file() {
+                files.push((name.to_string(), path));
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
     /// 최신 백업 파일 가져오기
     fn get_latest_backup(&self) -> Result<Option<PathBuf>> {
         let backups = self.list_backups()?;
@@ -142,7 +433,7 @@ impl BackupManager {
             .map(|e| e.path())
             .filter(|p| {
                 p.extension()
-                    .map(|ext| ext == "gz" || ext == "enc")
+                    .map(|ext| ext == "gz" || ext == "enc" || ext == MANIFEST_EXT)
                     .unwrap_or(false)
             })
             .collect();
@@ -152,78 +443,467 @@ impl BackupManager {
         Ok(backups)
     }
 
-    /// 오래된 백업 정리
+    /// 백업 목록 (최신순, 크기/시각 포함)
+    pub fn entries(&self) -> Result<Vec<BackupEntry>> {
+        let mut entries = Vec::new();
+        for path in self.list_backups()? {
+            let metadata = fs::metadata(&path).map_err(LazarusError::Io)?;
+            let name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default()
+                .to_string();
+            let created_at = backup_time(&name)
+                .or_else(|| metadata.modified().ok().map(DateTime::<Utc>::from))
+                .unwrap_or_default();
+
+            let entry = match is_manifest(&path).then(|| BackupManifest::load(&path)) {
+                Some(Ok(manifest)) => BackupEntry {
+                    name,
+                    path,
+                    size: manifest.size(),
+                    created_at: manifest.created_at,
+                    encrypted: manifest.key_id.is_some(),
+                },
+                _ => BackupEntry {
+                    encrypted: name.ends_with(".enc"),
+                    name,
+                    path,
+                    size: metadata.len(),
+                    created_at,
+                },
+            };
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// 이름으로 백업 찾기 (백업 디렉토리 밖은 가리킬 수 없음)
+    pub fn find(&self, name: &str) -> Result<PathBuf> {
+        self.list_backups()?
+            .into_iter()
+            .find(|p| p.file_name().and_then(|n| n.to_str()) == Some(name))
+            .ok_or_else(|| LazarusError::NotFound(format!("백업: {}", name)))
+    }
+
+    /// 백업을 읽기 전용 스냅샷으로 열기
+    ///
+    /// 노트 DB를 백업 디렉토리의 임시 파일로 풀고 그 파일을 엽니다.
+    pub fn open_snapshot(&self, backup_path: &Path) -> Result<BackupSnapshot> {
+        fs::create_dir_all(&self.backup_dir).map_err(LazarusError::Io)?;
+        let temp = self
+            .backup_dir
+            .join(format!(".snapshot-{}.tmp", uuid::Uuid::new_v4()));
+
+        let written = File::create(&temp)
+            .map_err(LazarusError::Io)
+            .and_then(|file| {
+                let mut writer = BufWriter::new(file);
+                self.read_backup(backup_path, &mut writer)?;
+                writer.flush().map_err(LazarusError::Io)
+            });
+        if let Err(e) = written {
+            let _ = fs::remove_file(&temp);
+            return Err(e);
+        }
+        BackupSnapshot::open(temp)
+    }
+
+    /// 오래된 백업 정리 (보존 정책 적용) 후 쓰지 않는 청크 삭제
     fn cleanup_old_backups(&self) -> Result<()> {
-        let backups = self.list_backups()?;
-        for old_backup in backups.into_iter().skip(self.max_backups) {
-            fs::remove_file(&old_backup).map_err(LazarusError::Io)?;
-            tracing::debug!("오래된 백업 삭제: {}", old_backup.display());
+        let entries = self.entries()?;
+        let stamps: Vec<DateTime<Utc>> = entries.iter().map(|e| e.created_at).collect();
+        let keep = self.retention.retain(&stamps);
+
+        for (entry, keep) in entries.into_iter().zip(keep) {
+            if !keep {
+                fs::remove_file(&entry.path).map_err(LazarusError::Io)?;
+                tracing::debug!("오래된 백업 삭제: {}", entry.path.display());
+            }
+        }
+
+        // 남은 매니페스트가 참조하는 청크만 유지
+        let mut live = HashSet::new();
+        for path in self.list_backups()? {
+            if !is_manifest(&path) {
+                continue;
+            }
+            match BackupManifest::load(&path) {
+                Ok(manifest) => {
+                    live.extend(manifest.files.into_iter().flat_map(|f| f.chunks));
+                }
+                Err(e) => {
+                    // 읽을 수 없는 매니페스트가 있으면 청크를 지우지 않음
+                    tracing::warn!("매니페스트 읽기 실패, 청크 정리 생략: {}", e);
+                    return Ok(());
+                }
+            }
+        }
+        let removed = self.chunks.gc(&live)?;
+        if removed > 0 {
+            tracing::debug!("쓰지 않는 청크 {}개 삭제", removed);
         }
         Ok(())
     }
 
     /// 내용 비교 (변경 여부 확인)
-    fn is_same_content(&self, backup_path: &Path) -> Result<bool> {
-        let source_data = fs::read(&self.source_path).map_err(LazarusError::Io)?;
-        let backup_data = fs::read(backup_path).map_err(LazarusError::Io)?;
-
-        // 암호화된 백업이면 복호화 필요
-        let decompressed = if backup_path.to_string_lossy().ends_with(".enc") {
-            if let Some(ref crypto) = self.crypto {
-                let decrypted = crypto.decrypt(&backup_data)?;
-                let mut decoder = GzDecoder::new(&decrypted[..]);
-                let mut data = Vec::new();
-                decoder.read_to_end(&mut data).map_err(LazarusError::Io)?;
-                data
-            } else {
-                // 암호화된 백업인데 키가 없으면 비교 불가
+    ///
+    /// 예전 gzip 백업과는 비교하지 않습니다 (다음 백업을 증분 형식으로 새로 씀).
+    fn is_same_content(&self, backup_path: &Path, files: &[(String, PathBuf)]) -> Result<bool> {
+        if !is_manifest(backup_path) {
+            return Ok(false);
+        }
+
+        let mut manifest = BackupManifest::load(backup_path)?;
+        // 암호화 설정이 바뀌었거나 예전 해시 방식이면 새로 백업
+        if manifest.key_id != self.crypto.as_ref().map(|c| c.key_id())
+            || manifest.keyed_hashes != self.crypto.is_some()
+        {
+            return Ok(false);
+        }
+        if manifest.files.len() != files.len() {
+            return Ok(false);
+        }
+
+        // 크기부터 비교 (WAL/JSONL은 append라 대부분 여기서 판별됨)
+        let mut touched = Vec::new();
+        for (i, ((rel, abs), file)) in files.iter().zip(&manifest.files).enumerate() {
+            let meta = fs::metadata(abs)?;
+            if *rel != file.path || meta.len() != file.size {
+                return Ok(false);
+            }
+            // 크기와 수정 시각이 그대로면 해시하지 않음
+            let modified = modified_time(&meta);
+            if modified.is_none() || modified != file.modified {
+                touched.push((i, abs, modified));
+            }
+        }
+        if touched.is_empty() {
+            return Ok(true);
+        }
+
+        let hasher = ContentHasher::new(self.crypto.as_ref())?;
+        let mut buf = Vec::with_capacity(CHUNK_SIZE);
+        for (i, abs, modified) in touched {
+            let mut source = File::open(abs).map_err(LazarusError::Io)?;
+            let mut total = hasher.clone();
+            while read_chunk(&mut source, &mut buf)? > 0 {
+                total.update(&buf);
+            }
+            if total.finalize() != manifest.files[i].sha256 {
                 return Ok(false);
             }
+            manifest.files[i].modified = modified;
+        }
+
+        // 내용은 같고 수정 시각만 바뀐 파일은 다음부터 해시하지 않도록 기록
+        manifest.save(backup_path)?;
+        Ok(true)
+    }
+
+    /// 매니페스트 암호화 키 확인 (평문 백업이면 None)
+    fn manifest_crypto(&self, manifest: &BackupManifest) -> Result<Option<&CryptoManager>> {
+        match (&manifest.key_id, &self.crypto) {
+            (None, _) => Ok(None),
+            (Some(key_id), Some(crypto)) if *key_id == crypto.key_id() => Ok(Some(crypto)),
+            // 잠겨 있거나 다른 키로 만든 백업
+            (Some(_), _) => Err(LazarusError::Decryption),
+        }
+    }
+
+    /// 매니페스트의 내용 해시 방식
+    fn manifest_hasher(
+        manifest: &BackupManifest,
+        crypto: Option<&CryptoManager>,
+    ) -> Result<ContentHasher> {
+        if manifest.keyed_hashes {
+            ContentHasher::new(crypto)
         } else {
-            let mut decoder = GzDecoder::new(&backup_data[..]);
-            let mut data = Vec::new();
-            decoder.read_to_end(&mut data).map_err(LazarusError::Io)?;
-            data
-        };
+            ContentHasher::new(None)
+        }
+    }
+
+    /// 파일의 청크를 순서대로 읽고 검증 (청크마다 `sink` 호출)
+    ///
+    /// 청크마다 내용 해시를 확인하고, 끝나면 파일 전체 크기와 해시도 확인합니다.
+    fn read_chunks(
+        &self,
+        file: &BackupFile,
+        crypto: Option<&CryptoManager>,
+        hasher: &ContentHasher,
+        mut sink: impl FnMut(&[u8]) -> Result<()>,
+    ) -> Result<()> {
+        let mut total = hasher.clone();
+        let mut size = 0u64;
+        for hash in &file.chunks {
+            let data = self.chunks.get(hash, crypto, hasher)?;
+            total.update(&data);
+            size += data.len() as u64;
+            sink(&data)?;
+        }
 
-        Ok(source_data == decompressed)
+        if size != file.size || total.finalize() != file.sha256 {
+            return Err(LazarusError::BackupIntegrity(format!(
+                "파일 해시 불일치: {}",
+                file.path
+            )));
+        }
+        Ok(())
     }
 
-    /// 복원
-    pub fn restore(&self, backup_path: &Path) -> Result<()> {
+    /// 백업의 노트 DB 내용을 `out`에 쓰기 (복호화 + 압축 해제)
+    ///
+    /// 반환: 쓴 바이트 수
+    fn read_backup(&self, backup_path: &Path, mut out: impl Write) -> Result<u64> {
         if !backup_path.exists() {
             return Err(LazarusError::NotFound(backup_path.display().to_string()));
         }
 
-        let backup_data = fs::read(backup_path).map_err(LazarusError::Io)?;
+        if is_manifest(backup_path) {
+            let manifest = BackupManifest::load(backup_path)?;
+            let crypto = self.manifest_crypto(&manifest)?;
+            let hasher = Self::manifest_hasher(&manifest, crypto)?;
+            let file = manifest.file(NOTES_FILE).ok_or_else(|| {
+                LazarusError::BackupIntegrity(format!("백업에 {} 없음", NOTES_FILE))
+            })?;
 
-        // 암호화된 백업이면 복호화
-        let compressed = if backup_path.to_string_lossy().ends_with(".enc") {
-            if let Some(ref crypto) = self.crypto {
-                crypto.decrypt(&backup_data)?
-            } else {
+            self.read_chunks(file, crypto, &hasher, |chunk| {
+                out.write_all(chunk).map_err(LazarusError::Io)
+            })?;
+            return Ok(file.size);
+        }
+
+        // 예전 암호화 백업은 파일 통째로 암호화되어 있어 한 번에 복호화
+        if backup_path.to_string_lossy().ends_with(".enc") {
+            let Some(ref crypto) = self.crypto else {
                 return Err(LazarusError::Encryption);
-            }
+            };
+            let compressed = crypto.decrypt(&fs::read(backup_path).map_err(LazarusError::Io)?)?;
+            let mut decoder = GzDecoder::new(&compressed[..]);
+            return io::copy(&mut decoder, &mut out).map_err(LazarusError::Io);
+        }
+
+        // gzip 압축 해제
+        let file = File::open(backup_path).map_err(LazarusError::Io)?;
+        let mut decoder = GzDecoder::new(BufReader::new(file));
+        io::copy(&mut decoder, &mut out).map_err(LazarusError::Io)
+    }
+
+    /// 백업 검증 (모든 파일의 청크와 전체 해시 확인)
+    ///
+    /// 반환: 원본 전체 크기
+    pub fn verify(&self, backup_path: &Path) -> Result<u64> {
+        if !is_manifest(backup_path) {
+            return self.read_backup(backup_path, io::sink());
+        }
+
+        let manifest = BackupManifest::load(backup_path)?;
+        let crypto = self.manifest_crypto(&manifest)?;
+        let hasher = Self::manifest_hasher(&manifest, crypto)?;
+        for file in &manifest.files {
+            self.read_chunks(file, crypto, &hasher, |_| Ok(()))?;
+        }
+        Ok(manifest.size())
+    }
+
+    /// 복원 (데이터 디렉토리의 백업 대상 전체 교체)
+    ///
+    /// 모든 파일을 임시 파일에 검증하며 쓴 뒤에 한꺼번에 교체하므로, 청크가
+    /// 하나라도 깨져 있으면 아무것도 바뀌지 않습니다. 백업 시점에 없던 파일은
+    /// 지웁니다. 실행 중인 저장소들이 이 파일들을 쓰고 있지 않아야 합니다.
+    /// 예전 gzip 백업은 노트 DB만 복원합니다.
+    pub fn restore(&self, backup_path: &Path) -> Result<()> {
+        if !backup_path.exists() {
+            return Err(LazarusError::NotFound(backup_path.display().to_string()));
+        }
+
+        let staged = if is_manifest(backup_path) {
+            let manifest = BackupManifest::load(backup_path)?;
+            self.stage_manifest(&manifest)
         } else {
-            backup_data
+            let temp = staging_path(&self.source_path);
+            File::create(&temp)
+                .map_err(LazarusError::Io)
+                .and_then(|file| {
+                    let mut writer = BufWriter::new(file);
+                    self.read_backup(backup_path, &mut writer)?;
+                    writer.flush().map_err(LazarusError::Io)
+                })
+                .map(|_| vec![(temp, self.source_path.clone())])
         };
+        let staged = staged?;
 
-        // gzip 압축 해제
-        let mut decoder = GzDecoder::new(&compressed[..]);
-        let mut data = Vec::new();
-        decoder.read_to_end(&mut data).map_err(LazarusError::Io)?;
+        // 백업에 없는 현재 파일 삭제 (전체 복원일 때만)
+        if is_manifest(backup_path) {
+            let keep: HashSet<&PathBuf> = staged.iter().map(|(_, target)| target).collect();
+            for (_, current) in self.data_files()? {
+                if !keep.contains(&current) {
+                    fs::remove_file(&current).map_err(LazarusError::Io)?;
+                    tracing::debug!("백업에 없는 파일 삭제: {}", current.display());
+                }
+            }
+        }
 
         // 복원
-        fs::write(&self.source_path, data).map_err(LazarusError::Io)?;
-        tracing::info!("복원 완료: {}", self.source_path.display());
+        for (temp, target) in &staged {
+            fs::rename(temp, target).map_err(LazarusError::Io)?;
+        }
+        let _ = fs::remove_file(checkpoint::path_for(&self.source_path));
+        tracing::info!(
+            "복원 완료: {} (파일 {}개)",
+            self.data_dir.display(),
+            staged.len()
+        );
 
         Ok(())
     }
 
+    /// 매니페스트의 모든 파일을 임시 파일로 풀기
+    ///
+    /// 반환: (임시 파일, 대상 경로). 실패하면 만든 임시 파일을 지웁니다.
+    fn stage_manifest(&self, manifest: &BackupManifest) -> Result<Vec<(PathBuf, PathBuf)>> {
+        let crypto = self.manifest_crypto(manifest)?;
+        let hasher = Self::manifest_hasher(manifest, crypto)?;
+        let mut staged = Vec::with_capacity(manifest.files.len());
+
+        let result = manifest.files.iter().try_for_each(|file| {
+            let target = self.data_dir.join(safe_relative_path(&file.path)?);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).map_err(LazarusError::Io)?;
+            }
+
+            let temp = staging_path(&target);
+            staged.push((temp.clone(), target));
+            let mut out = File::create(&temp).map_err(LazarusError::Io)?;
+            self.read_chunks(file, crypto, &hasher, |chunk| {
+                out.write_all(chunk).map_err(LazarusError::Io)
+            })?;
+            out.sync_all().map_err(LazarusError::Io)
+        });
+
+        if let Err(e) = result {
+            for (temp, _) in &staged {
+                let _ = fs::remove_file(temp);
+            }
+            return Err(e);
+        }
+        Ok(staged)
+    }
+
+    /// 백업을 다른 디렉토리에 한 벌 더 복사 (USB 등)
+    ///
+    /// 매니페스트와 청크를 그대로 복사해 그 디렉토리만으로도 복원할 수 있게
+    /// 합니다. 이미 있는 파일은 건너뛰고, 여기서 사라진 백업은 거기서도
+    /// 지웁니다. 대상 디렉토리가 없으면 (마운트 안 됨) 실패합니다.
+    pub fn mirror(&self, target: &Path) -> Result<MirrorReport> {
+        if !target.is_dir() {
+            return Err(LazarusError::PathNotFound(target.display().to_string()));
+        }
+
+        // 청크를 먼저 복사해야 중간에 끊겨도 매니페스트가 없는 청크를 가리키지 않음
+        let mut wanted: Vec<PathBuf> = Vec::new();
+        for chunk in self.chunks.files()? {
+            if let Ok(rel) = chunk.strip_prefix(&self.backup_dir) {
+                wanted.push(rel.to_path_buf());
+            }
+        }
+        for backup in self.list_backups()? {
+            if let Some(name) = backup.file_name() {
+                wanted.push(PathBuf::from(name));
+            }
+        }
+
+        let mut report = MirrorReport::default();
+        for rel in &wanted {
+            let src = self.backup_dir.join(rel);
+            let dst = target.join(rel);
+            let same_size = fs::metadata(&dst)
+                .ok()
+                .zip(fs::metadata(&src).ok())
+                .map(|(d, s)| d.len() == s.len())
+                .unwrap_or(false);
+            if same_size {
+                continue;
+            }
+
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent).map_err(LazarusError::Io)?;
+            }
+            let temp = dst.with_extension("tmp");
+            fs::copy(&src, &temp).map_err(LazarusError::Io)?;
+            File::open(&temp)
+                .and_then(|f| f.sync_all())
+                .map_err(LazarusError::Io)?;
+            fs::rename(&temp, &dst).map_err(LazarusError::Io)?;
+            report.copied += 1;
+        }
+
+        // 여기서 사라진 백업/청크 정리 (매니페스트 먼저)
+        let wanted: HashSet<PathBuf> = wanted.into_iter().collect();
+        let mirror = BackupManager::new(target.join(NOTES_FILE), target.to_path_buf());
+        let mut stale = mirror.list_backups()?;
+        stale.extend(mirror.chunks.files()?);
+        for path in stale {
+            let keep = path
+                .strip_prefix(target)
+                .map(|rel| wanted.contains(rel))
+                .unwrap_or(true);
+            if !keep {
+                fs::remove_file(&path).map_err(LazarusError::Io)?;
+                report.removed += 1;
+            }
+        }
+
+        tracing::info!(
+            "백업 복사 완료: {} (복사 {}개, 삭제 {}개)",
+            target.display(),
+            report.copied,
+            report.removed
+        );
+        Ok(report)
+    }
+
+    /// 모든 백업과 청크 삭제 (금고 삭제용), 반환: 지운 백업 수
+    ///
+    /// 두 번째 대상(USB 등)에 복사해 둔 백업도 지웁니다. 대상이 연결돼 있지
+    /// 않으면 그쪽은 남고 경고만 남깁니다.
+    pub fn delete_all(&self) -> Result<usize> {
+        let deleted = self.purge()?;
+        match self.schedule.mirror_dir.as_deref() {
+            Some(dir) if dir.is_dir() => {
+                BackupManager::new(dir.join(NOTES_FILE), dir.to_path_buf()).purge()?;
+            }
+            Some(dir) => tracing::warn!("두 번째 백업 대상이 없어 지우지 못함: {}", dir.display()),
+            None => {}
+        }
+        Ok(deleted)
+    }
+
+    /// 이 디렉토리의 백업과 청크 삭제, 반환: 지운 백업 수
+    fn purge(&self) -> Result<usize> {
+        let backups = self.list_backups()?;
+        for backup in &backups {
+            fs::remove_file(backup).map_err(LazarusError::Io)?;
+        }
+        self.chunks.gc(&HashSet::new())?;
+        Ok(backups.len())
+    }
+
+    /// 백업이 차지하는 디스크 크기 (매니페스트 + 청크)
+    pub fn stored_bytes(&self) -> Result<u64> {
+        let files: u64 = self
+            .list_backups()?
+            .iter()
+            .filter_map(|p| fs::metadata(p).ok())
+            .map(|m| m.len())
+            .sum();
+        Ok(files + self.chunks.stored_bytes()?)
+    }
+
     /// 백업 정보 가져오기
     pub fn info(&self) -> Result<BackupInfo> {
         let backups = self.list_backups()?;
-        let encrypted = self.crypto.is_some();
+        let encrypted = self.crypto.is_some() || self.locked;
         let latest = backups.first().cloned();
 
         Ok(BackupInfo {
@@ -234,6 +914,73 @@ impl BackupManager {
     }
 }
 
+/// 디렉토리 안의 파일을 재귀적으로 수집 (임시 파일 제외)
+fn collect_files(dir: &Path, rel: &str, files: &mut Vec<(String, PathBuf)>) -> Result<()> {
+    for entry in fs::read_dir(dir).map_err(LazarusError::Io)?.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        let child = format!("{}/{}", rel, name);
+        if path.is_dir() {
+            collect_files(&path, &child, files)?;
+        } else if !name.ends_with(".tmp") && !name.ends_with(".restore") {
+            files.push((child, path));
+        }
+    }
+    Ok(())
+}
+
+/// 매니페스트 경로 검증 (데이터 디렉토리 밖을 가리키지 못하게)
+fn safe_relative_path(path: &str) -> Result<PathBuf> {
+    let relative = PathBuf::from(path);
+    let safe = !path.is_empty()
+        && relative
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)));
+    if !safe {
+        return Err(LazarusError::BackupIntegrity(format!(
+            "잘못된 경로: {}",
+            path
+        )));
+    }
+    Ok(relative)
+}
+
+/// 복원 중 임시 파일 경로
+fn staging_path(target: &Path) -> PathBuf {
+    let mut name = target.as_os_str().to_owned();
+    name.push(".restore");
+    PathBuf::from(name)
+}
+
+/// 파일 수정 시각 (지원하지 않는 파일 시스템이면 None)
+fn modified_time(meta: &fs::Metadata) -> Option<DateTime<Utc>> {
+    meta.modified().ok().map(DateTime::<Utc>::from)
+}
+
+/// 증분 백업 매니페스트인지
+fn is_manifest(path: &Path) -> bool {
+    path.extension().map(|e| e == MANIFEST_EXT).unwrap_or(false)
+}
+
+/// 백업 파일 이름에서 생성 시각 (`notes.lazarus_20240101_120000.manifest`)
+fn backup_time(name: &str) -> Option<DateTime<Utc>> {
+    let stem = name
+        .trim_end_matches(".enc")
+        .trim_end_matches(".gz")
+        .trim_end_matches(".manifest");
+    let stamp = stem.get(stem.len().checked_sub(15)?..)?;
+    NaiveDateTime::parse_from_str(stamp, STAMP_FORMAT)
+        .ok()
+        .map(|t| t.and_utc())
+}
+
+/// 두 번째 대상 복사 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MirrorReport {
+    pub copied: usize,
+    pub removed: usize,
+}
+
 /// 백업 정보
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct BackupInfo {
@@ -241,3 +988,277 @@ pub struct BackupInfo {
     pub encrypted: bool,
     pub latest: Option<PathBuf>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_backup_time_from_name() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(
+            backup_time("notes.lazarus_20240102_030405.gz"),
+            Some(expected)
+        );
+        assert_eq!(
+            backup_time("notes.lazarus_20240102_030405.gz.enc"),
+            Some(expected)
+        );
+        assert_eq!(
+            backup_time("notes.lazarus_20240102_030405.manifest"),
+            Some(expected)
+        );
+        assert_eq!(backup_time("retention.json"), None);
+    }
+
+    #[test]
+    fn test_tiered_retention() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 20, 12, 0, 0).unwrap();
+        // 최신순: 10분 간격 6개, 그다음 하루 간격 20개
+        let mut stamps: Vec<DateTime<Utc>> = (0..6)
+            .map(|i| now - chrono::Duration::minutes(10 * i))
+            .collect();
+        stamps.extend((1..=20).map(|d| now - chrono::Duration::days(d)));
+
+        let policy = BackupRetention {
+            keep_last: 2,
+            hourly: 3,
+            daily: 5,
+            weekly: 3,
+        };
+        let keep = policy.retain(&stamps);
+
+        let kept: Vec<usize> = (0..keep.len()).filter(|i| keep[*i]).collect();
+        // 최근 2개, 시간별(12시/11시/하루 전), 일별(오늘~4일 전),
+        // 주별(이번 주/3일 전 일요일/10일 전)
+        assert_eq!(kept, vec![0, 1, 6, 7, 8, 9, 15]);
+
+        let nothing = BackupRetention {
+            keep_last: 1,
+            hourly: 0,
+            daily: 0,
+            weekly: 0,
+        };
+        assert_eq!(nothing.retain(&stamps).iter().filter(|k| **k).count(), 1);
+    }
+
+    #[test]
+    fn test_incremental_backup_and_verified_restore() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let source = tmp.path().join("notes.lazarus");
+        let manager = BackupManager::new(source.clone(), tmp.path().join("backups"));
+
+        // 청크 2개 반 분량
+        let mut data: Vec<u8> = (0..CHUNK_SIZE * 5 / 2).map(|i| (i % 251) as u8).collect();
+        fs::write(&source, &data).unwrap();
+        let first = manager.backup().unwrap().unwrap();
+        assert!(manager.backup().unwrap().is_none());
+
+        // 크기와 수정 시각이 그대로면 다시 해시하지 않고, 수정 시각만 바뀌면
+        // 해시로 실제 변경 여부를 확인
+        let modified = fs::metadata(&source).unwrap().modified().unwrap();
+        let mut same_size = data.clone();
+        same_size[0] ^= 0xff;
+        fs::write(&source, &same_size).unwrap();
+        File::options()
+            .write(true)
+            .open(&source)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert!(manager.backup().unwrap().is_none());
+        fs::write(&source, &data).unwrap();
+        assert!(manager.backup().unwrap().is_none());
+        assert_eq!(
+            BackupManifest::load(&first).unwrap().files[0].modified,
+            modified_time(&fs::metadata(&source).unwrap())
+        );
+
+        // 뒤에 덧붙이면 앞의 청크는 재사용
+        data.extend_from_slice(b"appended");
+        fs::write(&source, &data).unwrap();
+        fs::rename(
+            &first,
+            first.with_file_name("notes.lazarus_20000101_000000.manifest"),
+        )
+        .unwrap();
+        let second = manager.backup().unwrap().unwrap();
+        let manifest = BackupManifest::load(&second).unwrap();
+        let chunks = &manifest.file(NOTES_FILE).unwrap().chunks;
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(manifest.new_chunks, 1);
+        assert_eq!(manager.verify(&second).unwrap(), data.len() as u64);
+
+        fs::write(&source, b"broken").unwrap();
+        manager.restore(&second).unwrap();
+        assert_eq!(fs::read(&source).unwrap(), data);
+
+        // 청크가 깨지면 복원하지 않고 원본 유지
+        let chunk = manager.chunks.chunk_path(&chunks[2], None);
+        fs::write(&chunk, zstd::encode_all(&b"tampered"[..], 3).unwrap()).unwrap();
+        fs::write(&source, b"current").unwrap();
+        assert!(matches!(
+            manager.restore(&second),
+            Err(LazarusError::BackupIntegrity(_))
+        ));
+        assert_eq!(fs::read(&source).unwrap(), b"current");
+        assert!(manager.verify(&second).is_err());
+    }
+
+    #[test]
+    fn test_encrypted_backup_needs_matching_key() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let source = tmp.path().join("notes.lazarus");
+        fs::write(&source, b"secret wal").unwrap();
+
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+        let mut manager = BackupManager::new(source.clone(), tmp.path().join("backups"))
+            .with_crypto(crypto.clone());
+        let path = manager.backup().unwrap().unwrap();
+        assert!(manager.entries().unwrap()[0].encrypted);
+        let mut data = Vec::new();
+        manager.read_backup(&path, &mut data).unwrap();
+        assert_eq!(data, b"secret wal");
+
+        // 매니페스트와 청크 이름에 평문 SHA-256이 드러나지 않음
+        let plain = ContentHasher::new(None).unwrap();
+        let plain_hash = plain.hash(b"secret wal");
+        let manifest = BackupManifest::load(&path).unwrap();
+        assert!(manifest.keyed_hashes);
+        assert!(!fs::read_to_string(&path).unwrap().contains(&plain_hash));
+
+        // 키 해시 이전의 암호화 백업도 계속 읽힘
+        let (chunk, _) = manager
+            .chunks
+            .put(b"old wal", Some(&crypto), &plain)
+            .unwrap();
+        let legacy = BackupManifest {
+            keyed_hashes: false,
+            files: vec![BackupFile {
+                path: NOTES_FILE.to_string(),
+                size: 7,
+                sha256: plain.hash(b"old wal"),
+                modified: None,
+                chunks: vec![chunk],
+            }],
+            ..manifest
+        };
+        let legacy_path = tmp.path().join(format!("legacy.{}", MANIFEST_EXT));
+        legacy.save(&legacy_path).unwrap();
+        let mut data = Vec::new();
+        manager.read_backup(&legacy_path, &mut data).unwrap();
+        assert_eq!(data, b"old wal");
+
+        manager.set_crypto(None);
+        assert!(matches!(
+            manager.read_backup(&path, io::sink()),
+            Err(LazarusError::Decryption)
+        ));
+    }
+
+    #[test]
+    fn test_locked_backup_is_deferred() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let source = tmp.path().join("notes.lazarus");
+        fs::write(&source, b"secret wal").unwrap();
+
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+        let mut manager = BackupManager::new(source.clone(), tmp.path().join("backups"))
+            .with_crypto(crypto.clone());
+        manager.backup().unwrap().unwrap();
+
+        // 잠긴 동안에는 평문으로 쓰지 않고 건너뜀
+        manager.lock();
+        fs::write(&source, b"secret wal, more").unwrap();
+        assert!(manager.backup().unwrap().is_none());
+        assert_eq!(manager.list_backups().unwrap().len(), 1);
+        assert!(manager.info().unwrap().encrypted);
+
+        // 같은 키로 다시 열면 이어서 암호화 백업
+        manager.set_crypto(Some(crypto));
+        manager.backup().unwrap().unwrap();
+        assert!(manager.entries().unwrap().iter().all(|e| e.encrypted));
+    }
+
+    #[test]
+    fn test_backup_covers_data_dir() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let data = tmp.path();
+        let manager = BackupManager::new(data.join("notes.lazarus"), data.join("backups"));
+
+        fs::write(data.join("notes.lazarus"), b"wal v1").unwrap();
+        fs::write(data.join("srs.jsonl"), b"{\"card\":1}\n").unwrap();
+        fs::write(data.join("posts.jsonl"), b"{\"post\":1}\n").unwrap();
+        fs::create_dir_all(data.join("packages")).unwrap();
+        fs::write(data.join("packages/a.laz"), b"package a").unwrap();
+        // 백업 대상이 아닌 파일
+        fs::create_dir_all(data.join("index")).unwrap();
+        fs::write(data.join("index/meta.json"), b"{}").unwrap();
+
+        let path = manager.backup().unwrap().unwrap();
+        let manifest = BackupManifest::load(&path).unwrap();
+        let paths: Vec<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "notes.lazarus",
+                "packages/a.laz",
+                "posts.jsonl",
+                "srs.jsonl"
+            ]
+        );
+
+        // 모든 저장소를 바꾸고 새 파일도 추가
+        fs::write(data.join("notes.lazarus"), b"wal v2").unwrap();
+        fs::write(data.join("srs.jsonl"), b"").unwrap();
+        fs::remove_file(data.join("posts.jsonl")).unwrap();
+        fs::write(data.join("packages/b.laz"), b"package b").unwrap();
+        fs::write(data.join("questions.jsonl"), b"{}\n").unwrap();
+
+        manager.restore(&path).unwrap();
+        assert_eq!(fs::read(data.join("notes.lazarus")).unwrap(), b"wal v1");
+        assert_eq!(fs::read(data.join("srs.jsonl")).unwrap(), b"{\"card\":1}\n");
+        assert_eq!(
+            fs::read(data.join("posts.jsonl")).unwrap(),
+            b"{\"post\":1}\n"
+        );
+        assert!(data.join("packages/a.laz").exists());
+        assert!(!data.join("packages/b.laz").exists());
+        assert!(!data.join("questions.jsonl").exists());
+        assert!(data.join("index/meta.json").exists());
+
+        let mut tampered = manifest.clone();
+        tampered.files[0].path = "../escape".to_string();
+        assert!(manager.stage_manifest(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_mirror_to_second_target() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let data = tmp.path().join("data");
+        let usb = tmp.path().join("usb");
+        fs::create_dir_all(&data).unwrap();
+        let manager = BackupManager::new(data.join("notes.lazarus"), data.join("backups"));
+
+        fs::write(data.join("notes.lazarus"), b"wal").unwrap();
+        manager.backup().unwrap().unwrap();
+
+        // 마운트되지 않은 대상
+        assert!(manager.mirror(&usb).is_err());
+
+        fs::create_dir_all(&usb).unwrap();
+        let report = manager.mirror(&usb).unwrap();
+        assert_eq!(report.copied, 2);
+        assert_eq!(manager.mirror(&usb).unwrap().copied, 0);
+
+        // 복사본만으로 복원 가능
+        let copy = BackupManager::new(data.join("notes.lazarus"), usb.clone());
+        let name = copy.entries().unwrap()[0].path.clone();
+        assert_eq!(copy.verify(&name).unwrap(), 3);
+
+        // 주 백업에서 사라진 파일은 복사본에서도 정리
+        fs::write(usb.join("notes.lazarus_20000101_000000.manifest"), b"{}").unwrap();
+        assert_eq!(manager.mirror(&usb).unwrap().removed, 1);
+    }
+}
@@ -0,0 +1,144 @@
+//! 인덱스 체크포인트
+//!
+//! 시작할 때마다 WAL 전체를 다시 읽지 않도록 `index`/`revisions`/벡터 캐시를
+//! `notes.lazarus.idx`에 저장합니다. 체크포인트가 가리키는 WAL 오프셋 이후의
+//! 꼬리만 재생하면 됩니다.
+//!
+//! 파일 형식: `LAZCKPT\x01` + CRC32(4) + rkyv 페이로드.
+//! CRC가 맞지 않거나 WAL이 바뀌었으면 (compaction, 백업 복원 등) 무시하고
+//! 전체 재생으로 돌아갑니다.
+
+use bytecheck::CheckBytes;
+use crc32fast::Hasher;
+use rkyv::{Archive, Deserialize, Serialize};
+use std::io::Read;
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+
+use crate::error::{LazarusError, Result};
+
+/// 체크포인트 파일 매직 바이트
+const MAGIC: &[u8; 8] = b"LAZCKPT\x01";
+
+/// WAL 지문에 쓰는 꼬리 길이
+const TAIL_WINDOW: u64 = 256;
+
+/// 저장된 인덱스 상태
+#[derive(Archive, Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[archive_attr(derive(CheckBytes, Debug))]
+pub struct Checkpoint {
+    /// 이 오프셋까지의 WAL이 반영됨
+    pub wal_offset: u64,
+    /// WAL의 `wal_offset` 직전 바이트 CRC (파일이 바뀌었는지 확인용)
+    pub tail_crc: u32,
+    pub next_id: u64,
+    /// (ID, 최신 헤더 오프셋)
+    pub index: Vec<(u64, u64)>,
+    /// (ID, 리비전 헤더 오프셋들)
+    pub revisions: Vec<(u64, Vec<u64>)>,
+    /// (ID, 벡터)
+    pub vectors: Vec<(u64, Vec<i8>)>,
+}
+
+/// WAL 파일에 대한 체크포인트 경로
+pub fn path_for(wal_path: &Path) -> PathBuf {
+    wal_path.with_extension("lazarus.idx")
+}
+
+/// WAL 파일의 `offset` 직전 꼬리 CRC
+pub fn tail_crc(wal: &std::fs::File, offset: u64) -> Result<u32> {
+    let start = offset.saturating_sub(TAIL_WINDOW);
+    let mut buf = vec![0u8; (offset - start) as usize];
+
+    #[cfg(unix)]
+    wal.read_exact_at(&mut buf, start)?;
+    #[cfg(not(unix))]
+    {
+        use std::io::{Seek, SeekFrom};
+        let mut handle = wal;
+        handle.seek(SeekFrom::Start(start))?;
+        handle.read_exact(&mut buf)?;
+    }
+
+    let mut hasher = Hasher::new();
+    hasher.update(&buf);
+    Ok(hasher.finalize())
+}
+
+impl Checkpoint {
+    /// 체크포인트 바이트 (매직 + CRC + 페이로드)
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let payload =
+            rkyv::to_bytes::<_, 4096>(self).map_err(|e| LazarusError::Serialize(e.to_string()))?;
+
+        let mut hasher = Hasher::new();
+        hasher.update(&payload);
+
+        let mut bytes = Vec::with_capacity(12 + payload.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&hasher.finalize().to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// 체크포인트 파싱 (매직/CRC/구조 검증)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < 12 || &bytes[..8] != MAGIC {
+            return Err(LazarusError::Deserialize(
+                "체크포인트 매직 불일치".to_string(),
+            ));
+        }
+
+        let expected = u32::from_le_bytes(bytes[8..12].try_into().unwrap());
+        let payload = &bytes[12..];
+        let mut hasher = Hasher::new();
+        hasher.update(payload);
+        let actual = hasher.finalize();
+        if actual != expected {
+            return Err(LazarusError::DbCorruption { expected, actual });
+        }
+
+        // rkyv는 정렬된 버퍼가 필요
+        let mut aligned = rkyv::AlignedVec::with_capacity(payload.len());
+        aligned.extend_from_slice(payload);
+        rkyv::from_bytes::<Self>(&aligned).map_err(|e| LazarusError::Deserialize(e.to_string()))
+    }
+
+    /// 파일에서 읽기 (없으면 None)
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let mut file = match std::fs::File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Self::from_bytes(&bytes).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_roundtrip_and_crc() {
+        let checkpoint = Checkpoint {
+            wal_offset: 1234,
+            tail_crc: 42,
+            next_id: 3,
+            index: vec![(1, 8), (2, 100)],
+            revisions: vec![(1, vec![8]), (2, vec![50, 100])],
+            vectors: vec![(1, vec![1, -2, 3])],
+        };
+
+        let mut bytes = checkpoint.to_bytes().unwrap();
+        assert_eq!(Checkpoint::from_bytes(&bytes).unwrap(), checkpoint);
+
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        assert!(Checkpoint::from_bytes(&bytes).is_err());
+        assert!(Checkpoint::from_bytes(b"LAZARUS\x02").is_err());
+    }
+}
@@ -0,0 +1,260 @@
+//! 백업 청크 저장소 (content-addressed)
+//!
+//! WAL은 append-only라 앞부분 청크는 백업마다 그대로입니다. 고정 크기로
+//! 자른 청크를 내용 해시로 저장해, 새 백업은 새로 생긴 청크만 씁니다.
+//! 내용 해시는 평문 백업이면 SHA-256, 암호화 백업이면 데이터 키에서 유도한
+//! HMAC-SHA256입니다 (`ContentHasher`).
+//!
+//! 청크 파일: `chunks/<해시 앞 2자리>/<해시>.zst` (평문)
+//! 또는 `<해시>.<키 ID>.enc` (zstd 후 암호화)
+
+use hkdf::hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use crate::crypto::CryptoManager;
+use crate::error::{LazarusError, Result};
+
+/// 청크 크기 (1 MiB)
+pub const CHUNK_SIZE: usize = 1024 * 1024;
+
+/// 청크 저장소
+pub struct ChunkStore {
+    dir: PathBuf,
+}
+
+impl ChunkStore {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// 청크 파일 경로
+    pub(super) fn chunk_path(&self, hash: &str, key_id: Option<&str>) -> PathBuf {
+        let name = match key_id {
+            Some(key_id) => format!("{}.{}.enc", hash, key_id),
+            None => format!("{}.zst", hash),
+        };
+        self.dir.join(&hash[..2]).join(name)
+    }
+
+    /// 청크 저장 (이미 있으면 건너뜀)
+    ///
+    /// 반환: (해시, 새로 썼는지)
+    pub fn put(
+        &self,
+        data: &[u8],
+        crypto: Option<&CryptoManager>,
+        hasher: &ContentHasher,
+    ) -> Result<(String, bool)> {
+        let hash = hasher.hash(data);
+        let key_id = crypto.map(|c| c.key_id());
+        let path = self.chunk_path(&hash, key_id.as_deref());
+        if path.exists() {
+            return Ok((hash, false));
+        }
+
+        let compressed = zstd::encode_all(data, 3).map_err(LazarusError::Io)?;
+        let stored = match crypto {
+            Some(c) => c.encrypt(&compressed)?,
+            None => compressed,
+        };
+
+        // 임시 파일에 쓴 뒤 rename (중간에 끊겨도 반쪽 청크가 남지 않음)
+        let parent = path.parent().unwrap_or(&self.dir);
+        fs::create_dir_all(parent)?;
+        let temp = path.with_extension("tmp");
+        fs::write(&temp, &stored)?;
+        fs::rename(&temp, &path)?;
+
+        Ok((hash, true))
+    }
+
+    /// 청크 읽기 (해시 검증)
+    pub fn get(
+        &self,
+        hash: &str,
+        crypto: Option<&CryptoManager>,
+        hasher: &ContentHasher,
+    ) -> Result<Vec<u8>> {
+        if hash.len() < 2 {
+            return Err(LazarusError::BackupIntegrity(format!(
+                "잘못된 청크 해시: {}",
+                hash
+            )));
+        }
+
+        let key_id = crypto.map(|c| c.key_id());
+        let path = self.chunk_path(hash, key_id.as_deref());
+        let stored = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(LazarusError::BackupIntegrity(format!(
+                    "청크 없음: {}",
+                    hash
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let compressed = match crypto {
+            Some(c) => c.decrypt(&stored)?,
+            None => stored,
+        };
+        let data = zstd::decode_all(&compressed[..])
+            .map_err(|_| LazarusError::BackupIntegrity(format!("청크 압축 해제 실패: {}", hash)))?;
+
+        if hasher.hash(&data) != hash {
+            return Err(LazarusError::BackupIntegrity(format!(
+                "청크 해시 불일치: {}",
+                hash
+            )));
+        }
+        Ok(data)
+    }
+
+    /// 참조되지 않는 청크 삭제
+    ///
+    /// 반환: 삭제한 청크 수
+    pub fn gc(&self, live: &HashSet<String>) -> Result<usize> {
+        let mut removed = 0;
+        for path in self.files()? {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let hash = name.split('.').next().unwrap_or("");
+            if !live.contains(hash) {
+                fs::remove_file(&path)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// 저장된 청크 총 크기
+    pub fn stored_bytes(&self) -> Result<u64> {
+        Ok(self
+            .files()?
+            .iter()
+            .filter_map(|p| fs::metadata(p).ok())
+            .map(|m| m.len())
+            .sum())
+    }
+
+    /// 모든 청크 파일
+    pub(super) fn files(&self) -> Result<Vec<PathBuf>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        for prefix in fs::read_dir(&self.dir)?.flatten() {
+            if !prefix.path().is_dir() {
+                continue;
+            }
+            for entry in fs::read_dir(prefix.path())?.flatten() {
+                files.push(entry.path());
+            }
+        }
+        Ok(files)
+    }
+}
+
+/// 청크 크기만큼 읽기 (파일 끝이면 더 짧음)
+pub fn read_chunk<R: Read>(reader: &mut R, buf: &mut Vec<u8>) -> Result<usize> {
+    buf.clear();
+    reader
+        .take(CHUNK_SIZE as u64)
+        .read_to_end(buf)
+        .map_err(LazarusError::Io)
+}
+
+/// 내용 해시 (청크 이름, 매니페스트의 파일 해시)
+#[derive(Clone)]
+pub enum ContentHasher {
+    /// 평문 백업 (그리고 키 해시 이전의 암호화 백업)
+    Plain(Sha256),
+    /// 암호화 백업: 데이터 키에서 유도한 HMAC-SHA256
+    Keyed(Hmac<Sha256>),
+}
+
+impl ContentHasher {
+    /// 백업 암호화 키에 맞는 해시
+    pub fn new(crypto: Option<&CryptoManager>) -> Result<Self> {
+        match crypto {
+            Some(c) => Ok(Self::Keyed(c.content_mac()?)),
+            None => Ok(Self::Plain(Sha256::new())),
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Plain(h) => Digest::update(h, data),
+            Self::Keyed(m) => Mac::update(m, data),
+        }
+    }
+
+    /// 16진수 해시
+    pub fn finalize(self) -> String {
+        match self {
+            Self::Plain(h) => format!("{:x}", h.finalize()),
+            Self::Keyed(m) => format!("{:x}", m.finalize().into_bytes()),
+        }
+    }
+
+    /// 한 번에 해시 (`self`는 빈 상태 그대로 둠)
+    pub fn hash(&self, data: &[u8]) -> String {
+        let mut hasher = self.clone();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_chunk_dedup_and_verify() {
+        let tmp = TempDir::new().unwrap();
+        let store = ChunkStore::new(tmp.path().join("chunks"));
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+
+        let plain = ContentHasher::new(None).unwrap();
+        let keyed = ContentHasher::new(Some(&crypto)).unwrap();
+
+        let (hash, new) = store.put(b"hello", None, &plain).unwrap();
+        assert!(new);
+        assert_eq!(hash, format!("{:x}", Sha256::digest(b"hello")));
+        assert!(!store.put(b"hello", None, &plain).unwrap().1);
+        assert_eq!(store.get(&hash, None, &plain).unwrap(), b"hello");
+
+        // 암호화 청크 이름은 키 해시라 평문 SHA-256으로 맞춰볼 수 없고,
+        // 다른 키로는 읽을 수 없음
+        let (enc_hash, new) = store.put(b"hello", Some(&crypto), &keyed).unwrap();
+        assert_ne!(enc_hash, hash);
+        assert!(new);
+        assert_eq!(
+            store.get(&enc_hash, Some(&crypto), &keyed).unwrap(),
+            b"hello"
+        );
+        let other = CryptoManager::from_pin("9999", &CryptoManager::generate_salt()).unwrap();
+        let other_hasher = ContentHasher::new(Some(&other)).unwrap();
+        assert_ne!(other_hasher.hash(b"hello"), enc_hash);
+        assert!(store.get(&enc_hash, Some(&other), &other_hasher).is_err());
+
+        // 청크 내용이 바뀌면 해시 검증 실패
+        let path = store.chunk_path(&hash, None);
+        fs::write(&path, zstd::encode_all(&b"HELLO"[..], 3).unwrap()).unwrap();
+        assert!(matches!(
+            store.get(&hash, None, &plain),
+            Err(LazarusError::BackupIntegrity(_))
+        ));
+
+        assert_eq!(store.gc(&HashSet::new()).unwrap(), 2);
+        assert_eq!(store.stored_bytes().unwrap(), 0);
+    }
+}
@@ -2,20 +2,36 @@
 //!
 //! LazarusDB의 핵심 - WAL 기반 append-only 저장소
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{BufRead, BufReader, Write};
 #[cfg(unix)]
 use std::os::unix::fs::FileExt;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use super::checkpoint::{self, Checkpoint};
+use super::history::{superseded_by_encryption, RetentionPolicy, Revision};
 use super::note::{Note, NoteAtom};
-use super::wal::{WalReader, WalWriter, ENTRY_HEADER_SIZE};
+use super::repair;
+use super::wal::{self, Durability, WalReader, WalWriter, ENTRY_HEADER_SIZE};
 use crate::crypto::CryptoManager;
 use crate::error::{LazarusError, Result};
+use crate::search::cosine;
 /// 버퍼 크기 (4KB)
 const BUFFER_SIZE: usize = 4096;
 
+/// compaction 임시 파일 확장자
+const COMPACT_TEMP_EXT: &str = "lazarus.tmp";
+
+/// 마이그레이션 임시 파일 확장자
+const MIGRATE_TEMP_EXT: &str = "lazarus.migrate";
+
+/// 체크포인트 임시 파일 확장자
+const CHECKPOINT_TEMP_EXT: &str = "lazarus.idx.tmp";
+
+/// 시작 시 이만큼 넘게 재생했으면 바로 체크포인트를 새로 씀
+const CHECKPOINT_REPLAY_THRESHOLD: usize = 1000;
+
 /// 스토리지 엔진
 pub struct StorageEngine {
     /// 데이터 파일 경로
@@ -30,11 +46,31 @@ pub struct StorageEngine {
     /// ID -> (헤더 오프셋) 인덱스
     index: HashMap<u64, u64>,
 
+    /// ID -> 리비전 헤더 오프셋 목록 (오래된 순, 마지막이 index와 같음)
+    revisions: HashMap<u64, Vec<u64>>,
+
     /// 벡터 캐시 (검색용)
-    vector_cache: Vec<(u64, Vec<i8>)>,
+    vector_cache: HashMap<u64, Vec<i8>>,
 
     /// 다음 ID
     next_id: AtomicU64,
+
+    /// 내구성 모드
+    durability: Durability,
+
+    /// batch 안에서는 fsync를 batch 끝으로 미룸
+    in_batch: bool,
+
+    /// 마지막 복구 때 재생한 WAL 엔트리 수
+    replayed: usize,
+
+    /// 복구 중 손상을 만남 (`repair` 필요)
+    needs_repair: bool,
+
+    /// 지금 키로 열리지 않는 암호화 노트 (다른 금고 것, 메모리에만 있음)
+    ///
+    /// 목록/조회/삭제에서 없는 노트처럼 다루고, compaction은 그대로 보존합니다.
+    hidden: HashSet<u64>,
 }
 
 impl StorageEngine {
@@ -50,6 +86,22 @@ impl StorageEngine {
             std::fs::create_dir_all(parent)?;
         }
 
+        // 중단된 compaction/마이그레이션의 임시 파일 정리 (원본은 그대로임)
+        for ext in [COMPACT_TEMP_EXT, MIGRATE_TEMP_EXT, CHECKPOINT_TEMP_EXT] {
+            let temp = path.with_extension(ext);
+            if temp.exists() {
+                tracing::warn!("중단된 작업의 임시 파일 삭제: {}", temp.display());
+                std::fs::remove_file(&temp)?;
+            }
+        }
+
+        // 이전 버전 파일이면 먼저 마이그레이션
+        if let Some(version) = wal::file_version(path_str)? {
+            if version < wal::VERSION {
+                migrate(&path, version)?;
+            }
+        }
+
         let writer = WalWriter::open(path_str, BUFFER_SIZE)?;
         let read_handle = std::fs::File::open(&path)?;
 
@@ -58,13 +110,26 @@ impl StorageEngine {
             writer,
             read_handle,
             index: HashMap::new(),
-            vector_cache: Vec::new(),
+            revisions: HashMap::new(),
+            vector_cache: HashMap::new(),
             next_id: AtomicU64::new(1),
+            durability: Durability::default(),
+            in_batch: false,
+            replayed: 0,
+            needs_repair: false,
+            hidden: HashSet::new(),
         };
 
         // 기존 데이터 복구
         engine.recover()?;
 
+        // 재생한 꼬리가 길면 다음 시작을 위해 체크포인트 갱신
+        if engine.replayed >= CHECKPOINT_REPLAY_THRESHOLD {
+            if let Err(e) = engine.checkpoint() {
+                tracing::warn!("체크포인트 저장 실패: {}", e);
+            }
+        }
+
         tracing::info!(
             "StorageEngine 초기화 완료: {} 개의 노트 로드됨",
             engine.index.len()
@@ -92,6 +157,20 @@ impl StorageEngine {
         let mut recovered = 0usize;
         let mut corrupted = 0usize;
 
+        // 유효한 체크포인트가 있으면 그 이후 꼬리만 재생
+        if let Some(checkpoint) = self.load_checkpoint() {
+            reader.seek(checkpoint.wal_offset)?;
+            max_id = checkpoint.next_id.saturating_sub(1);
+            self.index = checkpoint.index.into_iter().collect();
+            self.revisions = checkpoint.revisions.into_iter().collect();
+            self.vector_cache = checkpoint.vectors.into_iter().collect();
+            tracing::info!(
+                "체크포인트 로드: {}개 노트, 오프셋 {} 이후만 재생",
+                self.index.len(),
+                checkpoint.wal_offset
+            );
+        }
+
         loop {
             match reader.next_entry() {
                 Ok(Some((entry_offset, entry))) => {
@@ -105,16 +184,21 @@ impl StorageEngine {
                     // 삭제된 노트는 인덱스에서 제외
                     if archived.deleted {
                         self.index.remove(&id);
-                        self.vector_cache.retain(|(vid, _)| *vid != id);
+                        self.revisions.remove(&id);
+                        self.vector_cache.remove(&id);
                     } else {
                         // 헤더 오프셋 저장 (데이터 읽을 때 여기서부터 읽음)
                         self.index.insert(id, entry_offset);
-
-                        // 벡터 캐시 업데이트
-                        if let rkyv::option::ArchivedOption::Some(ref vec) = archived.vector {
-                            let vec_copy: Vec<i8> = vec.iter().copied().collect();
-                            self.vector_cache.retain(|(vid, _)| *vid != id);
-                            self.vector_cache.push((id, vec_copy));
+                        self.revisions.entry(id).or_default().push(entry_offset);
+
+                        // 벡터 캐시 업데이트 (save와 같이 벡터 없는 버전이면 이전 벡터도 버림)
+                        match archived.vector {
+                            rkyv::option::ArchivedOption::Some(ref vec) => {
+                                self.vector_cache.insert(id, vec.iter().copied().collect());
+                            }
+                            rkyv::option::ArchivedOption::None => {
+                                self.vector_cache.remove(&id);
+                            }
                         }
                     }
 
@@ -126,17 +210,25 @@ impl StorageEngine {
                 Ok(None) => break,
                 Err(LazarusError::DbCorruption { .. }) => {
                     corrupted += 1;
+                    self.needs_repair = true;
                     tracing::warn!("손상된 엔트리 발견, 건너뜀");
                     continue;
                 }
                 Err(e) => {
-                    tracing::error!("복구 중 에러: {}", e);
+                    // 헤더가 깨지면 다음 엔트리 위치를 알 수 없음 → 이후 노트는 repair로 살려야 함
+                    self.needs_repair = true;
+                    tracing::error!(
+                        "복구 중 에러 (오프셋 {} 이후 읽지 못함, `lazarus repair`로 복구하세요): {}",
+                        reader.offset(),
+                        e
+                    );
                     break;
                 }
             }
         }
 
         self.next_id.store(max_id + 1, Ordering::SeqCst);
+        self.replayed = recovered + corrupted;
 
         if corrupted > 0 {
             tracing::warn!("복구 완료: {} 노트 로드, {} 손상됨", recovered, corrupted);
@@ -147,6 +239,59 @@ impl StorageEngine {
         Ok(())
     }
 
+    /// 현재 WAL과 맞는 체크포인트 (없거나 어긋나면 None → 전체 재생)
+    fn load_checkpoint(&self) -> Option<Checkpoint> {
+        let path = checkpoint::path_for(&self.path);
+        let checkpoint = match Checkpoint::load(&path) {
+            Ok(Some(c)) => c,
+            Ok(None) => return None,
+            Err(e) => {
+                tracing::warn!("체크포인트 손상, 전체 재생: {}", e);
+                return None;
+            }
+        };
+
+        let file_len = self.read_handle.metadata().map(|m| m.len()).unwrap_or(0);
+        let matches = checkpoint.wal_offset <= file_len
+            && checkpoint::tail_crc(&self.read_handle, checkpoint.wal_offset).ok()
+                == Some(checkpoint.tail_crc);
+        if !matches {
+            tracing::warn!("체크포인트가 현재 WAL과 맞지 않음, 전체 재생");
+            return None;
+        }
+        Some(checkpoint)
+    }
+
+    /// 인덱스 체크포인트 저장 (다음 시작 때 WAL 꼬리만 재생)
+    pub fn checkpoint(&mut self) -> Result<()> {
+        self.writer.flush()?;
+        let wal_offset = self.writer.offset();
+
+        let checkpoint = Checkpoint {
+            wal_offset,
+            tail_crc: checkpoint::tail_crc(&self.read_handle, wal_offset)?,
+            next_id: self.next_id.load(Ordering::SeqCst),
+            index: self.index.iter().map(|(&id, &o)| (id, o)).collect(),
+            revisions: self
+                .revisions
+                .iter()
+                .map(|(&id, offsets)| (id, offsets.clone()))
+                .collect(),
+            vectors: self
+                .vector_cache
+                .iter()
+                .map(|(&id, v)| (id, v.clone()))
+                .collect(),
+        };
+
+        let temp = self.path.with_extension(CHECKPOINT_TEMP_EXT);
+        std::fs::write(&temp, checkpoint.to_bytes()?)?;
+        replace_atomically(&temp, &checkpoint::path_for(&self.path))?;
+
+        tracing::debug!("체크포인트 저장: 오프셋 {}", wal_offset);
+        Ok(())
+    }
+
     /// 노트 저장
     pub fn save(&mut self, note: &Note, vector: Option<Vec<i8>>) -> Result<u64> {
         let id = if note.id == 0 {
@@ -185,15 +330,15 @@ impl StorageEngine {
 
         // 인덱스에 헤더 오프셋 저장
         self.index.insert(id, entry_offset);
+        self.revisions.entry(id).or_default().push(entry_offset);
 
-        // 벡터 캐시 업데이트
-        if let Some(v) = vector {
-            self.vector_cache.retain(|(vid, _)| *vid != id);
-            self.vector_cache.push((id, v));
-        }
+        // 벡터 캐시 업데이트 (벡터 없이 저장되면 이전 벡터도 버림)
+        match vector {
+            Some(v) => self.vector_cache.insert(id, v),
+            None => self.vector_cache.remove(&id),
+        };
 
-        // 즉시 플러시
-        self.writer.flush()?;
+        self.commit()?;
 
         tracing::debug!("노트 저장: id={}, offset={}", id, entry_offset);
 
@@ -258,15 +403,15 @@ impl StorageEngine {
 
         // 인덱스에 저장
         self.index.insert(id, entry_offset);
+        self.revisions.entry(id).or_default().push(entry_offset);
 
-        // 벡터 캐시 업데이트
-        if let Some(v) = vector {
-            self.vector_cache.retain(|(vid, _)| *vid != id);
-            self.vector_cache.push((id, v));
-        }
+        // 벡터 캐시 업데이트 (벡터 없이 저장되면 이전 벡터도 버림)
+        match vector {
+            Some(v) => self.vector_cache.insert(id, v),
+            None => self.vector_cache.remove(&id),
+        };
 
-        // 즉시 플러시
-        self.writer.flush()?;
+        self.commit()?;
         tracing::debug!("노트 저장 (암호화={}): id={}", is_encrypted, id);
 
         Ok(id)
@@ -274,103 +419,105 @@ impl StorageEngine {
 
     /// 노트 로드 (복호화 지원)
     pub fn get_decrypted(&self, id: u64, crypto: Option<&CryptoManager>) -> Result<Option<Note>> {
+        if self.hidden.contains(&id) {
+            return Ok(None);
+        }
         let header_offset = match self.index.get(&id) {
             Some(&o) => o,
             None => return Ok(None),
         };
 
-        // 헤더에서 길이 읽기
-        let mut len_buf = [0u8; 4];
-        #[cfg(unix)]
-        self.read_handle.read_at(&mut len_buf, header_offset)?;
-        #[cfg(not(unix))]
-        {
-            use std::io::{Read, Seek, SeekFrom};
-            let mut handle = &self.read_handle;
-            handle.seek(SeekFrom::Start(header_offset))?;
-            handle.read_exact(&mut len_buf)?;
-        }
+        let atom = self.read_atom(header_offset)?;
+        decode_atom(atom, crypto)
+    }
 
-        let len = u32::from_le_bytes(len_buf) as usize;
-        let data_offset = header_offset + ENTRY_HEADER_SIZE as u64;
+    /// 노트의 리비전 목록 (오래된 순, 암호화 전 평문 리비전은 빠짐)
+    ///
+    /// 리비전 번호는 빠진 리비전을 포함해 매깁니다.
+    pub fn history(&self, id: u64) -> Result<Vec<Revision>> {
+        Ok(self
+            .revision_atoms(id)?
+            .into_iter()
+            .map(|(revision, _)| revision)
+            .collect())
+    }
 
-        // 데이터 읽기
-        let mut buffer = vec![0u8; len];
-        #[cfg(unix)]
-        self.read_handle.read_at(&mut buffer, data_offset)?;
-        #[cfg(not(unix))]
-        {
-            use std::io::{Read, Seek, SeekFrom};
-            let mut handle = &self.read_handle;
-            handle.seek(SeekFrom::Start(data_offset))?;
-            handle.read_exact(&mut buffer)?;
+    /// 리비전 목록과 각 리비전 내용 (`history`와 같은 규칙, 엔트리마다 한 번만 읽음)
+    ///
+    /// 내용은 키가 없어 열 수 없으면 None입니다.
+    pub fn history_notes(
+        &self,
+        id: u64,
+        crypto: Option<&CryptoManager>,
+    ) -> Result<Vec<(Revision, Option<Note>)>> {
+        self.revision_atoms(id)?
+            .into_iter()
+            .map(|(revision, atom)| Ok((revision, decode_atom(atom, crypto)?)))
+            .collect()
+    }
+
+    /// 보여 줄 수 있는 리비전과 그 원자 (암호화 전 평문 리비전은 빠짐)
+    fn revision_atoms(&self, id: u64) -> Result<Vec<(Revision, NoteAtom)>> {
+        if self.hidden.contains(&id) {
+            return Ok(Vec::new());
         }
+        let Some(offsets) = self.revisions.get(&id) else {
+            return Ok(Vec::new());
+        };
 
-        // rkyv 역직렬화 (validation 포함)
-        let atom = rkyv::from_bytes::<NoteAtom>(&buffer)
-            .map_err(|e| LazarusError::Deserialize(e.to_string()))?;
+        let mut revisions = Vec::with_capacity(offsets.len());
+        for (i, &offset) in offsets.iter().enumerate() {
+            let atom = self.read_atom(offset)?;
+            let revision = Revision {
+                rev: i + 1,
+                updated_at: atom.updated_at,
+                size: atom.content.len(),
+                encrypted: atom.encrypted,
+            };
+            revisions.push((revision, atom));
+        }
+
+        let encrypted: Vec<bool> = revisions.iter().map(|(r, _)| r.encrypted).collect();
+        let mut superseded = superseded_by_encryption(&encrypted).into_iter();
+        revisions.retain(|_| !superseded.next().unwrap_or(false));
+        Ok(revisions)
+    }
 
-        if atom.deleted {
+    /// 특정 리비전 로드 (rev는 1부터, 암호화 전 평문 리비전이면 None)
+    pub fn get_revision(
+        &self,
+        id: u64,
+        rev: usize,
+        crypto: Option<&CryptoManager>,
+    ) -> Result<Option<Note>> {
+        if self.hidden.contains(&id) {
             return Ok(None);
         }
-
-        // 복호화 (필요시)
-        let decompressed = if atom.encrypted {
-            match crypto {
-                Some(c) => {
-                    let decrypted = c.decrypt(&atom.content)?;
-                    zstd::decode_all(std::io::Cursor::new(&decrypted))
-                        .map_err(|_| LazarusError::ZimDecompress)?
-                }
-                None => {
-                    // 암호화됐는데 키 없음 → 내용 숨김
-                    return Ok(Some(Note {
-                        id: atom.id,
-                        title: "🔒 암호화된 노트".to_string(),
-                        content: "PIN을 입력하여 잠금을 해제하세요".to_string(),
-                        tags: vec![],
-                        created_at: chrono::DateTime::from_timestamp(atom.created_at, 0)
-                            .unwrap_or_default()
-                            .with_timezone(&chrono::Utc),
-                        updated_at: chrono::DateTime::from_timestamp(atom.updated_at, 0)
-                            .unwrap_or_default()
-                            .with_timezone(&chrono::Utc),
-                        encrypted: true,
-                        note_type: crate::db::note::NoteType::default(),
-                        rating: None,
-                        mood: None,
-                    }));
-                }
-            }
-        } else {
-            zstd::decode_all(std::io::Cursor::new(&atom.content))
-                .map_err(|_| LazarusError::ZimDecompress)?
+        let Some(offsets) = self.revisions.get(&id) else {
+            return Ok(None);
+        };
+        let index = rev.wrapping_sub(1);
+        let Some(&offset) = offsets.get(index) else {
+            return Ok(None);
         };
 
-        let content = String::from_utf8_lossy(&decompressed).to_string();
-        match Note::from_markdown(atom.id, &content) {
-            Some(mut note) => {
-                note.encrypted = atom.encrypted;
-                note.note_type = crate::db::note::NoteType::from_u8(atom.note_type);
-                Ok(Some(note))
+        let atom = self.read_atom(offset)?;
+        if !atom.encrypted {
+            for &later in &offsets[index + 1..] {
+                if self.read_atom(later)?.encrypted {
+                    return Ok(None);
+                }
             }
-            None => Ok(None),
         }
+        decode_atom(atom, crypto)
     }
 
-    /// 노트 읽기
-    pub fn get(&self, id: u64) -> Result<Option<Note>> {
-        let header_offset = match self.index.get(&id) {
-            Some(&o) => o,
-            None => return Ok(None),
-        };
-
+    /// 헤더 오프셋의 엔트리 데이터 (헤더 제외)
+    fn read_entry(&self, header_offset: u64) -> Result<Vec<u8>> {
         // 헤더에서 길이 읽기
         let mut len_buf = [0u8; 4];
-
         #[cfg(unix)]
         self.read_handle.read_at(&mut len_buf, header_offset)?;
-
         #[cfg(not(unix))]
         {
             use std::io::{Read, Seek, SeekFrom};
@@ -380,16 +527,12 @@ impl StorageEngine {
         }
 
         let len = u32::from_le_bytes(len_buf) as usize;
-
-        // 데이터 오프셋 = 헤더 오프셋 + 헤더 크기(8)
         let data_offset = header_offset + ENTRY_HEADER_SIZE as u64;
 
         // 데이터 읽기
         let mut buffer = vec![0u8; len];
-
         #[cfg(unix)]
         self.read_handle.read_at(&mut buffer, data_offset)?;
-
         #[cfg(not(unix))]
         {
             use std::io::{Read, Seek, SeekFrom};
@@ -398,27 +541,23 @@ impl StorageEngine {
             handle.read_exact(&mut buffer)?;
         }
 
-        // 역직렬화
-        let archived = unsafe { rkyv::archived_root::<NoteAtom>(&buffer) };
-
-        // 압축 해제
-        let decompressed = zstd::decode_all(std::io::Cursor::new(&archived.content))
-            .map_err(|e| LazarusError::Deserialize(e.to_string()))?;
-
-        let markdown = String::from_utf8(decompressed)
-            .map_err(|e| LazarusError::Deserialize(e.to_string()))?;
-
-        // Note로 변환
-        let mut note = Note::from_markdown(id, &markdown)
-            .ok_or_else(|| LazarusError::Deserialize("마크다운 파싱 실패".to_string()))?;
+        Ok(buffer)
+    }
 
-        note.note_type = crate::db::note::NoteType::from_u8(archived.note_type);
+    /// 헤더 오프셋의 NoteAtom (validation 포함)
+    fn read_atom(&self, header_offset: u64) -> Result<NoteAtom> {
+        let buffer = self.read_entry(header_offset)?;
+        rkyv::from_bytes::<NoteAtom>(&buffer).map_err(|e| LazarusError::Deserialize(e.to_string()))
+    }
 
-        Ok(Some(note))
+    /// 노트 읽기 (암호화된 노트는 내용을 숨긴 채 반환)
+    pub fn get(&self, id: u64) -> Result<Option<Note>> {
+        self.get_decrypted(id, None)
     }
+
     /// 노트 삭제 (soft delete)
     pub fn delete(&mut self, id: u64) -> Result<bool> {
-        if !self.index.contains_key(&id) {
+        if !self.index.contains_key(&id) || self.hidden.contains(&id) {
             return Ok(false);
         }
 
@@ -438,11 +577,12 @@ impl StorageEngine {
             rkyv::to_bytes::<_, 256>(&atom).map_err(|e| LazarusError::Serialize(e.to_string()))?;
 
         self.writer.append(bytes.to_vec())?;
-        self.writer.flush()?;
+        self.commit()?;
 
         // 인덱스에서 제거
         self.index.remove(&id);
-        self.vector_cache.retain(|(vid, _)| *vid != id);
+        self.revisions.remove(&id);
+        self.vector_cache.remove(&id);
 
         tracing::debug!("노트 삭제: id={}", id);
 
@@ -451,12 +591,95 @@ impl StorageEngine {
 
     /// 모든 노트 ID 목록
     pub fn list_ids(&self) -> Vec<u64> {
-        self.index.keys().copied().collect()
+        self.index
+            .keys()
+            .copied()
+            .filter(|id| !self.hidden.contains(id))
+            .collect()
+    }
+
+    /// 숨길 노트 지정 (바뀌었으면 true)
+    pub fn set_hidden(&mut self, hidden: HashSet<u64>) -> bool {
+        if self.hidden == hidden {
+            return false;
+        }
+        self.hidden = hidden;
+        true
+    }
+
+    /// 숨긴 노트 (미끼 금고로 열었을 때의 진짜 금고 노트)
+    pub fn hidden(&self) -> &HashSet<u64> {
+        &self.hidden
+    }
+
+    /// 숨긴 노트를 실제로 삭제, 반환: 삭제한 개수
+    pub fn purge_hidden(&mut self) -> Result<usize> {
+        let ids: Vec<u64> = self.hidden.drain().collect();
+        let mut purged = 0usize;
+        for id in ids {
+            if self.delete(id)? {
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+
+    /// 노트 벡터
+    pub fn vector(&self, id: u64) -> Option<&[i8]> {
+        if self.hidden.contains(&id) {
+            return None;
+        }
+        self.vector_cache.get(&id).map(Vec::as_slice)
+    }
+
+    /// 벡터가 없는 노트 ID (예전 버전에서 저장된 노트)
+    pub fn ids_without_vector(&self) -> Vec<u64> {
+        self.index
+            .keys()
+            .copied()
+            .filter(|id| !self.hidden.contains(id) && !self.vector_cache.contains_key(id))
+            .collect()
+    }
+
+    /// 메모리 벡터 캐시만 갱신 (디스크에는 쓰지 않음)
+    pub fn cache_vector(&mut self, id: u64, vector: Vec<i8>) {
+        if !self.index.contains_key(&id) {
+            return;
+        }
+        self.vector_cache.insert(id, vector);
+    }
+
+    /// 코사인 유사도 최근접 노트 (유사도 내림차순)
+    pub fn nearest(&self, query: &[i8], limit: usize, exclude: Option<u64>) -> Vec<(u64, f32)> {
+        let mut scored: Vec<(u64, f32)> = self
+            .vector_cache
+            .iter()
+            .filter(|(&id, _)| Some(id) != exclude && !self.hidden.contains(&id))
+            .map(|(&id, v)| (id, cosine(query, v)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        scored.truncate(limit);
+        scored
+    }
+
+    /// 데이터 파일 경로
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 시작할 때 손상된 엔트리를 만났는지
+    pub fn needs_repair(&self) -> bool {
+        self.needs_repair
     }
 
     /// 노트 개수
     pub fn count(&self) -> usize {
-        self.index.len()
+        self.index
+            .keys()
+            .filter(|id| !self.hidden.contains(id))
+            .count()
     }
 
     /// 버퍼 플러시
@@ -464,57 +687,164 @@ impl StorageEngine {
         self.writer.flush()
     }
 
+    /// 쓰기 한 건 마무리 (내구성 모드에 따라 fsync)
+    ///
+    /// 읽기는 파일 핸들로 하므로 버퍼는 항상 파일에 씁니다.
+    fn commit(&mut self) -> Result<()> {
+        if self.in_batch || self.durability != Durability::AlwaysFsync {
+            self.writer.write_buffer()
+        } else {
+            self.writer.flush()
+        }
+    }
+
+    /// 여러 쓰기를 묶어 fsync 한 번으로 처리 (가져오기 등 대량 작업용)
+    ///
+    /// 클로저가 실패해도 그때까지 쓴 내용은 디스크에 내립니다.
+    pub fn batch<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        let outer = self.in_batch;
+        self.in_batch = true;
+        let result = f(self);
+        self.in_batch = outer;
+
+        if !outer {
+            self.writer.flush()?;
+        }
+        result
+    }
+
+    /// fsync 대기 중인 쓰기를 디스크에 내리기 (group commit 태스크용)
+    pub fn sync(&mut self) -> Result<()> {
+        self.writer.flush()
+    }
+
+    /// fsync 대기 중인 쓰기를 파일에 쓰고 fsync할 핸들을 넘기기
+    ///
+    /// group commit 태스크가 DB 락을 놓은 뒤 fsync하도록 쓰입니다.
+    pub fn begin_sync(&mut self) -> Result<Option<(std::fs::File, u64)>> {
+        self.writer.begin_sync()
+    }
+
+    /// `begin_sync`로 넘긴 핸들의 fsync 완료
+    pub fn mark_synced(&mut self, bytes: u64) {
+        self.writer.mark_synced(bytes);
+    }
+
+    /// 아직 디스크에 내려가지 않은 바이트
+    pub fn pending_bytes(&self) -> u64 {
+        self.writer.pending_bytes()
+    }
+
+    /// 내구성 모드
+    pub fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    /// 내구성 모드 변경 (always-fsync로 바꾸면 대기 중인 쓰기도 바로 내림)
+    pub fn set_durability(&mut self, durability: Durability) -> Result<()> {
+        self.durability = durability;
+        if durability == Durability::AlwaysFsync {
+            self.writer.flush()?;
+        }
+        Ok(())
+    }
+
+    /// 데이터 파일 옆에 남은 사본 삭제 (마이그레이션 `.v{버전}.bak`, 복구 `.corrupt`)
+    ///
+    /// 금고를 지울 때 예전 암호문이 사본에 남지 않도록 합니다. 반환: 지운 파일 수
+    pub fn purge_copies(&self) -> Result<usize> {
+        let mut copies = repair::corrupt_copies(&self.path);
+        copies.extend(
+            (wal::MIN_VERSION..wal::VERSION)
+                .map(|version| {
+                    self.path
+                        .with_extension(format!("lazarus.v{}.bak", version))
+                })
+                .filter(|path| path.exists()),
+        );
+        for copy in &copies {
+            std::fs::remove_file(copy)?;
+        }
+        Ok(copies.len())
+    }
+
     /// DB 압축 (Compaction)
-    /// 삭제된 레코드 제거, 최신 상태만 유지
-    pub fn compact(&mut self) -> Result<CompactResult> {
+    /// 삭제된 노트를 제거하고, 살아 있는 노트는 보존 정책에 맞는 리비전만 남김
+    ///
+    /// 엔트리 바이트를 그대로 복사하므로 암호화된 노트는 암호문 그대로,
+    /// 벡터도 함께 유지됩니다. 임시 파일을 검증하고 fsync한 뒤 원자적으로
+    /// 교체하므로, 중간에 죽어도 원본은 온전합니다.
+    pub fn compact(&mut self, policy: &RetentionPolicy) -> Result<CompactResult> {
         let before_size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
 
-        let record_count = self.index.len();
+        // 숨긴 노트도 그대로 옮기지만 결과 개수에는 넣지 않음
+        let record_count = self.count();
+        let now = chrono::Utc::now().timestamp();
+
+        // 노트별로 남길 리비전 선택 (ID 순, 리비전은 오래된 순 유지)
+        let mut ids: Vec<u64> = self.index.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut entries: Vec<Vec<u8>> = Vec::new();
+        let mut revisions_kept = 0usize;
+        let mut revisions_dropped = 0usize;
+        for id in ids {
+            let visible = !self.hidden.contains(&id);
+            let offsets = self.revisions.get(&id).cloned().unwrap_or_default();
+            let mut datas = Vec::with_capacity(offsets.len());
+            let mut stamps = Vec::with_capacity(offsets.len());
+            let mut encrypted = Vec::with_capacity(offsets.len());
+            for offset in offsets {
+                let data = self.read_entry(offset)?;
+                let archived = rkyv::check_archived_root::<NoteAtom>(&data)
+                    .map_err(|e| LazarusError::Deserialize(e.to_string()))?;
+                stamps.push(archived.updated_at);
+                encrypted.push(archived.encrypted);
+                datas.push(data);
+            }
 
-        // 현재 모든 노트 읽기
-        let mut notes: Vec<Note> = Vec::new();
-        for &id in self.index.keys() {
-            if let Some(note) = self.get(id)? {
-                notes.push(note);
+            // 암호화 전 평문 리비전은 정책과 관계없이 버림
+            let keeps = policy.retain(&stamps, now);
+            let superseded = superseded_by_encryption(&encrypted);
+            for ((data, keep), superseded) in datas.into_iter().zip(keeps).zip(superseded) {
+                if keep && !superseded {
+                    entries.push(data);
+                    revisions_kept += usize::from(visible);
+                } else {
+                    revisions_dropped += usize::from(visible);
+                }
             }
         }
+        let entry_count = entries.len();
 
-        // 임시 파일에 새로 쓰기
-        let temp_path = self.path.with_extension("lazarus.tmp");
+        // 임시 파일에 새로 쓰기 (남아 있던 임시 파일에 이어 쓰지 않도록 먼저 삭제)
+        let temp_path = self.path.with_extension(COMPACT_TEMP_EXT);
         let temp_path_str = temp_path
             .to_str()
             .ok_or_else(|| LazarusError::DbInit("잘못된 경로".to_string()))?;
+        let _ = std::fs::remove_file(&temp_path);
 
         {
             let mut temp_writer = WalWriter::open(temp_path_str, BUFFER_SIZE)?;
-
-            for note in &notes {
-                // 콘텐츠 압축
-                let content_bytes = note.to_markdown().into_bytes();
-                let compressed = zstd::encode_all(std::io::Cursor::new(&content_bytes), 3)
-                    .map_err(|e| LazarusError::DbWrite(e.to_string()))?;
-
-                let atom = NoteAtom {
-                    id: note.id,
-                    created_at: note.created_at.timestamp(),
-                    updated_at: note.updated_at.timestamp(),
-                    content: compressed,
-                    vector: None,
-                    encrypted: note.encrypted,
-                    deleted: false,
-                    note_type: note.note_type.to_u8(),
-                };
-
-                let data = rkyv::to_bytes::<_, 256>(&atom)
-                    .map_err(|e| LazarusError::Serialize(e.to_string()))?;
-                temp_writer.append(data.to_vec())?;
+            for data in entries {
+                temp_writer.append(data)?;
             }
-
             temp_writer.flush()?;
         }
 
-        // 기존 파일 교체
-        std::fs::rename(&temp_path, &self.path).map_err(LazarusError::Io)?;
+        // 교체 전에 임시 파일을 다시 읽어 검증
+        let written = count_entries(temp_path_str)?;
+        if written != entry_count {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(LazarusError::DbWrite(format!(
+                "compaction 검증 실패: {}개 중 {}개만 읽힘",
+                entry_count, written
+            )));
+        }
+
+        // 기존 파일 교체 (예전 체크포인트는 더 이상 맞지 않음)
+        replace_atomically(&temp_path, &self.path)?;
+        let _ = std::fs::remove_file(checkpoint::path_for(&self.path));
 
         // 엔진 재초기화
         let path_str = self
@@ -524,19 +854,25 @@ impl StorageEngine {
         self.writer = WalWriter::open(path_str, BUFFER_SIZE)?;
         self.read_handle = std::fs::File::open(&self.path)?;
         self.index.clear();
+        self.revisions.clear();
         self.vector_cache.clear();
         self.recover()?;
+        if let Err(e) = self.checkpoint() {
+            tracing::warn!("체크포인트 저장 실패: {}", e);
+        }
 
         let after_size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
 
         let saved = before_size.saturating_sub(after_size);
 
         tracing::info!(
-            "Compaction 완료: {} -> {} ({} 절약, {}개 레코드)",
+            "Compaction 완료: {} -> {} ({} 절약, {}개 레코드, 리비전 {}개 유지/{}개 정리)",
             format_size(before_size),
             format_size(after_size),
             format_size(saved),
-            record_count
+            record_count,
+            revisions_kept,
+            revisions_dropped
         );
 
         Ok(CompactResult {
@@ -544,6 +880,8 @@ impl StorageEngine {
             after_size,
             saved_bytes: saved,
             record_count,
+            revisions_kept,
+            revisions_dropped,
         })
     }
 }
@@ -555,6 +893,185 @@ pub struct CompactResult {
     pub after_size: u64,
     pub saved_bytes: u64,
     pub record_count: usize,
+    pub revisions_kept: usize,
+    pub revisions_dropped: usize,
+}
+
+/// NoteAtom → Note (암호화됐는데 키가 없으면 내용을 숨긴 노트)
+pub(super) fn decode_atom(atom: NoteAtom, crypto: Option<&CryptoManager>) -> Result<Option<Note>> {
+    if atom.deleted {
+        return Ok(None);
+    }
+
+    // 복호화 (필요시)
+    let decompressed = if atom.encrypted {
+        match crypto {
+            Some(c) => {
+                let decrypted = c.decrypt(&atom.content)?;
+                zstd::decode_all(std::io::Cursor::new(&decrypted))
+                    .map_err(|_| LazarusError::ZimDecompress)?
+            }
+            None => {
+                // 암호화됐는데 키 없음 → 내용 숨김
+                return Ok(Some(Note {
+                    id: atom.id,
+                    title: "🔒 암호화된 노트".to_string(),
+                    content: "PIN을 입력하여 잠금을 해제하세요".to_string(),
+                    tags: vec![],
+                    created_at: chrono::DateTime::from_timestamp(atom.created_at, 0)
+                        .unwrap_or_default()
+                        .with_timezone(&chrono::Utc),
+                    updated_at: chrono::DateTime::from_timestamp(atom.updated_at, 0)
+                        .unwrap_or_default()
+                        .with_timezone(&chrono::Utc),
+                    encrypted: true,
+                    note_type: crate::db::note::NoteType::default(),
+                    rating: None,
+                    mood: None,
+                }));
+            }
+        }
+    } else {
+        zstd::decode_all(std::io::Cursor::new(&atom.content))
+            .map_err(|_| LazarusError::ZimDecompress)?
+    };
+
+    let content = String::from_utf8_lossy(&decompressed).to_string();
+    match Note::from_markdown(atom.id, &content) {
+        Some(mut note) => {
+            // 시각/타입/암호화 여부는 atom 값이 기준 (스키마 1 프론트매터에는 없음)
+            note.encrypted = atom.encrypted;
+            note.note_type = crate::db::note::NoteType::from_u8(atom.note_type);
+            if note.created_at.timestamp() != atom.created_at {
+                note.created_at = timestamp_or_now(atom.created_at);
+            }
+            if note.updated_at.timestamp() != atom.updated_at {
+                note.updated_at = timestamp_or_now(atom.updated_at);
+            }
+            Ok(Some(note))
+        }
+        None => Ok(None),
+    }
+}
+
+/// 임시 파일을 디스크에 내린 뒤 대상 파일과 원자적으로 교체
+///
+/// rename 후 디렉토리도 fsync해야 전원이 나가도 교체가 남습니다.
+fn replace_atomically(temp: &Path, target: &Path) -> Result<()> {
+    std::fs::File::open(temp)?.sync_all()?;
+    std::fs::rename(temp, target)?;
+
+    #[cfg(unix)]
+    {
+        let dir = target
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        std::fs::File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// WAL 파일의 온전한 엔트리 수
+fn count_entries(path: &str) -> Result<usize> {
+    let mut reader = WalReader::open(path)?;
+    let mut count = 0;
+    while reader.next_entry()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn timestamp_or_now(ts: i64) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp(ts, 0).unwrap_or_else(chrono::Utc::now)
+}
+
+/// 이전 버전 WAL 파일을 현재 버전으로 변환
+///
+/// 평문 노트는 프론트매터를 새 스키마로 다시 쓰고, 암호화된 노트와
+/// 삭제 표시는 그대로 복사합니다. 원본은 `.v{버전}.bak`으로 남깁니다.
+///
+/// `recover`처럼 찢긴 꼬리에서는 멈추고 그때까지 읽은 것만 옮기며, 풀 수
+/// 없는 노트는 바이트 그대로 복사합니다 (시작을 막지 않도록).
+fn migrate(path: &Path, version: u8) -> Result<()> {
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| LazarusError::DbInit("잘못된 경로".to_string()))?;
+    let temp_path = path.with_extension(MIGRATE_TEMP_EXT);
+    let temp_path_str = temp_path
+        .to_str()
+        .ok_or_else(|| LazarusError::DbInit("잘못된 경로".to_string()))?;
+    let _ = std::fs::remove_file(&temp_path);
+
+    let mut reader = WalReader::open(path_str)?;
+    let mut upgraded = 0usize;
+    let mut copied = 0usize;
+    {
+        let mut writer = WalWriter::open(temp_path_str, BUFFER_SIZE)?;
+        loop {
+            let entry = match reader.next_entry() {
+                Ok(Some((_, entry))) => entry,
+                Ok(None) => break,
+                Err(LazarusError::DbCorruption { .. }) => continue,
+                Err(e) => {
+                    tracing::warn!(
+                        "마이그레이션 중 오프셋 {} 이후 읽지 못함 (원본은 백업에 남음): {}",
+                        reader.offset(),
+                        e
+                    );
+                    break;
+                }
+            };
+            let Ok(mut atom) = rkyv::from_bytes::<NoteAtom>(&entry.data) else {
+                continue;
+            };
+
+            if atom.deleted || atom.encrypted {
+                writer.append(entry.data)?;
+                copied += 1;
+                continue;
+            }
+
+            match decode_atom(atom.clone(), None) {
+                Ok(Some(note)) => {
+                    let compressed =
+                        zstd::encode_all(std::io::Cursor::new(note.to_markdown().as_bytes()), 3)
+                            .map_err(|e| LazarusError::DbWrite(e.to_string()))?;
+                    atom.content = compressed;
+                    let bytes = rkyv::to_bytes::<_, 256>(&atom)
+                        .map_err(|e| LazarusError::Serialize(e.to_string()))?;
+                    writer.append(bytes.to_vec())?;
+                    upgraded += 1;
+                }
+                Ok(None) => {
+                    writer.append(entry.data)?;
+                    copied += 1;
+                }
+                Err(e) => {
+                    tracing::warn!("노트 {} 변환 실패, 그대로 복사: {}", atom.id, e);
+                    writer.append(entry.data)?;
+                    copied += 1;
+                }
+            }
+        }
+        writer.flush()?;
+    }
+
+    let backup = path.with_extension(format!("lazarus.v{}.bak", version));
+    std::fs::copy(path, &backup)?;
+    replace_atomically(&temp_path, path)?;
+    let _ = std::fs::remove_file(checkpoint::path_for(path));
+
+    tracing::info!(
+        "DB 마이그레이션 v{} -> v{}: {}개 변환, {}개 복사 (원본: {})",
+        version,
+        wal::VERSION,
+        upgraded,
+        copied,
+        backup.display()
+    );
+    Ok(())
 }
 
 /// 사이즈 포맷팅
@@ -611,4 +1128,391 @@ mod tests {
             assert_eq!(loaded.title, "복구 테스트");
         }
     }
+
+    #[test]
+    fn test_history_and_retention() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        let mut note = Note::new(0, "버전".to_string(), "v1".to_string());
+        note.id = engine.save(&note, None).unwrap();
+        for v in 2..=4 {
+            note.content = format!("v{}", v);
+            engine.save(&note, None).unwrap();
+        }
+
+        let history = engine.history(note.id).unwrap();
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[0].rev, 1);
+        let first = engine.get_revision(note.id, 1, None).unwrap().unwrap();
+        assert_eq!(first.content, "v1");
+        assert!(engine.get_revision(note.id, 5, None).unwrap().is_none());
+        assert!(engine.get_revision(note.id, 0, None).unwrap().is_none());
+
+        // 재시작 후에도 리비전 체인 복구
+        drop(engine);
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.history(note.id).unwrap().len(), 4);
+
+        let policy = RetentionPolicy {
+            keep_last: 2,
+            keep_days: 0,
+        };
+        let result = engine.compact(&policy).unwrap();
+        assert_eq!(result.revisions_kept, 2);
+        assert_eq!(result.revisions_dropped, 2);
+
+        let history = engine.history(note.id).unwrap();
+        assert_eq!(history.len(), 2);
+        let oldest = engine.get_revision(note.id, 1, None).unwrap().unwrap();
+        assert_eq!(oldest.content, "v3");
+        assert_eq!(engine.get(note.id).unwrap().unwrap().content, "v4");
+    }
+
+    #[test]
+    fn test_migrate_v1_file() {
+        use crate::db::note::NoteType;
+
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        // 스키마 1 프론트매터로 쓴 v1 파일
+        {
+            let mut writer = WalWriter::open(db_path.to_str().unwrap(), BUFFER_SIZE).unwrap();
+            let markdown = "---\nid: 1\ntitle: \"옛 노트\"\ntags: [old, pkm]\n---\n\n본문";
+            let atom = NoteAtom {
+                id: 1,
+                created_at: 1_700_000_000,
+                updated_at: 1_700_000_100,
+                content: zstd::encode_all(std::io::Cursor::new(markdown.as_bytes()), 3).unwrap(),
+                vector: None,
+                deleted: false,
+                note_type: NoteType::Journal.to_u8(),
+                encrypted: false,
+            };
+            let bytes = rkyv::to_bytes::<_, 256>(&atom).unwrap();
+            writer.append(bytes.to_vec()).unwrap();
+        }
+        let mut bytes = std::fs::read(&db_path).unwrap();
+        bytes[7] = 1;
+        std::fs::write(&db_path, bytes).unwrap();
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(
+            wal::file_version(db_path.to_str().unwrap()).unwrap(),
+            Some(wal::VERSION)
+        );
+        assert!(tmp.path().join("test.lazarus.v1.bak").exists());
+
+        let mut note = engine.get(1).unwrap().unwrap();
+        assert_eq!(note.title, "옛 노트");
+        assert_eq!(note.tags, vec!["old", "pkm"]);
+        assert_eq!(note.note_type, NoteType::Journal);
+        assert_eq!(note.created_at.timestamp(), 1_700_000_000);
+        assert_eq!(note.updated_at.timestamp(), 1_700_000_100);
+
+        // 이후 저장한 별점/기분은 재시작 후에도 유지
+        note.mood = Some("😊".to_string());
+        note.rating = Some(5);
+        engine.save(&note, None).unwrap();
+        drop(engine);
+
+        let engine = StorageEngine::open(&db_path).unwrap();
+        let reloaded = engine.get(1).unwrap().unwrap();
+        assert_eq!(reloaded.mood.as_deref(), Some("😊"));
+        assert_eq!(reloaded.rating, Some(5));
+        assert_eq!(reloaded.created_at.timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_migrate_v1_file_with_torn_tail() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        // 정상 노트, 풀 수 없는 노트, 찢긴 마지막 쓰기
+        {
+            let mut writer = WalWriter::open(db_path.to_str().unwrap(), BUFFER_SIZE).unwrap();
+            for (id, content) in [
+                (
+                    1,
+                    zstd::encode_all(&b"---\nid: 1\ntitle: \"A\"\n---\n\nok"[..], 3).unwrap(),
+                ),
+                (2, b"not zstd".to_vec()),
+                (3, zstd::encode_all(&b"tail"[..], 3).unwrap()),
+            ] {
+                let atom = NoteAtom {
+                    id,
+                    created_at: 1_700_000_000,
+                    updated_at: 1_700_000_000,
+                    content,
+                    vector: None,
+                    deleted: false,
+                    note_type: 0,
+                    encrypted: false,
+                };
+                let bytes = rkyv::to_bytes::<_, 256>(&atom).unwrap();
+                writer.append(bytes.to_vec()).unwrap();
+            }
+        }
+        let mut bytes = std::fs::read(&db_path).unwrap();
+        bytes[7] = 1;
+        bytes.truncate(bytes.len() - 4);
+        std::fs::write(&db_path, bytes).unwrap();
+
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(
+            wal::file_version(db_path.to_str().unwrap()).unwrap(),
+            Some(wal::VERSION)
+        );
+        assert_eq!(engine.get(1).unwrap().unwrap().title, "A");
+        let mut ids = engine.list_ids();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2]);
+        assert!(tmp.path().join("test.lazarus.v1.bak").exists());
+    }
+
+    #[test]
+    fn test_compact_keeps_ciphertext_and_vectors() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        let mut secret = Note::new(0, "비밀".to_string(), "plaintext-marker".to_string());
+        secret.encrypted = true;
+        secret.id = engine.save_encrypted(&secret, None, Some(&crypto)).unwrap();
+        let plain = Note::new(0, "공개".to_string(), "open".to_string());
+        let plain_id = engine.save(&plain, Some(vec![1, 2, 3])).unwrap();
+        engine.save(&plain, Some(vec![1, 2, 3])).unwrap();
+
+        // 이전 compaction이 남긴 임시 파일이 있어도 영향 없음
+        std::fs::write(db_path.with_extension(COMPACT_TEMP_EXT), b"garbage").unwrap();
+
+        engine.compact(&RetentionPolicy::latest_only()).unwrap();
+        assert!(!db_path.with_extension(COMPACT_TEMP_EXT).exists());
+
+        let raw = std::fs::read(&db_path).unwrap();
+        assert!(!raw
+            .windows(b"plaintext-marker".len())
+            .any(|w| w == b"plaintext-marker"));
+
+        drop(engine);
+        let engine = StorageEngine::open(&db_path).unwrap();
+        let loaded = engine
+            .get_decrypted(secret.id, Some(&crypto))
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.content, "plaintext-marker");
+        assert!(loaded.encrypted);
+        assert_eq!(engine.vector(plain_id), Some(&[1i8, 2, 3][..]));
+        assert_eq!(engine.history(plain_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_encrypting_hides_plaintext_revisions() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        let mut note = Note::new(0, "취재".to_string(), "source-marker".to_string());
+        note.id = engine.save(&note, None).unwrap();
+        note.content = "source-marker 2".to_string();
+        engine.save(&note, None).unwrap();
+        note.encrypted = true;
+        engine.save_encrypted(&note, None, Some(&crypto)).unwrap();
+
+        // 암호화 전 평문 리비전은 목록에도, 리비전 조회에도 없음
+        let history = engine.history(note.id).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].rev, 3);
+        assert!(engine.get_revision(note.id, 1, None).unwrap().is_none());
+        assert!(engine.get_revision(note.id, 2, None).unwrap().is_none());
+        assert!(engine
+            .get_revision(note.id, 3, Some(&crypto))
+            .unwrap()
+            .is_some());
+        let notes = engine.history_notes(note.id, Some(&crypto)).unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].0.rev, 3);
+        assert_eq!(notes[0].1.as_ref().unwrap().title, "취재");
+
+        // compaction은 보존 정책과 관계없이 평문 리비전을 버림
+        let result = engine.compact(&RetentionPolicy::default()).unwrap();
+        assert_eq!(result.revisions_dropped, 2);
+        drop(engine);
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.revisions[&note.id].len(), 1);
+    }
+
+    #[test]
+    fn test_group_commit_and_batch() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        engine
+            .set_durability(Durability::GroupCommit { interval_ms: 50 })
+            .unwrap();
+
+        // fsync 전에도 읽기는 가능
+        let id = engine
+            .save(&Note::new(0, "그룹".to_string(), "커밋".to_string()), None)
+            .unwrap();
+        assert!(engine.pending_bytes() > 0);
+        assert_eq!(engine.get(id).unwrap().unwrap().content, "커밋");
+        engine.sync().unwrap();
+        assert_eq!(engine.pending_bytes(), 0);
+
+        engine
+            .save(
+                &Note::new(0, "락 밖".to_string(), "fsync".to_string()),
+                None,
+            )
+            .unwrap();
+        let (file, bytes) = engine.begin_sync().unwrap().unwrap();
+        assert_eq!(engine.pending_bytes(), bytes);
+        file.sync_data().unwrap();
+        engine.mark_synced(bytes);
+        assert_eq!(engine.pending_bytes(), 0);
+        assert!(engine.begin_sync().unwrap().is_none());
+
+        engine.set_durability(Durability::AlwaysFsync).unwrap();
+        let ids = engine
+            .batch(|db| {
+                let mut ids = Vec::new();
+                for i in 0..10 {
+                    let note = Note::new(0, format!("대량 {}", i), "내용".to_string());
+                    ids.push(db.save(&note, None)?);
+                }
+                assert!(db.pending_bytes() > 0);
+                Ok(ids)
+            })
+            .unwrap();
+        assert_eq!(ids.len(), 10);
+        assert_eq!(engine.pending_bytes(), 0);
+
+        drop(engine);
+        assert_eq!(StorageEngine::open(&db_path).unwrap().count(), 12);
+    }
+
+    #[test]
+    fn test_checkpoint_replays_only_tail() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        {
+            let mut engine = StorageEngine::open(&db_path).unwrap();
+            for i in 0..5 {
+                let note = Note::new(0, format!("노트 {}", i), "내용".to_string());
+                engine.save(&note, Some(vec![i as i8 + 1])).unwrap();
+            }
+            engine.checkpoint().unwrap();
+
+            // 체크포인트 이후 쓰기: 수정 1, 삭제 1, 새 노트 1
+            let mut first = engine.get(1).unwrap().unwrap();
+            first.content = "수정됨".to_string();
+            engine.save(&first, None).unwrap();
+            engine.delete(2).unwrap();
+            engine
+                .save(
+                    &Note::new(0, "새 노트".to_string(), "꼬리".to_string()),
+                    None,
+                )
+                .unwrap();
+        }
+
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.replayed, 3);
+        assert_eq!(engine.count(), 5);
+        assert_eq!(engine.get(1).unwrap().unwrap().content, "수정됨");
+        assert!(engine.get(2).unwrap().is_none());
+        assert_eq!(engine.get(6).unwrap().unwrap().content, "꼬리");
+        assert_eq!(engine.history(1).unwrap().len(), 2);
+        assert_eq!(engine.vector(3), Some(&[3i8][..]));
+        assert!(engine.vector(1).is_none());
+        drop(engine);
+
+        // 손상된 체크포인트는 무시하고 전체 재생
+        let idx = checkpoint::path_for(&db_path);
+        let mut bytes = std::fs::read(&idx).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xFF;
+        std::fs::write(&idx, bytes).unwrap();
+
+        let mut engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.replayed, 8);
+        assert_eq!(engine.count(), 5);
+
+        // compaction 후에는 새 체크포인트로 바로 시작
+        engine.compact(&RetentionPolicy::latest_only()).unwrap();
+        drop(engine);
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.replayed, 0);
+        assert_eq!(engine.count(), 5);
+        let next = engine.next_id.load(Ordering::SeqCst);
+        assert_eq!(next, 7);
+    }
+
+    #[test]
+    fn test_torn_header_flags_repair() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("test.lazarus");
+
+        let first_end = {
+            let mut engine = StorageEngine::open(&db_path).unwrap();
+            engine
+                .save(&Note::new(0, "앞".to_string(), "1".to_string()), None)
+                .unwrap();
+            let first_end = std::fs::metadata(&db_path).unwrap().len() as usize;
+            engine
+                .save(&Note::new(0, "뒤".to_string(), "2".to_string()), None)
+                .unwrap();
+            first_end
+        };
+
+        let mut bytes = std::fs::read(&db_path).unwrap();
+        bytes[first_end..first_end + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        std::fs::write(&db_path, bytes).unwrap();
+
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert!(engine.needs_repair());
+        assert_eq!(engine.count(), 1);
+    }
+
+    #[test]
+    fn test_nearest_vectors() {
+        use crate::search::{embed, embed_note};
+
+        let tmp = TempDir::new().unwrap();
+        let mut engine = StorageEngine::open(tmp.path().join("test.lazarus")).unwrap();
+
+        let texts = [
+            ("뉴턴", "Newton's laws of motion and gravity"),
+            ("중력", "Gravity pulls masses together, as Newton described"),
+            ("김치", "Kimchi recipe with cabbage"),
+        ];
+        for (title, content) in texts {
+            let note = Note::new(0, title.to_string(), content.to_string());
+            engine.save(&note, embed_note(&note)).unwrap();
+        }
+
+        let query = engine.vector(1).unwrap().to_vec();
+        let hits = engine.nearest(&query, 5, Some(1));
+        assert_eq!(hits[0].0, 2);
+        assert!(hits.iter().all(|(id, _)| *id != 1));
+
+        // 벡터 없이 다시 저장하면 캐시에서도 빠짐
+        let note = engine.get(2).unwrap().unwrap();
+        engine.save(&note, None).unwrap();
+        assert!(engine.vector(2).is_none());
+        assert_eq!(engine.ids_without_vector(), vec![2]);
+
+        // 재시작해도 저장된 벡터는 복구됨
+        drop(engine);
+        let engine = StorageEngine::open(tmp.path().join("test.lazarus")).unwrap();
+        assert!(engine.vector(1).is_some());
+        assert!(!engine.nearest(&embed("gravity"), 5, None).is_empty());
+    }
 }
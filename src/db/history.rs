@@ -0,0 +1,221 @@
+//! 노트 리비전 히스토리
+//!
+//! WAL은 append-only라 compaction 전까지 모든 버전이 남아 있습니다.
+//! 여기서는 리비전 메타데이터, 보존 정책, 줄 단위 diff를 다룹니다.
+
+use serde::{Deserialize, Serialize};
+
+/// 기본 보존 리비전 수
+pub const DEFAULT_KEEP_LAST: usize = 10;
+
+/// 기본 보존 기간 (일)
+pub const DEFAULT_KEEP_DAYS: i64 = 30;
+
+/// diff 계산 상한 (줄 수 곱, 넘으면 전체 교체로 표시, u32 표 약 2MB)
+const MAX_DIFF_CELLS: usize = 500_000;
+
+/// 리비전 정보
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Revision {
+    /// 리비전 번호 (1부터, 오래된 순)
+    pub rev: usize,
+    /// 수정 타임스탬프 (Unix timestamp)
+    pub updated_at: i64,
+    /// 저장된 크기 (바이트)
+    pub size: usize,
+    pub encrypted: bool,
+}
+
+/// Compaction 시 리비전 보존 정책
+///
+/// 최근 `keep_last`개 또는 최근 `keep_days`일 안의 리비전을 남깁니다.
+/// 최신 리비전은 항상 남습니다.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub keep_last: usize,
+    pub keep_days: i64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: DEFAULT_KEEP_LAST,
+            keep_days: DEFAULT_KEEP_DAYS,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// 최신 버전만 남김 (예전 compaction 동작)
+    pub fn latest_only() -> Self {
+        Self {
+            keep_last: 1,
+            keep_days: 0,
+        }
+    }
+
+    /// 리비전별 보존 여부 (`updated_at`은 오래된 순)
+    pub fn retain(&self, updated_at: &[i64], now: i64) -> Vec<bool> {
+        let len = updated_at.len();
+        let cutoff = now - self.keep_days.max(0) * 86_400;
+
+        updated_at
+            .iter()
+            .enumerate()
+            .map(|(i, &ts)| {
+                let from_end = len - i;
+                from_end == 1 || from_end <= self.keep_last || (self.keep_days > 0 && ts >= cutoff)
+            })
+            .collect()
+    }
+}
+
+/// 암호화되기 전의 평문 리비전 (뒤에 암호화된 리비전이 있음)
+///
+/// 노트를 암호화하면 그 전 평문 리비전은 보여주지도, compaction 때 남기지도
+/// 않습니다. `encrypted`는 오래된 순입니다.
+pub fn superseded_by_encryption(encrypted: &[bool]) -> Vec<bool> {
+    let mut later_encrypted = false;
+    let mut superseded = vec![false; encrypted.len()];
+    for (i, &enc) in encrypted.iter().enumerate().rev() {
+        superseded[i] = !enc && later_encrypted;
+        later_encrypted |= enc;
+    }
+    superseded
+}
+
+/// diff 줄 종류
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    Same,
+    Added,
+    Removed,
+}
+
+/// diff 한 줄
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// 줄 단위 diff (LCS)
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // 공통 앞/뒤 줄은 표를 만들지 않고 처리
+    let prefix = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    let mut lines: Vec<DiffLine> = a[..prefix].iter().map(|l| line(DiffOp::Same, l)).collect();
+
+    if (a_mid.len() + 1) * (b_mid.len() + 1) > MAX_DIFF_CELLS {
+        lines.extend(a_mid.iter().map(|l| line(DiffOp::Removed, l)));
+        lines.extend(b_mid.iter().map(|l| line(DiffOp::Added, l)));
+    } else {
+        lines.extend(lcs_diff(a_mid, b_mid));
+    }
+
+    lines.extend(a[a.len() - suffix..].iter().map(|l| line(DiffOp::Same, l)));
+    lines
+}
+
+fn lcs_diff(a: &[&str], b: &[&str]) -> Vec<DiffLine> {
+    let (n, m) = (a.len(), b.len());
+    // table[i][j] = a[i..]와 b[j..]의 LCS 길이
+    let mut table = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            lines.push(line(DiffOp::Same, a[i]));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            lines.push(line(DiffOp::Removed, a[i]));
+            i += 1;
+        } else {
+            lines.push(line(DiffOp::Added, b[j]));
+            j += 1;
+        }
+    }
+    lines.extend(a[i..].iter().map(|l| line(DiffOp::Removed, l)));
+    lines.extend(b[j..].iter().map(|l| line(DiffOp::Added, l)));
+    lines
+}
+
+fn line(op: DiffOp, text: &str) -> DiffLine {
+    DiffLine {
+        op,
+        text: text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let diff = diff_lines("a\nb\nc\nd", "a\nc\nx\nd");
+        let ops: Vec<(DiffOp, &str)> = diff.iter().map(|l| (l.op, l.text.as_str())).collect();
+        assert_eq!(
+            ops,
+            vec![
+                (DiffOp::Same, "a"),
+                (DiffOp::Removed, "b"),
+                (DiffOp::Same, "c"),
+                (DiffOp::Added, "x"),
+                (DiffOp::Same, "d"),
+            ]
+        );
+
+        assert!(diff_lines("same", "same")
+            .iter()
+            .all(|l| l.op == DiffOp::Same));
+    }
+
+    #[test]
+    fn test_retention_policy() {
+        let day = 86_400;
+        let now = 100 * day;
+        let stamps = [
+            now - 90 * day,
+            now - 60 * day,
+            now - 10 * day,
+            now - day,
+            now,
+        ];
+
+        let policy = RetentionPolicy {
+            keep_last: 2,
+            keep_days: 30,
+        };
+        assert_eq!(
+            policy.retain(&stamps, now),
+            vec![false, false, true, true, true]
+        );
+
+        let latest = RetentionPolicy::latest_only().retain(&stamps, now);
+        assert_eq!(latest, vec![false, false, false, false, true]);
+    }
+}
@@ -1,18 +1,27 @@
 pub mod backup;
+pub mod checkpoint;
+pub mod chunk_store;
 pub mod engine;
+pub mod history;
 pub mod note;
 pub mod package_store;
 pub mod post;
 pub mod post_store;
 pub mod qna;
 pub mod qna_store;
+pub mod repair;
+pub mod schedule;
+pub mod snapshot;
 pub mod wal;
 
-pub use backup::BackupManager;
+pub use backup::{BackupEntry, BackupManager, BackupRetention, MirrorReport};
 pub use engine::{CompactResult, StorageEngine};
+pub use history::{DiffLine, DiffOp, RetentionPolicy, Revision};
 pub use note::Note;
 pub use package_store::{PackageStore, PackageStoreError, PackageSummary};
 pub use post::{Post, Reply};
 pub use post_store::PostStore;
 pub use qna::{Answer, Question};
 pub use qna_store::QnaStore;
+pub use schedule::{BackupInterval, BackupSchedule, BackupStatus};
+pub use wal::Durability;
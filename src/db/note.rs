@@ -74,6 +74,16 @@ impl NoteType {
         }
     }
 
+    /// `journal` 같은 이름에서 (모르면 Note)
+    pub fn from_label(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "journal" => NoteType::Journal,
+            "review" => NoteType::Review,
+            "idea" => NoteType::Idea,
+            _ => NoteType::Note,
+        }
+    }
+
     pub fn from_u8(v: u8) -> Self {
         match v {
             1 => NoteType::Journal,
@@ -123,17 +133,37 @@ impl Note {
         }
     }
     /// 마크다운 형식으로 직렬화
+    ///
+    /// 프론트매터에 `Note`의 모든 필드를 담습니다 (스키마 2).
+    /// 문자열은 JSON 따옴표 규칙으로 이스케이프합니다 (YAML과 호환).
     pub fn to_markdown(&self) -> String {
         let mut md = String::new();
 
         // YAML 프론트매터
         md.push_str("---\n");
+        md.push_str(&format!("schema: {}\n", FRONTMATTER_SCHEMA));
         md.push_str(&format!("id: {}\n", self.id));
-        md.push_str(&format!("title: \"{}\"\n", self.title.replace('"', "\\\"")));
+        md.push_str(&format!("title: {}\n", quote(&self.title)));
         md.push_str(&format!("created: {}\n", self.created_at.to_rfc3339()));
         md.push_str(&format!("updated: {}\n", self.updated_at.to_rfc3339()));
+        if self.note_type != NoteType::Note {
+            md.push_str(&format!(
+                "type: {}\n",
+                self.note_type.label().to_lowercase()
+            ));
+        }
         if !self.tags.is_empty() {
-            md.push_str(&format!("tags: [{}]\n", self.tags.join(", ")));
+            let tags: Vec<String> = self.tags.iter().map(|t| quote(t)).collect();
+            md.push_str(&format!("tags: [{}]\n", tags.join(", ")));
+        }
+        if let Some(rating) = self.rating {
+            md.push_str(&format!("rating: {}\n", rating));
+        }
+        if let Some(ref mood) = self.mood {
+            md.push_str(&format!("mood: {}\n", quote(mood)));
+        }
+        if self.encrypted {
+            md.push_str("encrypted: true\n");
         }
         md.push_str("---\n\n");
 
@@ -144,6 +174,9 @@ impl Note {
     }
 
     /// 마크다운에서 파싱
+    ///
+    /// 스키마 1 프론트매터 (`title: "..."`, `tags: [a, b]`)도 읽습니다.
+    /// 시각이 없으면 현재 시각을 씁니다.
     pub fn from_markdown(id: u64, markdown: &str) -> Option<Self> {
         // 간단한 프론트매터 파싱
         if !markdown.starts_with("---\n") {
@@ -158,34 +191,61 @@ impl Note {
         let frontmatter = parts[1];
         let content = parts[2].trim().to_string();
 
-        let mut title = String::new();
-        let mut tags = Vec::new();
+        let mut note = Self::new(id, String::new(), content);
 
         for line in frontmatter.lines() {
-            if let Some(t) = line.strip_prefix("title: ") {
-                title = t.trim_matches('"').to_string();
-            } else if let Some(t) = line.strip_prefix("tags: ") {
-                // [tag1, tag2] 형식 파싱
-                let t = t.trim_matches(|c| c == '[' || c == ']');
-                tags = t.split(',').map(|s| s.trim().to_string()).collect();
+            let Some((key, value)) = line.split_once(": ") else {
+                continue;
+            };
+            let value = value.trim();
+            match key {
+                "title" => note.title = unquote(value),
+                "created" => note.created_at = parse_time(value).unwrap_or(note.created_at),
+                "updated" => note.updated_at = parse_time(value).unwrap_or(note.updated_at),
+                "type" => note.note_type = NoteType::from_label(value),
+                "tags" => note.tags = parse_tags(value),
+                "rating" => note.rating = value.parse().ok().filter(|r| (1..=5).contains(r)),
+                "mood" => note.mood = Some(unquote(value)).filter(|m| !m.is_empty()),
+                "encrypted" => note.encrypted = value == "true",
+                _ => {}
             }
         }
 
-        Some(Self {
-            id,
-            title,
-            content,
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            tags,
-            encrypted: false,
-            note_type: NoteType::default(),
-            rating: None,
-            mood: None,
-        })
+        Some(note)
     }
 }
 
+/// 프론트매터 스키마 버전
+pub const FRONTMATTER_SCHEMA: u32 = 2;
+
+/// JSON 문자열 리터럴로 감싸기
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("\"{}\"", s))
+}
+
+/// JSON 문자열이면 디코딩, 아니면 따옴표만 제거 (스키마 1)
+fn unquote(s: &str) -> String {
+    serde_json::from_str::<String>(s).unwrap_or_else(|_| s.trim_matches('"').to_string())
+}
+
+/// `["a", "b"]` 또는 스키마 1의 `[a, b]`
+fn parse_tags(s: &str) -> Vec<String> {
+    if let Ok(tags) = serde_json::from_str::<Vec<String>>(s) {
+        return tags;
+    }
+    s.trim_matches(|c| c == '[' || c == ']')
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+fn parse_time(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,4 +274,33 @@ tags: [rust, pkm]
         assert_eq!(note.tags, vec!["rust", "pkm"]);
         assert_eq!(note.content, "본문입니다.");
     }
+
+    #[test]
+    fn test_markdown_roundtrip_keeps_all_fields() {
+        let mut note = Note::new(
+            7,
+            "\"따옴표\" 제목: 1".to_string(),
+            "본문\n둘째 줄".to_string(),
+        );
+        note.tags = vec!["a, b".to_string(), "rust".to_string()];
+        note.note_type = NoteType::Review;
+        note.rating = Some(4);
+        note.mood = Some("😊".to_string());
+        note.created_at = DateTime::parse_from_rfc3339("2024-03-01T10:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        note.updated_at = DateTime::parse_from_rfc3339("2024-03-02T11:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let parsed = Note::from_markdown(7, &note.to_markdown()).unwrap();
+        assert_eq!(parsed.title, note.title);
+        assert_eq!(parsed.content, note.content);
+        assert_eq!(parsed.tags, note.tags);
+        assert_eq!(parsed.note_type, NoteType::Review);
+        assert_eq!(parsed.rating, Some(4));
+        assert_eq!(parsed.mood.as_deref(), Some("😊"));
+        assert_eq!(parsed.created_at, note.created_at);
+        assert_eq!(parsed.updated_at, note.updated_at);
+    }
 }
@@ -23,7 +23,7 @@ impl PostStore {
     /// 모든 게시글 (최신순)
     pub fn list(&self) -> Vec<&Post> {
         let mut posts: Vec<_> = self.posts.values().collect();
-        posts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        posts.sort_by_key(|p| std::cmp::Reverse(p.created_at));
         posts
     }
 
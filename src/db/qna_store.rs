@@ -26,7 +26,7 @@ impl QnaStore {
     /// 모든 질문 (최신순)
     pub fn list(&self) -> Vec<&Question> {
         let mut questions: Vec<_> = self.questions.values().collect();
-        questions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        questions.sort_by_key(|q| std::cmp::Reverse(q.created_at));
         questions
     }
 
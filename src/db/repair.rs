@@ -0,0 +1,379 @@
+//! notes.lazarus 검사/복구
+//!
+//! 찢긴 쓰기(전원 차단 등)로 엔트리 헤더가 깨지면 `recover`는 그 뒤를 읽지
+//! 못합니다. 여기서는 파일을 바이트 단위로 훑어 다음 유효한 엔트리 헤더에
+//! 다시 맞추고(resync), 살린 엔트리만 새 파일에 씁니다.
+//! 원본은 `.<시각>.corrupt`로 남깁니다.
+//!
+//! 파일 전체를 메모리에 올리지 않고 고정 크기 창으로 훑습니다.
+
+use crc32fast::Hasher;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use super::checkpoint;
+use super::note::NoteAtom;
+use super::wal::{WalWriter, ENTRY_HEADER_SIZE, MAGIC_BYTES};
+use crate::error::{LazarusError, Result};
+
+/// 엔트리 하나의 최대 크기 (이보다 크면 헤더가 깨진 것으로 봄)
+const MAX_ENTRY_SIZE: usize = 64 * 1024 * 1024;
+
+/// 읽기 창 크기 (이보다 큰 엔트리는 CRC를 조각으로 확인한 뒤에만 읽음)
+const WINDOW_SIZE: usize = 256 * 1024;
+
+/// 검사/복구 결과
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RepairReport {
+    pub file_size: u64,
+    /// 읽을 수 있는 엔트리 수
+    pub valid_entries: usize,
+    /// 그중 삭제 표시가 아닌 엔트리
+    pub note_entries: usize,
+    /// 살아 있는 노트 수 (같은 ID의 최신 버전 기준)
+    pub notes: usize,
+    /// 건너뛴 손상 구간 수
+    pub corrupted_regions: usize,
+    /// 건너뛴 바이트
+    pub skipped_bytes: u64,
+    /// 첫 손상 위치
+    pub first_corruption: Option<u64>,
+    /// 복구를 실제로 했는지
+    pub repaired: bool,
+    /// 원본 보관 경로
+    pub corrupt_path: Option<String>,
+}
+
+impl RepairReport {
+    /// 손상이 있는지
+    pub fn is_clean(&self) -> bool {
+        self.corrupted_regions == 0
+    }
+}
+
+/// 원본 보관 경로 (`notes.lazarus.20260101-120000.corrupt`)
+pub fn corrupt_path_for(path: &Path, now: chrono::DateTime<chrono::Utc>) -> PathBuf {
+    path.with_extension(format!("lazarus.{}.corrupt", now.format("%Y%m%d-%H%M%S")))
+}
+
+/// 예전 복구로 남은 원본들 (`notes.lazarus.corrupt`, `notes.lazarus.*.corrupt`)
+pub fn corrupt_copies(path: &Path) -> Vec<PathBuf> {
+    let (Some(dir), Some(name)) = (path.parent(), path.file_name().and_then(|n| n.to_str())) else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| n.strip_prefix(name))
+                .is_some_and(|rest| rest.starts_with('.') && rest.ends_with(".corrupt"))
+        })
+        .collect()
+}
+
+/// 파일 검사 (읽기 전용)
+///
+/// `hidden`의 노트(미끼 금고로 열었을 때의 진짜 금고 노트)는 개수에서 뺍니다.
+pub fn check(path: &Path, hidden: &HashSet<u64>) -> Result<RepairReport> {
+    scan_file(path, hidden, |_, _| Ok(()))
+}
+
+/// 파일 복구
+///
+/// 손상이 없으면 아무것도 바꾸지 않습니다. 있으면 살린 엔트리로 새 파일을
+/// 쓰고 원본은 `.corrupt`로 옮깁니다. 실행 중인 엔진이 이 파일을 쓰고
+/// 있지 않아야 합니다. `hidden`은 [`check`]와 같습니다 (숨긴 노트도 살림).
+pub fn repair(path: &Path, hidden: &HashSet<u64>) -> Result<RepairReport> {
+    let mut report = check(path, hidden)?;
+    if report.is_clean() {
+        return Ok(report);
+    }
+
+    // 같은 초에 두 번 복구해 예전 원본을 덮어쓰지 않도록
+    let corrupt = corrupt_path_for(path, chrono::Utc::now());
+    if corrupt.exists() {
+        return Err(LazarusError::DbRecovery(format!(
+            "이미 보관된 원본이 있습니다: {}",
+            corrupt.display()
+        )));
+    }
+
+    let temp = path.with_extension("lazarus.repair");
+    let temp_str = temp
+        .to_str()
+        .ok_or_else(|| LazarusError::DbInit("잘못된 경로".to_string()))?;
+    let _ = std::fs::remove_file(&temp);
+    {
+        let mut writer = WalWriter::open(temp_str, 64 * 1024)?;
+        scan_file(path, hidden, |_, data| {
+            writer.append(data.to_vec()).map(|_| ())
+        })?;
+        writer.flush()?;
+    }
+    std::fs::File::open(&temp)?.sync_all()?;
+
+    std::fs::rename(path, &corrupt)?;
+    std::fs::rename(&temp, path)?;
+    let _ = std::fs::remove_file(checkpoint::path_for(path));
+
+    tracing::warn!(
+        "DB 복구: 엔트리 {}개 살림, 손상 구간 {}개 ({} 바이트) 제외, 원본: {}",
+        report.valid_entries,
+        report.corrupted_regions,
+        report.skipped_bytes,
+        corrupt.display()
+    );
+
+    report.repaired = true;
+    report.corrupt_path = Some(corrupt.display().to_string());
+    Ok(report)
+}
+
+/// 파일을 훑어 유효한 엔트리마다 `on_entry(헤더 오프셋, 데이터)` 호출
+///
+/// `hidden`의 노트 엔트리도 `on_entry`에 넘기지만 보고서의 개수에는 넣지 않습니다.
+pub(super) fn scan_file(
+    path: &Path,
+    hidden: &HashSet<u64>,
+    mut on_entry: impl FnMut(u64, &[u8]) -> Result<()>,
+) -> Result<RepairReport> {
+    let mut window = Window::new(File::open(path)?)?;
+    let magic_ok = window
+        .get(0, MAGIC_BYTES.len())?
+        .is_some_and(|magic| magic[..7] == MAGIC_BYTES[..7]);
+    if !magic_ok {
+        return Err(LazarusError::DbRecovery(
+            "LazarusDB 파일이 아닙니다".to_string(),
+        ));
+    }
+
+    let file_size = window.file_len;
+    let mut report = RepairReport {
+        file_size,
+        ..Default::default()
+    };
+    let mut live = std::collections::HashMap::new();
+
+    let mut pos = MAGIC_BYTES.len() as u64;
+    let mut bad_start: Option<u64> = None;
+
+    while pos < file_size {
+        match window.entry_at(pos)? {
+            Some((len, deleted, id)) => {
+                if let Some(start) = bad_start.take() {
+                    close_region(&mut report, start, pos);
+                }
+                if !hidden.contains(&id) {
+                    live.insert(id, !deleted);
+                    if !deleted {
+                        report.note_entries += 1;
+                    }
+                    report.valid_entries += 1;
+                }
+                let data = window
+                    .get(pos + ENTRY_HEADER_SIZE as u64, len)?
+                    .ok_or_else(|| LazarusError::DbRecovery("엔트리 다시 읽기 실패".to_string()))?;
+                on_entry(pos, data)?;
+                pos += (ENTRY_HEADER_SIZE + len) as u64;
+            }
+            None => {
+                // 다음 유효한 헤더가 나올 때까지 한 바이트씩 이동
+                bad_start.get_or_insert(pos);
+                pos += 1;
+            }
+        }
+    }
+    if let Some(start) = bad_start {
+        close_region(&mut report, start, file_size);
+    }
+
+    report.notes = live.values().filter(|alive| **alive).count();
+    Ok(report)
+}
+
+fn close_region(report: &mut RepairReport, start: u64, end: u64) {
+    report.corrupted_regions += 1;
+    report.skipped_bytes += end - start;
+    report.first_corruption.get_or_insert(start);
+}
+
+/// 파일의 일부만 메모리에 두는 읽기 창
+struct Window {
+    file: File,
+    file_len: u64,
+    /// 창 내용과 그 시작 오프셋
+    buf: Vec<u8>,
+    start: u64,
+}
+
+impl Window {
+    fn new(file: File) -> Result<Self> {
+        Ok(Self {
+            file_len: file.metadata()?.len(),
+            file,
+            buf: Vec::new(),
+            start: 0,
+        })
+    }
+
+    /// `pos`부터 `n`바이트 (파일 끝을 넘으면 None)
+    fn get(&mut self, pos: u64, n: usize) -> Result<Option<&[u8]>> {
+        let end = pos + n as u64;
+        if end > self.file_len {
+            return Ok(None);
+        }
+        if pos < self.start || end > self.start + self.buf.len() as u64 {
+            let size = n.max(WINDOW_SIZE).min((self.file_len - pos) as usize);
+            self.buf.resize(size, 0);
+            self.buf.shrink_to(size);
+            self.file.seek(SeekFrom::Start(pos))?;
+            self.file.read_exact(&mut self.buf)?;
+            self.start = pos;
+        }
+        let offset = (pos - self.start) as usize;
+        Ok(Some(&self.buf[offset..offset + n]))
+    }
+
+    /// 데이터 CRC (창보다 크면 조각으로 읽어 메모리에 다 올리지 않음)
+    fn crc(&mut self, pos: u64, len: usize) -> Result<Option<u32>> {
+        let mut hasher = Hasher::new();
+        let mut done = 0usize;
+        while done < len {
+            let step = (len - done).min(WINDOW_SIZE);
+            let Some(chunk) = self.get(pos + done as u64, step)? else {
+                return Ok(None);
+            };
+            hasher.update(chunk);
+            done += step;
+        }
+        Ok(Some(hasher.finalize()))
+    }
+
+    /// `pos`에 온전한 엔트리가 있으면 (데이터 길이, 삭제 여부, ID)
+    fn entry_at(&mut self, pos: u64) -> Result<Option<(usize, bool, u64)>> {
+        let Some(header) = self.get(pos, ENTRY_HEADER_SIZE)? else {
+            return Ok(None);
+        };
+        let len = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let crc = u32::from_le_bytes(header[4..8].try_into().unwrap());
+        let start = pos + ENTRY_HEADER_SIZE as u64;
+        if len == 0 || len > MAX_ENTRY_SIZE || start + len as u64 > self.file_len {
+            return Ok(None);
+        }
+        if self.crc(start, len)? != Some(crc) {
+            return Ok(None);
+        }
+
+        let Some(data) = self.get(start, len)? else {
+            return Ok(None);
+        };
+        // rkyv는 정렬된 버퍼가 필요
+        let mut aligned = rkyv::AlignedVec::with_capacity(len);
+        aligned.extend_from_slice(data);
+        Ok(rkyv::check_archived_root::<NoteAtom>(&aligned)
+            .ok()
+            .map(|atom| (len, atom.deleted, atom.id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{Note, StorageEngine};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_repair_resyncs_after_torn_header() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("notes.lazarus");
+
+        let offsets: Vec<u64> = {
+            let mut engine = StorageEngine::open(&db_path).unwrap();
+            (0..3)
+                .map(|i| {
+                    let note = Note::new(0, format!("노트 {}", i), "내용".to_string());
+                    engine.save(&note, None).unwrap();
+                    std::fs::metadata(&db_path).unwrap().len()
+                })
+                .collect()
+        };
+
+        // 둘째 엔트리 길이 필드를 망가뜨리고, 끝에 찢긴 쓰기 추가
+        let mut bytes = std::fs::read(&db_path).unwrap();
+        let second = offsets[0] as usize;
+        bytes[second..second + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        bytes.extend_from_slice(&[0x10, 0, 0, 0, 1, 2]);
+        std::fs::write(&db_path, &bytes).unwrap();
+
+        let report = check(&db_path, &HashSet::new()).unwrap();
+        assert_eq!(report.valid_entries, 2);
+        assert_eq!(report.corrupted_regions, 2);
+        assert_eq!(report.first_corruption, Some(offsets[0]));
+        assert!(!report.repaired);
+
+        let report = repair(&db_path, &HashSet::new()).unwrap();
+        assert!(report.repaired);
+        let corrupt = PathBuf::from(report.corrupt_path.unwrap());
+        assert_eq!(std::fs::read(&corrupt).unwrap(), bytes);
+        assert_eq!(corrupt_copies(&db_path), vec![corrupt]);
+
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.count(), 2);
+        assert!(!engine.needs_repair());
+        assert_eq!(engine.get(3).unwrap().unwrap().title, "노트 2");
+        assert!(check(&db_path, &HashSet::new()).unwrap().is_clean());
+    }
+
+    #[test]
+    fn test_scan_streams_entries_larger_than_window() {
+        use rand::{distributions::Alphanumeric, Rng};
+
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("notes.lazarus");
+        let big: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(WINDOW_SIZE * 3)
+            .map(char::from)
+            .collect();
+
+        let offsets: Vec<u64> = {
+            let mut engine = StorageEngine::open(&db_path).unwrap();
+            engine
+                .save(&Note::new(0, "큰 노트".to_string(), big.clone()), None)
+                .unwrap();
+            (0..3)
+                .map(|i| {
+                    let offset = std::fs::metadata(&db_path).unwrap().len();
+                    let note = Note::new(0, format!("노트 {}", i), "내용".to_string());
+                    engine.save(&note, None).unwrap();
+                    offset
+                })
+                .collect()
+        };
+        assert!(offsets[0] > WINDOW_SIZE as u64);
+
+        // 큰 엔트리 뒤 둘째 작은 엔트리의 CRC를 망가뜨림
+        let mut bytes = std::fs::read(&db_path).unwrap();
+        let crc_at = offsets[1] as usize + 4;
+        bytes[crc_at] ^= 0xff;
+        std::fs::write(&db_path, &bytes).unwrap();
+
+        let report = check(&db_path, &HashSet::new()).unwrap();
+        assert_eq!(report.valid_entries, 3);
+        assert_eq!(report.corrupted_regions, 1);
+        assert_eq!(report.first_corruption, Some(offsets[1]));
+
+        repair(&db_path, &HashSet::new()).unwrap();
+        let engine = StorageEngine::open(&db_path).unwrap();
+        assert_eq!(engine.count(), 3);
+        assert_eq!(engine.get(1).unwrap().unwrap().content, big);
+    }
+}
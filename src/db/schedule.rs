@@ -0,0 +1,242 @@
+//! 자동 백업 스케줄
+//!
+//! 설정은 백업 디렉토리의 `schedule.json`에 저장합니다. 실제 실행은
+//! `AppState::start_backup_scheduler`의 백그라운드 태스크가 합니다.
+
+use chrono::{DateTime, Local, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{LazarusError, Result};
+
+/// 기본 백업 간격 (분)
+pub const DEFAULT_INTERVAL_MINUTES: u64 = 6 * 60;
+
+/// 최대 백업 간격 (분, 365일)
+pub const MAX_INTERVAL_MINUTES: u64 = 365 * 24 * 60;
+
+/// 백업 주기
+///
+/// 문자열 형식: `off`, `30m`, `6h`, `1d`, `daily 03:00` (서버 현지 시각)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum BackupInterval {
+    Off,
+    Every { minutes: u64 },
+    Daily { at: NaiveTime },
+}
+
+impl Default for BackupInterval {
+    fn default() -> Self {
+        Self::Every {
+            minutes: DEFAULT_INTERVAL_MINUTES,
+        }
+    }
+}
+
+impl FromStr for BackupInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        if s.is_empty() || s == "off" {
+            return Ok(Self::Off);
+        }
+
+        if let Some(time) = s.strip_prefix("daily") {
+            let time = time.trim_start_matches(['@', ' ']);
+            return NaiveTime::parse_from_str(time, "%H:%M")
+                .map(|at| Self::Daily { at })
+                .map_err(|_| format!("잘못된 시각: {} (예: daily 03:00)", time));
+        }
+
+        let (number, scale) = if let Some(n) = s.strip_suffix('m') {
+            (n, 1)
+        } else if let Some(n) = s.strip_suffix('h') {
+            (n, 60)
+        } else if let Some(n) = s.strip_suffix('d') {
+            (n, 24 * 60)
+        } else {
+            return Err(format!("알 수 없는 백업 주기: {} (예: 30m, 6h, 1d)", s));
+        };
+        match number
+            .parse::<u64>()
+            .ok()
+            .and_then(|n| n.checked_mul(scale))
+        {
+            Some(minutes) if (1..=MAX_INTERVAL_MINUTES).contains(&minutes) => {
+                Ok(Self::Every { minutes })
+            }
+            _ => Err(format!("잘못된 백업 주기: {} (1m ~ 365d)", s)),
+        }
+    }
+}
+
+impl fmt::Display for BackupInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Every { minutes } if minutes % (24 * 60) == 0 => {
+                write!(f, "{}d", minutes / (24 * 60))
+            }
+            Self::Every { minutes } if minutes % 60 == 0 => write!(f, "{}h", minutes / 60),
+            Self::Every { minutes } => write!(f, "{}m", minutes),
+            Self::Daily { at } => write!(f, "daily {}", at.format("%H:%M")),
+        }
+    }
+}
+
+impl TryFrom<String> for BackupInterval {
+    type Error = String;
+
+    fn try_from(s: String) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<BackupInterval> for String {
+    fn from(interval: BackupInterval) -> Self {
+        interval.to_string()
+    }
+}
+
+impl BackupInterval {
+    /// 마지막 실행 이후 다음 실행 시각 (꺼져 있거나 표현할 수 없으면 None)
+    pub fn next_run(&self, last: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match *self {
+            Self::Off => None,
+            Self::Every { minutes } => {
+                let minutes = i64::try_from(minutes.min(MAX_INTERVAL_MINUTES)).ok()?;
+                last.checked_add_signed(chrono::Duration::try_minutes(minutes)?)
+            }
+            Self::Daily { at } => {
+                let local = last.with_timezone(&Local);
+                let today = local.date_naive().and_time(at);
+                let candidate = today
+                    .and_local_timezone(Local)
+                    .earliest()
+                    .filter(|t| *t > local)
+                    .or_else(|| {
+                        today
+                            .checked_add_signed(chrono::Duration::days(1))?
+                            .and_local_timezone(Local)
+                            .earliest()
+                    })?;
+                Some(candidate.with_timezone(&Utc))
+            }
+        }
+    }
+}
+
+/// 자동 백업 설정
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackupSchedule {
+    pub interval: BackupInterval,
+    /// 백업을 한 벌 더 복사할 디렉토리 (USB 등, 마운트돼 있어야 함)
+    pub mirror_dir: Option<PathBuf>,
+}
+
+impl BackupSchedule {
+    /// 파일에서 로드 (없으면 기본값)
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path).map_err(LazarusError::Io)?;
+        serde_json::from_str(&content).map_err(LazarusError::JsonParse)
+    }
+
+    /// 파일에 저장
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self).map_err(LazarusError::JsonParse)?;
+        fs::write(path, content).map_err(LazarusError::Io)?;
+        Ok(())
+    }
+}
+
+/// 마지막 자동/수동 백업 결과 (설정 화면과 `/health`에 표시)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BackupStatus {
+    pub last_run: Option<DateTime<Utc>>,
+    /// 마지막으로 새로 만든 백업 이름
+    pub last_backup: Option<String>,
+    /// 마지막 실행에서 변경이 없어 건너뛰었는지
+    pub skipped: bool,
+    pub error: Option<String>,
+    /// 두 번째 대상 복사 실패
+    pub mirror_error: Option<String>,
+    pub next_run: Option<DateTime<Utc>>,
+}
+
+impl BackupStatus {
+    /// 실패가 있는지
+    pub fn failed(&self) -> bool {
+        self.error.is_some() || self.mirror_error.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Timelike;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!("off".parse(), Ok(BackupInterval::Off));
+        assert_eq!("90m".parse(), Ok(BackupInterval::Every { minutes: 90 }));
+        assert_eq!("6h".parse::<BackupInterval>().unwrap().to_string(), "6h");
+        assert_eq!("2d".parse::<BackupInterval>().unwrap().to_string(), "2d");
+        assert_eq!(
+            "daily@03:30".parse::<BackupInterval>().unwrap().to_string(),
+            "daily 03:30"
+        );
+        assert!("0h".parse::<BackupInterval>().is_err());
+        assert!("weekly".parse::<BackupInterval>().is_err());
+        assert!("6시".parse::<BackupInterval>().is_err());
+        assert!("daily 25:00".parse::<BackupInterval>().is_err());
+
+        // 곱셈이 넘치거나 365일을 넘는 간격은 거부
+        assert_eq!(
+            "365d".parse(),
+            Ok(BackupInterval::Every {
+                minutes: MAX_INTERVAL_MINUTES
+            })
+        );
+        assert!("366d".parse::<BackupInterval>().is_err());
+        assert!("18446744073709551615d".parse::<BackupInterval>().is_err());
+        assert!("99999999999999999999m".parse::<BackupInterval>().is_err());
+    }
+
+    #[test]
+    fn test_next_run() {
+        let last = Utc::now();
+        assert_eq!(BackupInterval::Off.next_run(last), None);
+        assert_eq!(
+            BackupInterval::Every { minutes: 30 }.next_run(last),
+            Some(last + chrono::Duration::minutes(30))
+        );
+
+        // 직접 만든 큰 간격이나 표현 범위 끝에서도 패닉하지 않음
+        let huge = BackupInterval::Every { minutes: u64::MAX };
+        assert_eq!(
+            huge.next_run(last),
+            Some(last + chrono::Duration::minutes(MAX_INTERVAL_MINUTES as i64))
+        );
+        assert_eq!(huge.next_run(DateTime::<Utc>::MAX_UTC), None);
+        let at = NaiveTime::from_hms_opt(3, 0, 0).unwrap();
+        assert_eq!(
+            BackupInterval::Daily { at }.next_run(DateTime::<Utc>::MAX_UTC),
+            None
+        );
+
+        let next = BackupInterval::Daily { at }.next_run(last).unwrap();
+        assert!(next > last);
+        assert!(next - last <= chrono::Duration::hours(25));
+        let local = next.with_timezone(&Local);
+        assert_eq!((local.hour(), local.minute()), (3, 0));
+    }
+}
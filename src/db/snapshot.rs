@@ -0,0 +1,177 @@
+//! 백업 스냅샷 (읽기 전용)
+//!
+//! 백업에서 풀어낸 WAL 임시 파일을 훑어 노트별 최신 엔트리 위치만 기억하고,
+//! 노트는 읽을 때마다 파일에서 가져옵니다. 라이브 DB를 건드리지 않고 그
+//! 시점의 노트를 보고 검색할 수 있으며, 백업 전체를 메모리에 올리지 않습니다.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+use super::engine::decode_atom;
+use super::note::{Note, NoteAtom};
+use super::repair;
+use super::wal::ENTRY_HEADER_SIZE;
+use crate::crypto::CryptoManager;
+use crate::error::{LazarusError, Result};
+
+/// 백업 시점의 노트 (ID별 최신 버전)
+pub struct BackupSnapshot {
+    /// 풀어낸 WAL 임시 파일 (드롭할 때 지움)
+    path: PathBuf,
+    file: File,
+    /// ID별 최신 엔트리 데이터 위치 (오프셋, 길이)
+    entries: BTreeMap<u64, (u64, usize)>,
+}
+
+impl BackupSnapshot {
+    /// 압축 해제된 WAL 임시 파일에서 생성
+    ///
+    /// 스냅샷이 파일을 넘겨받아 읽기 전용으로 열고, 드롭할 때 지웁니다.
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                let _ = fs::remove_file(&path);
+                return Err(LazarusError::Io(e));
+            }
+        };
+        let mut snapshot = Self {
+            path,
+            file,
+            entries: BTreeMap::new(),
+        };
+
+        let mut entries = BTreeMap::new();
+        let report = repair::scan_file(&snapshot.path, &Default::default(), |pos, data| {
+            let atom = deserialize(data)?;
+            if atom.deleted {
+                entries.remove(&atom.id);
+            } else {
+                let offset = pos + ENTRY_HEADER_SIZE as u64;
+                entries.insert(atom.id, (offset, data.len()));
+            }
+            Ok(())
+        })?;
+        if !report.is_clean() {
+            tracing::warn!(
+                "백업 스냅샷에 손상 구간 {}개 ({} 바이트) - 읽을 수 있는 엔트리만 사용",
+                report.corrupted_regions,
+                report.skipped_bytes
+            );
+        }
+
+        snapshot.entries = entries;
+        Ok(snapshot)
+    }
+
+    /// 노트 수
+    pub fn count(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 노트 ID 목록 (오름차순)
+    pub fn ids(&self) -> Vec<u64> {
+        self.entries.keys().copied().collect()
+    }
+
+    /// 노트 읽기 (암호화됐는데 키가 없으면 내용을 숨긴 노트)
+    pub fn get(&self, id: u64, crypto: Option<&CryptoManager>) -> Result<Option<Note>> {
+        let Some(&(offset, len)) = self.entries.get(&id) else {
+            return Ok(None);
+        };
+        let mut data = vec![0u8; len];
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut data)?;
+        decode_atom(deserialize(&data)?, crypto)
+    }
+
+    /// 전체 노트 (ID순)
+    pub fn notes(&self, crypto: Option<&CryptoManager>) -> Result<Vec<Note>> {
+        let mut notes = Vec::with_capacity(self.entries.len());
+        for id in self.entries.keys() {
+            if let Some(note) = self.get(*id, crypto)? {
+                notes.push(note);
+            }
+        }
+        Ok(notes)
+    }
+
+    /// 제목/내용/태그 검색 (대소문자 무시, 잠긴 노트는 제외)
+    pub fn search(&self, query: &str, crypto: Option<&CryptoManager>) -> Result<Vec<Note>> {
+        let query = query.trim().to_lowercase();
+        let notes = self.notes(crypto)?;
+        if query.is_empty() {
+            return Ok(notes);
+        }
+
+        Ok(notes
+            .into_iter()
+            .filter(|note| !note.encrypted || crypto.is_some())
+            .filter(|note| {
+                note.title.to_lowercase().contains(&query)
+                    || note.content.to_lowercase().contains(&query)
+                    || note.tags.iter().any(|t| t.to_lowercase().contains(&query))
+            })
+            .collect())
+    }
+}
+
+impl Drop for BackupSnapshot {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            tracing::warn!("백업 스냅샷 임시 파일 삭제 실패: {}", e);
+        }
+    }
+}
+
+/// WAL 엔트리 데이터를 노트 원자로 (rkyv는 정렬된 버퍼가 필요)
+fn deserialize(data: &[u8]) -> Result<NoteAtom> {
+    let mut aligned = rkyv::AlignedVec::with_capacity(data.len());
+    aligned.extend_from_slice(data);
+    rkyv::from_bytes::<NoteAtom>(&aligned).map_err(|e| LazarusError::Deserialize(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::StorageEngine;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_latest_versions() {
+        let tmp = TempDir::new().unwrap();
+        let db_path = tmp.path().join("notes.lazarus");
+        {
+            let mut engine = StorageEngine::open(&db_path).unwrap();
+            let mut note = Note::new(0, "사과".to_string(), "빨강".to_string());
+            note.id = engine.save(&note, None).unwrap();
+            note.content = "초록".to_string();
+            engine.save(&note, None).unwrap();
+
+            let other = Note::new(0, "바나나".to_string(), "노랑".to_string());
+            let other_id = engine.save(&other, None).unwrap();
+            engine.delete(other_id).unwrap();
+            engine
+                .save(&Note::new(0, "포도".to_string(), "보라".to_string()), None)
+                .unwrap();
+        }
+
+        let copy = tmp.path().join("snapshot.tmp");
+        std::fs::copy(&db_path, &copy).unwrap();
+        let snapshot = BackupSnapshot::open(copy.clone()).unwrap();
+        assert_eq!(snapshot.ids(), vec![1, 3]);
+        assert_eq!(snapshot.get(1, None).unwrap().unwrap().content, "초록");
+        assert!(snapshot.get(2, None).unwrap().is_none());
+
+        let found = snapshot.search("보라", None).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].title, "포도");
+        assert_eq!(snapshot.search("", None).unwrap().len(), 2);
+
+        drop(snapshot);
+        assert!(!copy.exists());
+    }
+}
@@ -8,15 +8,101 @@ use std::io::{BufReader, Read, Write};
 
 use crate::error::{LazarusError, Result};
 
-/// WAL 파일 매직 바이트
-pub const MAGIC_BYTES: &[u8; 8] = b"LAZARUS\x01";
+/// WAL 파일 매직 바이트 (마지막 바이트가 버전)
+pub const MAGIC_BYTES: &[u8; 8] = b"LAZARUS\x02";
 
 /// WAL 파일 버전
-pub const VERSION: u8 = 1;
+///
+/// - 1: 노트 프론트매터에 제목/태그만 저장
+/// - 2: 프론트매터 스키마 2 (별점, 기분, 타입, 시각까지 저장)
+pub const VERSION: u8 = 2;
+
+/// 읽을 수 있는 가장 오래된 버전 (이전 버전은 열 때 마이그레이션)
+pub const MIN_VERSION: u8 = 1;
+
+/// 파일의 WAL 버전 (빈 파일이나 없는 파일이면 None)
+pub fn file_version(path: &str) -> Result<Option<u8>> {
+    let mut file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    if file.metadata()?.len() == 0 {
+        return Ok(None);
+    }
+
+    let mut magic = [0u8; 8];
+    file.read_exact(&mut magic)?;
+    check_magic(&magic).map(Some)
+}
+
+/// 매직 바이트 확인 후 버전 반환
+fn check_magic(magic: &[u8; 8]) -> Result<u8> {
+    let version = magic[7];
+    if magic[..7] != MAGIC_BYTES[..7] || !(MIN_VERSION..=VERSION).contains(&version) {
+        return Err(LazarusError::DbCorruption {
+            expected: 0x4C415A41, // "LAZA"
+            actual: u32::from_le_bytes(magic[0..4].try_into().unwrap()),
+        });
+    }
+    Ok(version)
+}
 
 /// 엔트리 헤더 크기 (길이 4 + CRC 4)
 pub const ENTRY_HEADER_SIZE: usize = 8;
 
+/// group commit 기본 주기 (ms)
+pub const DEFAULT_GROUP_COMMIT_MS: u64 = 50;
+
+/// WAL 내구성 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Durability {
+    /// 저장할 때마다 fsync (기본)
+    #[default]
+    AlwaysFsync,
+    /// 쓰기는 바로 하고 fsync는 `interval_ms`마다 한 번에 (전원이 나가면 그 사이 쓰기 유실 가능)
+    GroupCommit { interval_ms: u64 },
+}
+
+impl std::str::FromStr for Durability {
+    type Err = String;
+
+    /// `always-fsync`, `group-commit`, `group-commit:100` (ms)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim().to_lowercase();
+        let (mode, interval) = match s.split_once(':') {
+            Some((mode, interval)) => (mode, Some(interval)),
+            None => (s.as_str(), None),
+        };
+        match (mode, interval) {
+            ("always-fsync" | "always", None) => Ok(Self::AlwaysFsync),
+            ("group-commit" | "group", None) => Ok(Self::GroupCommit {
+                interval_ms: DEFAULT_GROUP_COMMIT_MS,
+            }),
+            ("group-commit" | "group", Some(ms)) => ms
+                .trim_end_matches("ms")
+                .parse()
+                .ok()
+                .filter(|ms| *ms > 0)
+                .map(|interval_ms| Self::GroupCommit { interval_ms })
+                .ok_or_else(|| format!("잘못된 group commit 주기: {}", ms)),
+            _ => Err(format!(
+                "알 수 없는 내구성 모드: {} (always-fsync, group-commit[:ms])",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Durability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlwaysFsync => write!(f, "always-fsync"),
+            Self::GroupCommit { interval_ms } => write!(f, "group-commit:{}", interval_ms),
+        }
+    }
+}
+
 /// WAL 엔트리
 #[derive(Debug)]
 pub struct WalEntry {
@@ -61,6 +147,7 @@ pub struct WalReader {
     reader: BufReader<File>,
     current_offset: u64,
     file_len: u64,
+    version: u8,
 }
 
 impl WalReader {
@@ -73,21 +160,29 @@ impl WalReader {
         // 매직 바이트 확인
         let mut magic = [0u8; 8];
         reader.read_exact(&mut magic)?;
-
-        if &magic != MAGIC_BYTES {
-            return Err(LazarusError::DbCorruption {
-                expected: 0x4C415A41, // "LAZA"
-                actual: u32::from_le_bytes(magic[0..4].try_into().unwrap()),
-            });
-        }
+        let version = check_magic(&magic)?;
 
         Ok(Self {
             reader,
             current_offset: 8, // 매직 바이트 이후
             file_len,
+            version,
         })
     }
 
+    /// 파일 버전
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// 지정한 엔트리 헤더 오프셋부터 읽기
+    pub fn seek(&mut self, offset: u64) -> Result<()> {
+        use std::io::{Seek, SeekFrom};
+        self.reader.seek(SeekFrom::Start(offset))?;
+        self.current_offset = offset;
+        Ok(())
+    }
+
     /// 다음 엔트리 읽기
     pub fn next_entry(&mut self) -> Result<Option<(u64, WalEntry)>> {
         if self.current_offset >= self.file_len {
@@ -107,6 +202,15 @@ impl WalReader {
         let len = u32::from_le_bytes(header[0..4].try_into().unwrap());
         let crc = u32::from_le_bytes(header[4..8].try_into().unwrap());
 
+        // 길이가 파일 끝을 넘으면 헤더가 깨졌거나 찢긴 쓰기 (거대한 버퍼를 잡지 않음)
+        let end = self.current_offset + ENTRY_HEADER_SIZE as u64 + len as u64;
+        if end > self.file_len {
+            return Err(LazarusError::DbRecovery(format!(
+                "엔트리 길이({})가 파일 끝을 넘음 (오프셋 {})",
+                len, self.current_offset
+            )));
+        }
+
         // 데이터 읽기
         let mut data = vec![0u8; len as usize];
         self.reader.read_exact(&mut data)?;
@@ -143,6 +247,8 @@ pub struct WalWriter {
     buffer: Vec<u8>,
     buffer_size: usize,
     current_offset: u64,
+    /// 파일에 썼지만 아직 fsync하지 않은 바이트
+    unsynced: u64,
 }
 
 impl WalWriter {
@@ -170,6 +276,7 @@ impl WalWriter {
             buffer: Vec::with_capacity(buffer_size),
             buffer_size,
             current_offset,
+            unsynced: 0,
         })
     }
 
@@ -182,29 +289,70 @@ impl WalWriter {
 
         self.buffer.extend_from_slice(&bytes);
 
-        // 버퍼가 차면 플러시
+        // 버퍼가 차면 파일에 쓰기 (fsync는 호출자가 결정)
         if self.buffer.len() >= self.buffer_size {
-            self.flush()?;
+            self.write_buffer()?;
         }
 
         Ok(entry_offset)
     }
 
-    /// 버퍼 플러시
-    pub fn flush(&mut self) -> Result<()> {
+    /// 버퍼를 파일에 쓰기 (fsync 없음)
+    pub fn write_buffer(&mut self) -> Result<()> {
         if self.buffer.is_empty() {
             return Ok(());
         }
 
         self.file.write_all(&self.buffer)?;
-        self.file.sync_data()?;
 
         self.current_offset += self.buffer.len() as u64;
+        self.unsynced += self.buffer.len() as u64;
         self.buffer.clear();
 
         Ok(())
     }
 
+    /// 파일에 쓴 내용을 디스크에 내리기
+    pub fn sync(&mut self) -> Result<()> {
+        if self.unsynced == 0 {
+            return Ok(());
+        }
+
+        self.file.sync_data()?;
+        self.unsynced = 0;
+
+        Ok(())
+    }
+
+    /// 버퍼를 파일에 쓰고, 락 밖에서 fsync할 파일 핸들을 넘기기
+    ///
+    /// 반환값은 (복제한 핸들, 그 핸들로 내릴 바이트 수)이며 내릴 것이 없으면 `None`.
+    /// fsync가 끝나면 [`WalWriter::mark_synced`]로 알려야 합니다.
+    pub fn begin_sync(&mut self) -> Result<Option<(File, u64)>> {
+        self.write_buffer()?;
+        if self.unsynced == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some((self.file.try_clone()?, self.unsynced)))
+    }
+
+    /// `begin_sync` 핸들의 fsync 완료 (그 사이에 쓴 바이트는 대기로 남음)
+    pub fn mark_synced(&mut self, bytes: u64) {
+        self.unsynced = self.unsynced.saturating_sub(bytes);
+    }
+
+    /// 버퍼 플러시 (쓰기 + fsync)
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_buffer()?;
+        self.sync()
+    }
+
+    /// 아직 디스크에 내려가지 않은 바이트 (버퍼 + fsync 대기)
+    pub fn pending_bytes(&self) -> u64 {
+        self.buffer.len() as u64 + self.unsynced
+    }
+
     /// 현재 오프셋
     pub fn offset(&self) -> u64 {
         self.current_offset + self.buffer.len() as u64
@@ -254,4 +402,56 @@ mod tests {
             assert!(reader.next_entry().unwrap().is_none());
         }
     }
+
+    #[test]
+    fn test_wal_version() {
+        let tmp = NamedTempFile::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+        assert_eq!(file_version(path).unwrap(), None);
+
+        WalWriter::open(path, 4096).unwrap();
+        assert_eq!(file_version(path).unwrap(), Some(VERSION));
+
+        std::fs::write(path, b"LAZARUS\x01").unwrap();
+        assert_eq!(WalReader::open(path).unwrap().version(), 1);
+
+        std::fs::write(path, b"LAZARUS\x09").unwrap();
+        assert!(WalReader::open(path).is_err());
+    }
+
+    #[test]
+    fn test_pending_bytes() {
+        let tmp = NamedTempFile::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+
+        let mut writer = WalWriter::open(path, 4096).unwrap();
+        writer.append(b"entry".to_vec()).unwrap();
+        let pending = (ENTRY_HEADER_SIZE + 5) as u64;
+        assert_eq!(writer.pending_bytes(), pending);
+
+        // 파일에는 썼지만 fsync 전
+        writer.write_buffer().unwrap();
+        assert_eq!(writer.pending_bytes(), pending);
+        assert_eq!(std::fs::metadata(path).unwrap().len(), 8 + pending);
+
+        writer.sync().unwrap();
+        assert_eq!(writer.pending_bytes(), 0);
+    }
+
+    #[test]
+    fn test_parse_durability() {
+        assert_eq!("always-fsync".parse(), Ok(Durability::AlwaysFsync));
+        assert_eq!(
+            "group-commit".parse(),
+            Ok(Durability::GroupCommit {
+                interval_ms: DEFAULT_GROUP_COMMIT_MS
+            })
+        );
+        assert_eq!(
+            "group-commit:200ms".parse(),
+            Ok(Durability::GroupCommit { interval_ms: 200 })
+        );
+        assert!("group-commit:0".parse::<Durability>().is_err());
+        assert!("sometimes".parse::<Durability>().is_err());
+    }
 }
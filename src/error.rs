@@ -24,6 +24,9 @@ pub enum LazarusError {
     #[error("복구 실패: {0}")]
     DbRecovery(String),
 
+    #[error("백업 무결성 오류: {0}")]
+    BackupIntegrity(String),
+
     // === 검색 에러 ===
     #[error("인덱스 생성 실패: {0}")]
     IndexCreate(String),
@@ -85,6 +88,9 @@ pub enum LazarusError {
     #[error("잘못된 요청: {0}")]
     BadRequest(String),
 
+    #[error("PIN 시도가 너무 많습니다: {retry_after_secs}초 후에 다시 시도하세요")]
+    TooManyAttempts { retry_after_secs: u64 },
+
     #[error("리소스를 찾을 수 없음: {0}")]
     NotFound(String),
 
@@ -117,6 +123,16 @@ impl axum::response::IntoResponse for LazarusError {
     fn into_response(self) -> axum::response::Response {
         use axum::http::StatusCode;
 
+        if let LazarusError::TooManyAttempts { retry_after_secs } = &self {
+            let retry_after = retry_after_secs.to_string();
+            return (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(axum::http::header::RETRY_AFTER, retry_after)],
+                self.to_string(),
+            )
+                .into_response();
+        }
+
         let (status, message) = match &self {
             LazarusError::NotFound(_) => (StatusCode::NOT_FOUND, self.to_string()),
             LazarusError::BadRequest(_) => (StatusCode::BAD_REQUEST, self.to_string()),
@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "بدون عنوان");
     data.insert("notes.edit", "تحرير");
     data.insert("notes.delete", "حذف");
+    data.insert("notes.history", "السجل");
+    data.insert("notes.revision", "مراجعة");
+    data.insert("notes.current", "الحالية");
+    data.insert("notes.compare", "مقارنة");
+    data.insert("notes.restore", "استعادة");
+    data.insert(
+        "notes.restore_confirm",
+        "استعادة هذه المراجعة؟ ستبقى النسخة الحالية في السجل.",
+    );
+    data.insert("notes.no_changes", "لا تغييرات");
+    data.insert("notes.rating", "التقييم");
+    data.insert("notes.mood", "المزاج");
     data.insert("notes.delete_confirm", "هل أنت متأكد من الحذف؟");
     data.insert("notes.encrypted", "مشفر");
     data.insert("notes.created", "تم الإنشاء");
     data.insert("notes.updated", "تم التحديث");
+    data.insert("notes.related", "ملاحظات ذات صلة");
     data.insert("notes.find_duplicates", "البحث عن المكررات");
     data.insert("notes.select_all", "تحديد الكل");
     data.insert("notes.selected", "محدد");
@@ -98,6 +111,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "يبحث في الملاحظات وويكيبيديا");
     data.insert("search.tip3", "تظهر مطابقات العنوان أولاً");
+    data.insert("search.tip4", "المرشحات: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"العنوان\" has:link is:encrypted");
+    data.insert("search.scope_notes", "الملاحظات");
+    data.insert("search.scope_encrypted", "الملاحظات المشفرة");
 
     // Wiki
     data.insert("wiki.search", "بحث ويكي");
@@ -134,6 +150,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "تمت إزالة ZIM: ");
     data.insert("wiki.remove_failed", "فشلت الإزالة");
     data.insert("wiki.open_folder_msg", "يرجى فتح المجلد في مستكشف الملفات:");
+    data.insert("wiki.mode_title", "العناوين");
+    data.insert("wiki.mode_fulltext", "النص الكامل");
+    data.insert(
+        "wiki.index_missing",
+        "لا يوجد فهرس نص كامل لهذا الملف بعد. أنشئه من",
+    );
+    data.insert("wiki.fulltext_index", "فهرس النص الكامل");
+    data.insert("wiki.build_index", "إنشاء الفهرس");
+    data.insert("wiki.all_zims", "كل الأرشيفات");
+    data.insert("wiki.verify", "تحقق");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "مراجعة SRS");
@@ -184,6 +218,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "تم فتح القفل");
     data.insert("security.pin_min_length", "يجب أن يكون PIN 6 أحرف على الأقل");
     data.insert("security.enter_current_pin", "أدخل PIN الحالي");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "طباعة");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "النسخ الاحتياطي");
@@ -214,6 +288,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "الإصدار");
     data.insert("settings.storage", "التخزين");
     data.insert("settings.about", "حول");
+    data.insert("settings.backup", "النسخ الاحتياطي التلقائي");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "حفظ");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "خريطة المعرفة");
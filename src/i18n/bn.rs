@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "শিরোনামহীন");
     data.insert("notes.edit", "সম্পাদনা");
     data.insert("notes.delete", "মুছুন");
+    data.insert("notes.history", "ইতিহাস");
+    data.insert("notes.revision", "সংশোধন");
+    data.insert("notes.current", "বর্তমান");
+    data.insert("notes.compare", "তুলনা");
+    data.insert("notes.restore", "পুনরুদ্ধার");
+    data.insert(
+        "notes.restore_confirm",
+        "এই সংশোধন পুনরুদ্ধার করবেন? বর্তমান সংস্করণ ইতিহাসে থাকবে।",
+    );
+    data.insert("notes.no_changes", "কোনো পরিবর্তন নেই");
+    data.insert("notes.rating", "রেটিং");
+    data.insert("notes.mood", "মেজাজ");
     data.insert("notes.delete_confirm", "আপনি কি নিশ্চিত মুছতে চান?");
     data.insert("notes.encrypted", "এনক্রিপ্টেড");
     data.insert("notes.created", "তৈরি");
     data.insert("notes.updated", "আপডেট");
+    data.insert("notes.related", "সম্পর্কিত");
     data.insert("notes.find_duplicates", "সদৃশ খুঁজুন");
     data.insert("notes.select_all", "সব নির্বাচন");
     data.insert("notes.selected", "নির্বাচিত");
@@ -95,6 +108,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "একাধিক শব্দ সব শব্দ সহ ফলাফল খোঁজে");
     data.insert("search.tip2", "নোট এবং উইকিপিডিয়া উভয়ে অনুসন্ধান করে");
     data.insert("search.tip3", "শিরোনাম মিল প্রথমে দেখায়");
+    data.insert("search.tip4", "ফিল্টার: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"শিরোনাম\" has:link is:encrypted");
+    data.insert("search.scope_notes", "নোট");
+    data.insert("search.scope_encrypted", "এনক্রিপ্ট করা নোট");
 
     // Wiki
     data.insert("wiki.search", "উইকি অনুসন্ধান");
@@ -131,6 +147,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM সরানো হয়েছে: ");
     data.insert("wiki.remove_failed", "সরানো ব্যর্থ");
     data.insert("wiki.open_folder_msg", "ফাইল এক্সপ্লোরারে ফোল্ডারটি খুলুন:");
+    data.insert("wiki.mode_title", "শিরোনাম");
+    data.insert("wiki.mode_fulltext", "সম্পূর্ণ লেখা");
+    data.insert(
+        "wiki.index_missing",
+        "এই ZIM-এর জন্য এখনও পূর্ণ-লেখা সূচি নেই। এখান থেকে তৈরি করুন:",
+    );
+    data.insert("wiki.fulltext_index", "পূর্ণ-লেখা সূচি");
+    data.insert("wiki.build_index", "সূচি তৈরি করুন");
+    data.insert("wiki.all_zims", "সব আর্কাইভ");
+    data.insert("wiki.verify", "যাচাই করুন");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "SRS পর্যালোচনা");
@@ -181,6 +215,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "আনলক হয়েছে");
     data.insert("security.pin_min_length", "PIN কমপক্ষে ৬ অক্ষর হতে হবে");
     data.insert("security.enter_current_pin", "বর্তমান PIN দিন");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "প্রিন্ট");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "ব্যাকআপ");
@@ -208,6 +282,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "সংস্করণ");
     data.insert("settings.storage", "স্টোরেজ");
     data.insert("settings.about", "সম্পর্কে");
+    data.insert("settings.backup", "স্বয়ংক্রিয় ব্যাকআপ");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "সংরক্ষণ");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "জ্ঞান গ্রাফ");
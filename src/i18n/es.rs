@@ -65,10 +65,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Sin Título");
     data.insert("notes.edit", "Editar");
     data.insert("notes.delete", "Eliminar");
+    data.insert("notes.history", "Historial");
+    data.insert("notes.revision", "Revisión");
+    data.insert("notes.current", "Actual");
+    data.insert("notes.compare", "Comparar");
+    data.insert("notes.restore", "Restaurar");
+    data.insert(
+        "notes.restore_confirm",
+        "¿Restaurar esta revisión? La versión actual queda en el historial.",
+    );
+    data.insert("notes.no_changes", "Sin cambios");
+    data.insert("notes.rating", "Valoración");
+    data.insert("notes.mood", "Ánimo");
     data.insert("notes.delete_confirm", "¿Está seguro de eliminar?");
     data.insert("notes.encrypted", "Cifrado");
     data.insert("notes.created", "Creado");
     data.insert("notes.updated", "Actualizado");
+    data.insert("notes.related", "Relacionadas");
     data.insert("notes.find_duplicates", "Buscar Duplicados");
     data.insert("notes.select_all", "Seleccionar Todo");
     data.insert("notes.selected", "seleccionados");
@@ -107,6 +120,9 @@ pub fn translations() -> Translations {
         "search.tip3",
         "Las coincidencias de título aparecen primero",
     );
+    data.insert("search.tip4", "Filtros: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Título\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Notas");
+    data.insert("search.scope_encrypted", "Notas cifradas");
 
     // Wiki
     data.insert("wiki.search", "Buscar Wiki");
@@ -149,6 +165,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "Abra la carpeta en el explorador de archivos:",
     );
+    data.insert("wiki.mode_title", "Títulos");
+    data.insert("wiki.mode_fulltext", "Texto completo");
+    data.insert(
+        "wiki.index_missing",
+        "Este ZIM aún no tiene índice de texto completo. Créalo desde",
+    );
+    data.insert("wiki.fulltext_index", "Índice de texto completo");
+    data.insert("wiki.build_index", "Crear índice");
+    data.insert("wiki.all_zims", "Todos los archivos");
+    data.insert("wiki.verify", "Verificar");
+    data.insert("wiki.verify_ok", "Correcto");
+    data.insert("wiki.verify_checksum", "Comprobando MD5");
+    data.insert("wiki.verify_clusters", "Comprobando clústeres");
+    data.insert("wiki.verify_entries", "Comprobando entradas");
+    data.insert("wiki.verify_md5_mismatch", "MD5 no coincide");
+    data.insert("wiki.verify_bad_clusters", "clústeres dañados");
+    data.insert("wiki.verify_unreadable", "entradas ilegibles");
+    data.insert("wiki.verify_failed", "Error de verificación");
 
     // SRS
     data.insert("srs.title", "Revisión SRS");
@@ -202,6 +236,46 @@ pub fn translations() -> Translations {
         "El PIN debe tener al menos 6 caracteres",
     );
     data.insert("security.enter_current_pin", "Ingrese PIN actual");
+    data.insert("security.recovery_create", "Crear hoja de recuperación");
+    data.insert(
+        "security.recovery_forgot",
+        "¿Olvidó el PIN? Use su clave de recuperación",
+    );
+    data.insert("security.recovery_key", "Clave de recuperación");
+    data.insert(
+        "security.recovery_new_pin",
+        "Introduzca la clave de recuperación y escriba un PIN nuevo arriba.",
+    );
+    data.insert("security.recovery_submit", "Establecer PIN nuevo");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Cualquiera con esta clave puede abrir sus notas. Imprímala o anótela y guárdela lejos de este dispositivo. Solo se muestra una vez y reemplaza cualquier clave anterior.");
+    data.insert("security.recovery_print", "Imprimir");
+    data.insert(
+        "security.failed_attempts",
+        "Intentos de PIN fallidos desde el último desbloqueo",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Borrar las notas cifradas tras esta cantidad de PIN erróneos seguidos (0 = nunca)",
+    );
+    data.insert("security.wipe_warning", "El borrado destruye la clave, las notas cifradas y todas las copias de la carpeta de datos. No se puede deshacer. Las notas sin cifrar se conservan.");
+    data.insert("security.wipe_save", "Guardar política");
+    data.insert(
+        "security.idle_lock",
+        "Bloquear tras estos minutos de inactividad (0 = desactivado)",
+    );
+    data.insert("security.idle_lock_hint", "La bóveda también se bloquea al despertar el equipo. Cada navegador debe desbloquearse con el PIN por separado.");
+    data.insert("security.idle_lock_save", "Guardar");
+    data.insert(
+        "security.duress_pin",
+        "PIN de coacción (vacío para desactivar)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destruir la clave de la bóveda real al usar el PIN de coacción",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Guardar");
 
     // Backup
     data.insert("backup.title", "Respaldo");
@@ -229,6 +303,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Versión");
     data.insert("settings.storage", "Almacenamiento");
     data.insert("settings.about", "Acerca de");
+    data.insert("settings.backup", "Copia de seguridad automática");
+    data.insert(
+        "settings.backup_desc",
+        "Hace copias según un horario. Se omite si no hay cambios.",
+    );
+    data.insert("settings.backup_interval", "Intervalo");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Segunda carpeta de copias");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "Por ejemplo, una memoria USB. Debe estar montada. Déjelo vacío para desactivar.",
+    );
+    data.insert("settings.backup_save", "Guardar");
+    data.insert("settings.backup_now", "Hacer copia ahora");
+    data.insert("settings.backup_last_run", "Última ejecución");
+    data.insert("settings.backup_next_run", "Próxima ejecución");
+    data.insert("settings.backup_skipped", "Sin cambios, omitida");
+    data.insert("settings.backup_failed", "La copia falló");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Falló la copia a la segunda carpeta",
+    );
+    data.insert("settings.backup_never", "Nunca");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Grafo de Conocimiento");
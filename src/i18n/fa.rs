@@ -59,6 +59,18 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "بدون عنوان");
     data.insert("notes.edit", "ویرایش");
     data.insert("notes.delete", "حذف");
+    data.insert("notes.history", "تاریخچه");
+    data.insert("notes.revision", "نسخه");
+    data.insert("notes.current", "فعلی");
+    data.insert("notes.compare", "مقایسه");
+    data.insert("notes.restore", "بازیابی");
+    data.insert(
+        "notes.restore_confirm",
+        "این نسخه بازیابی شود؟ نسخه فعلی در تاریخچه می‌ماند.",
+    );
+    data.insert("notes.no_changes", "بدون تغییر");
+    data.insert("notes.rating", "امتیاز");
+    data.insert("notes.mood", "حال");
     data.insert(
         "notes.delete_confirm",
         "آیا مطمئن هستید که می‌خواهید حذف کنید؟",
@@ -66,6 +78,7 @@ pub fn translations() -> Translations {
     data.insert("notes.encrypted", "رمزنگاری شده");
     data.insert("notes.created", "ایجاد شده");
     data.insert("notes.updated", "به‌روزرسانی شده");
+    data.insert("notes.related", "مرتبط");
     data.insert("notes.find_duplicates", "یافتن تکراری‌ها");
     data.insert("notes.select_all", "انتخاب همه");
     data.insert("notes.selected", "انتخاب شده");
@@ -101,6 +114,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "در یادداشت‌ها و ویکی‌پدیا جستجو می‌کند");
     data.insert("search.tip3", "تطابق عنوان ابتدا نمایش داده می‌شود");
+    data.insert("search.tip4", "فیلترها: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"عنوان\" has:link is:encrypted");
+    data.insert("search.scope_notes", "یادداشت‌ها");
+    data.insert("search.scope_encrypted", "یادداشت‌های رمزگذاری‌شده");
 
     // Wiki
     data.insert("wiki.search", "جستجوی ویکی");
@@ -140,6 +156,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM حذف شد: ");
     data.insert("wiki.remove_failed", "حذف ناموفق");
     data.insert("wiki.open_folder_msg", "پوشه را در مدیر فایل باز کنید:");
+    data.insert("wiki.mode_title", "عنوان‌ها");
+    data.insert("wiki.mode_fulltext", "متن کامل");
+    data.insert(
+        "wiki.index_missing",
+        "هنوز نمایه متن کامل برای این ZIM وجود ندارد. از اینجا بسازید:",
+    );
+    data.insert("wiki.fulltext_index", "نمایه متن کامل");
+    data.insert("wiki.build_index", "ساخت نمایه");
+    data.insert("wiki.all_zims", "همه آرشیوها");
+    data.insert("wiki.verify", "بررسی");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "مرور SRS");
@@ -190,6 +224,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "قفل باز شد");
     data.insert("security.pin_min_length", "پین باید حداقل ۶ کاراکتر باشد");
     data.insert("security.enter_current_pin", "پین فعلی را وارد کنید");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "چاپ");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "پشتیبان‌گیری");
@@ -217,6 +291,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "نسخه");
     data.insert("settings.storage", "ذخیره‌سازی");
     data.insert("settings.about", "درباره");
+    data.insert("settings.backup", "پشتیبان‌گیری خودکار");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "ذخیره");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "نمودار دانش");
@@ -65,6 +65,18 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Sans Titre");
     data.insert("notes.edit", "Modifier");
     data.insert("notes.delete", "Supprimer");
+    data.insert("notes.history", "Historique");
+    data.insert("notes.revision", "Révision");
+    data.insert("notes.current", "Actuelle");
+    data.insert("notes.compare", "Comparer");
+    data.insert("notes.restore", "Restaurer");
+    data.insert(
+        "notes.restore_confirm",
+        "Restaurer cette révision ? La version actuelle reste dans l'historique.",
+    );
+    data.insert("notes.no_changes", "Aucun changement");
+    data.insert("notes.rating", "Note");
+    data.insert("notes.mood", "Humeur");
     data.insert(
         "notes.delete_confirm",
         "Êtes-vous sûr de vouloir supprimer?",
@@ -72,6 +84,7 @@ pub fn translations() -> Translations {
     data.insert("notes.encrypted", "Chiffré");
     data.insert("notes.created", "Créé");
     data.insert("notes.updated", "Mis à jour");
+    data.insert("notes.related", "Notes liées");
     data.insert("notes.find_duplicates", "Trouver les Doublons");
     data.insert("notes.select_all", "Tout Sélectionner");
     data.insert("notes.selected", "sélectionnés");
@@ -110,6 +123,9 @@ pub fn translations() -> Translations {
         "search.tip3",
         "Les correspondances de titre apparaissent en premier",
     );
+    data.insert("search.tip4", "Filtres : tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Titre\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Notes");
+    data.insert("search.scope_encrypted", "Notes chiffrées");
 
     // Wiki
     data.insert("wiki.search", "Rechercher Wiki");
@@ -152,6 +168,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "Ouvrez le dossier dans l'explorateur de fichiers:",
     );
+    data.insert("wiki.mode_title", "Titres");
+    data.insert("wiki.mode_fulltext", "Texte intégral");
+    data.insert(
+        "wiki.index_missing",
+        "Ce ZIM n'a pas encore d'index plein texte. Créez-le depuis",
+    );
+    data.insert("wiki.fulltext_index", "Index plein texte");
+    data.insert("wiki.build_index", "Créer l'index");
+    data.insert("wiki.all_zims", "Toutes les archives");
+    data.insert("wiki.verify", "Vérifier");
+    data.insert("wiki.verify_ok", "Intact");
+    data.insert("wiki.verify_checksum", "Vérification MD5");
+    data.insert("wiki.verify_clusters", "Vérification des clusters");
+    data.insert("wiki.verify_entries", "Vérification des entrées");
+    data.insert("wiki.verify_md5_mismatch", "MD5 différent");
+    data.insert("wiki.verify_bad_clusters", "clusters endommagés");
+    data.insert("wiki.verify_unreadable", "entrées illisibles");
+    data.insert("wiki.verify_failed", "Échec de la vérification");
 
     // SRS
     data.insert("srs.title", "Révision SRS");
@@ -208,6 +242,49 @@ pub fn translations() -> Translations {
         "Le PIN doit avoir au moins 6 caractères",
     );
     data.insert("security.enter_current_pin", "Entrez le PIN actuel");
+    data.insert(
+        "security.recovery_create",
+        "Créer une fiche de récupération",
+    );
+    data.insert(
+        "security.recovery_forgot",
+        "PIN oublié ? Utilisez votre clé de récupération",
+    );
+    data.insert("security.recovery_key", "Clé de récupération");
+    data.insert(
+        "security.recovery_new_pin",
+        "Saisissez la clé de récupération, puis un nouveau PIN ci-dessus.",
+    );
+    data.insert("security.recovery_submit", "Définir un nouveau PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Toute personne possédant cette clé peut ouvrir vos notes. Imprimez-la ou notez-la et gardez-la loin de cet appareil. Elle n'est affichée qu'une fois et remplace toute clé précédente.");
+    data.insert("security.recovery_print", "Imprimer");
+    data.insert(
+        "security.failed_attempts",
+        "Tentatives de PIN erronées depuis le dernier déverrouillage",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Effacer les notes chiffrées après ce nombre de PIN erronés consécutifs (0 = jamais)",
+    );
+    data.insert("security.wipe_warning", "L'effacement détruit la clé, les notes chiffrées et toutes les sauvegardes du dossier de données. C'est irréversible. Les notes non chiffrées sont conservées.");
+    data.insert("security.wipe_save", "Enregistrer la règle");
+    data.insert(
+        "security.idle_lock",
+        "Verrouiller après ces minutes d'inactivité (0 = désactivé)",
+    );
+    data.insert("security.idle_lock_hint", "Le coffre se verrouille aussi à la sortie de veille. Chaque navigateur doit être déverrouillé avec le PIN.");
+    data.insert("security.idle_lock_save", "Enregistrer");
+    data.insert(
+        "security.duress_pin",
+        "PIN de contrainte (vide pour désactiver)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Détruire la clé du vrai coffre lors de l'utilisation du PIN de contrainte",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Enregistrer");
 
     // Backup
     data.insert("backup.title", "Sauvegarde");
@@ -241,6 +318,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Version");
     data.insert("settings.storage", "Stockage");
     data.insert("settings.about", "À propos");
+    data.insert("settings.backup", "Sauvegarde automatique");
+    data.insert(
+        "settings.backup_desc",
+        "Sauvegarde à intervalle régulier. Ignorée si rien n'a changé.",
+    );
+    data.insert("settings.backup_interval", "Intervalle");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second dossier de sauvegarde");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "Par exemple une clé USB. Elle doit être montée. Laissez vide pour désactiver.",
+    );
+    data.insert("settings.backup_save", "Enregistrer");
+    data.insert("settings.backup_now", "Sauvegarder maintenant");
+    data.insert("settings.backup_last_run", "Dernière exécution");
+    data.insert("settings.backup_next_run", "Prochaine exécution");
+    data.insert("settings.backup_skipped", "Aucun changement, ignorée");
+    data.insert("settings.backup_failed", "Échec de la sauvegarde");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Échec de la copie vers le second dossier",
+    );
+    data.insert("settings.backup_never", "Jamais");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Graphe de Connaissances");
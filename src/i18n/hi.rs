@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "बिना शीर्षक");
     data.insert("notes.edit", "संपादित");
     data.insert("notes.delete", "हटाएं");
+    data.insert("notes.history", "इतिहास");
+    data.insert("notes.revision", "संशोधन");
+    data.insert("notes.current", "वर्तमान");
+    data.insert("notes.compare", "तुलना करें");
+    data.insert("notes.restore", "पुनर्स्थापित करें");
+    data.insert(
+        "notes.restore_confirm",
+        "यह संशोधन पुनर्स्थापित करें? वर्तमान संस्करण इतिहास में रहेगा।",
+    );
+    data.insert("notes.no_changes", "कोई बदलाव नहीं");
+    data.insert("notes.rating", "रेटिंग");
+    data.insert("notes.mood", "मनोदशा");
     data.insert("notes.delete_confirm", "क्या आप वाकई हटाना चाहते हैं?");
     data.insert("notes.encrypted", "एन्क्रिप्टेड");
     data.insert("notes.created", "बनाया गया");
     data.insert("notes.updated", "अपडेट किया गया");
+    data.insert("notes.related", "संबंधित");
     data.insert("notes.find_duplicates", "डुप्लिकेट खोजें");
     data.insert("notes.select_all", "सभी चुनें");
     data.insert("notes.selected", "चयनित");
@@ -95,6 +108,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "कई शब्द सभी शब्दों वाले परिणाम खोजते हैं");
     data.insert("search.tip2", "नोट्स और विकिपीडिया दोनों में खोजता है");
     data.insert("search.tip3", "शीर्षक मिलान पहले दिखाई देते हैं");
+    data.insert("search.tip4", "फ़िल्टर: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"शीर्षक\" has:link is:encrypted");
+    data.insert("search.scope_notes", "नोट्स");
+    data.insert("search.scope_encrypted", "एन्क्रिप्टेड नोट्स");
 
     // Wiki
     data.insert("wiki.search", "विकी खोजें");
@@ -134,6 +150,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM हटाई गई: ");
     data.insert("wiki.remove_failed", "हटाना विफल");
     data.insert("wiki.open_folder_msg", "कृपया फ़ाइल एक्सप्लोरर में फ़ोल्डर खोलें:");
+    data.insert("wiki.mode_title", "शीर्षक");
+    data.insert("wiki.mode_fulltext", "पूर्ण पाठ");
+    data.insert(
+        "wiki.index_missing",
+        "इस ZIM के लिए अभी पूर्ण-पाठ अनुक्रमणिका नहीं है। यहाँ से बनाएँ:",
+    );
+    data.insert("wiki.fulltext_index", "पूर्ण-पाठ अनुक्रमणिका");
+    data.insert("wiki.build_index", "अनुक्रमणिका बनाएँ");
+    data.insert("wiki.all_zims", "सभी संग्रह");
+    data.insert("wiki.verify", "सत्यापित करें");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "SRS समीक्षा");
@@ -181,6 +215,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "अनलॉक हो गया");
     data.insert("security.pin_min_length", "PIN कम से कम 6 अक्षर होना चाहिए");
     data.insert("security.enter_current_pin", "वर्तमान PIN दर्ज करें");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "प्रिंट");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "बैकअप");
@@ -208,6 +282,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "संस्करण");
     data.insert("settings.storage", "स्टोरेज");
     data.insert("settings.about", "जानकारी");
+    data.insert("settings.backup", "स्वचालित बैकअप");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "सहेजें");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "ज्ञान ग्राफ");
@@ -62,10 +62,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Tanpa Judul");
     data.insert("notes.edit", "Edit");
     data.insert("notes.delete", "Hapus");
+    data.insert("notes.history", "Riwayat");
+    data.insert("notes.revision", "Revisi");
+    data.insert("notes.current", "Saat ini");
+    data.insert("notes.compare", "Bandingkan");
+    data.insert("notes.restore", "Pulihkan");
+    data.insert(
+        "notes.restore_confirm",
+        "Pulihkan revisi ini? Versi saat ini tetap ada di riwayat.",
+    );
+    data.insert("notes.no_changes", "Tidak ada perubahan");
+    data.insert("notes.rating", "Penilaian");
+    data.insert("notes.mood", "Suasana hati");
     data.insert("notes.delete_confirm", "Yakin ingin menghapus?");
     data.insert("notes.encrypted", "Terenkripsi");
     data.insert("notes.created", "Dibuat");
     data.insert("notes.updated", "Diperbarui");
+    data.insert("notes.related", "Terkait");
     data.insert("notes.find_duplicates", "Cari Duplikat");
     data.insert("notes.select_all", "Pilih Semua");
     data.insert("notes.selected", "dipilih");
@@ -101,6 +114,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "Mencari di catatan dan Wikipedia");
     data.insert("search.tip3", "Kecocokan judul muncul pertama");
+    data.insert("search.tip4", "Filter: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Judul\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Catatan");
+    data.insert("search.scope_encrypted", "Catatan terenkripsi");
 
     // Wiki
     data.insert("wiki.search", "Cari Wiki");
@@ -143,6 +159,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "Silakan buka folder di file explorer:",
     );
+    data.insert("wiki.mode_title", "Judul");
+    data.insert("wiki.mode_fulltext", "Teks lengkap");
+    data.insert(
+        "wiki.index_missing",
+        "Belum ada indeks teks lengkap untuk ZIM ini. Buat dari",
+    );
+    data.insert("wiki.fulltext_index", "Indeks teks lengkap");
+    data.insert("wiki.build_index", "Buat indeks");
+    data.insert("wiki.all_zims", "Semua arsip");
+    data.insert("wiki.verify", "Verifikasi");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "Ulasan SRS");
@@ -193,6 +227,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "Terbuka");
     data.insert("security.pin_min_length", "PIN minimal 6 karakter");
     data.insert("security.enter_current_pin", "Masukkan PIN saat ini");
+    data.insert("security.recovery_create", "Buat lembar pemulihan");
+    data.insert(
+        "security.recovery_forgot",
+        "Lupa PIN? Gunakan kunci pemulihan",
+    );
+    data.insert("security.recovery_key", "Kunci pemulihan");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Atur PIN baru");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "Cetak");
+    data.insert(
+        "security.failed_attempts",
+        "Percobaan PIN salah sejak terakhir dibuka",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Simpan kebijakan");
+    data.insert(
+        "security.idle_lock",
+        "Kunci otomatis setelah sekian menit tidak dipakai (0 = mati)",
+    );
+    data.insert("security.idle_lock_hint", "Brankas juga terkunci saat komputer bangun dari tidur. Setiap browser harus dibuka dengan PIN sendiri.");
+    data.insert("security.idle_lock_save", "Simpan");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "Cadangan");
@@ -223,6 +297,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Versi");
     data.insert("settings.storage", "Penyimpanan");
     data.insert("settings.about", "Tentang");
+    data.insert("settings.backup", "Cadangan otomatis");
+    data.insert(
+        "settings.backup_desc",
+        "Mencadangkan sesuai jadwal. Dilewati jika tidak ada perubahan.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Folder cadangan kedua");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "Simpan");
+    data.insert("settings.backup_now", "Cadangkan sekarang");
+    data.insert("settings.backup_last_run", "Terakhir dijalankan");
+    data.insert("settings.backup_next_run", "Berikutnya");
+    data.insert("settings.backup_skipped", "Tidak ada perubahan, dilewati");
+    data.insert("settings.backup_failed", "Pencadangan gagal");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Belum pernah");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Grafik Pengetahuan");
@@ -62,10 +62,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "無題");
     data.insert("notes.edit", "編集");
     data.insert("notes.delete", "削除");
+    data.insert("notes.history", "履歴");
+    data.insert("notes.revision", "リビジョン");
+    data.insert("notes.current", "現在");
+    data.insert("notes.compare", "比較");
+    data.insert("notes.restore", "復元");
+    data.insert(
+        "notes.restore_confirm",
+        "このリビジョンを復元しますか？現在の版は履歴に残ります。",
+    );
+    data.insert("notes.no_changes", "変更なし");
+    data.insert("notes.rating", "評価");
+    data.insert("notes.mood", "気分");
     data.insert("notes.delete_confirm", "本当に削除しますか？");
     data.insert("notes.encrypted", "暗号化済み");
     data.insert("notes.created", "作成日");
     data.insert("notes.updated", "更新日");
+    data.insert("notes.related", "関連ノート");
     data.insert("notes.find_duplicates", "重複を検索");
     data.insert("notes.select_all", "すべて選択");
     data.insert("notes.selected", "件選択中");
@@ -98,6 +111,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "複数の単語はすべてを含む結果を検索します");
     data.insert("search.tip2", "ノートとWikipediaの両方を検索します");
     data.insert("search.tip3", "タイトルの一致が最初に表示されます");
+    data.insert("search.tip4", "フィルター: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"タイトル\" has:link is:encrypted");
+    data.insert("search.scope_notes", "ノート");
+    data.insert("search.scope_encrypted", "暗号化されたノート");
 
     // Wiki
     data.insert("wiki.search", "Wiki検索");
@@ -140,6 +156,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "エクスプローラーでフォルダを開いてください:",
     );
+    data.insert("wiki.mode_title", "タイトル");
+    data.insert("wiki.mode_fulltext", "本文");
+    data.insert(
+        "wiki.index_missing",
+        "このZIMにはまだ本文インデックスがありません。作成はこちら:",
+    );
+    data.insert("wiki.fulltext_index", "本文インデックス");
+    data.insert("wiki.build_index", "インデックス作成");
+    data.insert("wiki.all_zims", "すべてのアーカイブ");
+    data.insert("wiki.verify", "検証");
+    data.insert("wiki.verify_ok", "正常");
+    data.insert("wiki.verify_checksum", "MD5を確認中");
+    data.insert("wiki.verify_clusters", "クラスタを確認中");
+    data.insert("wiki.verify_entries", "エントリを確認中");
+    data.insert("wiki.verify_md5_mismatch", "MD5不一致");
+    data.insert("wiki.verify_bad_clusters", "破損クラスタ");
+    data.insert("wiki.verify_unreadable", "読めないエントリ");
+    data.insert("wiki.verify_failed", "検証に失敗しました");
 
     // SRS
     data.insert("srs.title", "SRS復習");
@@ -190,6 +224,43 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "ロック解除されました");
     data.insert("security.pin_min_length", "PINは6文字以上必要です");
     data.insert("security.enter_current_pin", "現在のPINを入力");
+    data.insert("security.recovery_create", "復旧シートを作成");
+    data.insert(
+        "security.recovery_forgot",
+        "PINを忘れた場合は復旧キーを使用",
+    );
+    data.insert("security.recovery_key", "復旧キー");
+    data.insert(
+        "security.recovery_new_pin",
+        "復旧キーを入力し、上の欄に新しいPINを入力してください。",
+    );
+    data.insert("security.recovery_submit", "新しいPINを設定");
+    data.insert("security.recovery_sheet_title", "Lazarus 復旧シート");
+    data.insert("security.recovery_sheet_hint", "このキーがあれば誰でもノートを開けます。印刷または書き写し、この端末とは別の場所に保管してください。表示は一度だけで、以前の復旧キーは無効になります。");
+    data.insert("security.recovery_print", "印刷");
+    data.insert("security.failed_attempts", "前回の解除以降のPIN誤入力回数");
+    data.insert(
+        "security.wipe_after",
+        "PINを連続でこの回数間違えたら暗号化ノートを消去 (0 = しない)",
+    );
+    data.insert("security.wipe_warning", "消去すると鍵、暗号化ノート、データフォルダ内のすべてのバックアップが失われ、元に戻せません。暗号化していないノートは残ります。");
+    data.insert("security.wipe_save", "ポリシーを保存");
+    data.insert(
+        "security.idle_lock",
+        "この時間(分)操作がなければ自動ロック (0 = オフ)",
+    );
+    data.insert(
+        "security.idle_lock_hint",
+        "スリープから復帰したときもロックされます。ブラウザごとにPINでロック解除が必要です。",
+    );
+    data.insert("security.idle_lock_save", "保存");
+    data.insert("security.duress_pin", "強要PIN (空欄で解除)");
+    data.insert(
+        "security.duress_destroy",
+        "強要PINを使うと本物の金庫の鍵を破棄",
+    );
+    data.insert("security.duress_hint", "強要PINで解除すると、別の空のおとり金庫が開きます。おとり金庫と本物の金庫の暗号化ノートは互いに見えません。強要PINを設定し直すと、おとり金庫も新しく始まります。");
+    data.insert("security.duress_save", "保存");
 
     // Backup
     data.insert("backup.title", "バックアップ");
@@ -217,6 +288,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "バージョン");
     data.insert("settings.storage", "ストレージ");
     data.insert("settings.about", "このアプリについて");
+    data.insert("settings.backup", "自動バックアップ");
+    data.insert(
+        "settings.backup_desc",
+        "定期的にバックアップします。変更がなければスキップします。",
+    );
+    data.insert("settings.backup_interval", "間隔");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "2つ目のバックアップフォルダ");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "例: USBメモリ。マウントされている必要があります。空欄で無効。",
+    );
+    data.insert("settings.backup_save", "保存");
+    data.insert("settings.backup_now", "今すぐバックアップ");
+    data.insert("settings.backup_last_run", "前回の実行");
+    data.insert("settings.backup_next_run", "次回の実行");
+    data.insert("settings.backup_skipped", "変更なし、スキップ");
+    data.insert("settings.backup_failed", "バックアップ失敗");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "2つ目のフォルダへのコピーに失敗",
+    );
+    data.insert("settings.backup_never", "なし");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "ナレッジグラフ");
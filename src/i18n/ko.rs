@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "제목 없음");
     data.insert("notes.edit", "편집");
     data.insert("notes.delete", "삭제");
+    data.insert("notes.history", "히스토리");
+    data.insert("notes.revision", "리비전");
+    data.insert("notes.current", "현재");
+    data.insert("notes.compare", "비교");
+    data.insert("notes.restore", "복원");
+    data.insert(
+        "notes.restore_confirm",
+        "이 리비전으로 복원할까요? 현재 버전은 히스토리에 남습니다.",
+    );
+    data.insert("notes.no_changes", "변경 없음");
+    data.insert("notes.rating", "별점");
+    data.insert("notes.mood", "기분");
     data.insert("notes.delete_confirm", "정말 삭제하시겠습니까?");
     data.insert("notes.encrypted", "암호화됨");
     data.insert("notes.created", "생성");
     data.insert("notes.updated", "수정");
+    data.insert("notes.related", "관련 노트");
     data.insert("notes.find_duplicates", "중복 찾기");
     data.insert("notes.select_all", "전체 선택");
     data.insert("notes.selected", "개 선택됨");
@@ -98,6 +111,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "노트와 위키백과를 동시에 검색합니다");
     data.insert("search.tip3", "제목에 일치하는 결과가 먼저 표시됩니다");
+    data.insert("search.tip4", "필터: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"제목\" has:link is:encrypted");
+    data.insert("search.scope_notes", "노트");
+    data.insert("search.scope_encrypted", "암호화된 노트");
 
     // 위키
     data.insert("wiki.search", "위키 검색");
@@ -140,6 +156,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "파일 탐색기에서 직접 폴더를 열어주세요:",
     );
+    data.insert("wiki.mode_title", "제목");
+    data.insert("wiki.mode_fulltext", "본문");
+    data.insert(
+        "wiki.index_missing",
+        "이 ZIM에는 아직 본문 인덱스가 없습니다. 여기서 만드세요:",
+    );
+    data.insert("wiki.fulltext_index", "본문 인덱스");
+    data.insert("wiki.build_index", "인덱스 만들기");
+    data.insert("wiki.all_zims", "모든 ZIM");
+    data.insert("wiki.verify", "무결성 검사");
+    data.insert("wiki.verify_ok", "정상");
+    data.insert("wiki.verify_checksum", "MD5 확인 중");
+    data.insert("wiki.verify_clusters", "클러스터 확인 중");
+    data.insert("wiki.verify_entries", "엔트리 확인 중");
+    data.insert("wiki.verify_md5_mismatch", "MD5 불일치");
+    data.insert("wiki.verify_bad_clusters", "손상된 클러스터");
+    data.insert("wiki.verify_unreadable", "읽을 수 없는 엔트리");
+    data.insert("wiki.verify_failed", "무결성 검사 실패");
 
     // SRS
     data.insert("srs.title", "SRS 복습");
@@ -190,6 +224,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "잠금이 해제되었습니다");
     data.insert("security.pin_min_length", "PIN을 6자리 이상 입력하세요");
     data.insert("security.enter_current_pin", "현재 PIN을 입력하세요");
+    data.insert("security.recovery_create", "복구 시트 만들기");
+    data.insert(
+        "security.recovery_forgot",
+        "PIN을 잊으셨나요? 복구 키를 사용하세요",
+    );
+    data.insert("security.recovery_key", "복구 키");
+    data.insert(
+        "security.recovery_new_pin",
+        "복구 키를 입력한 뒤 위 칸에 새 PIN을 입력하세요.",
+    );
+    data.insert("security.recovery_submit", "새 PIN 설정");
+    data.insert("security.recovery_sheet_title", "Lazarus 복구 시트");
+    data.insert("security.recovery_sheet_hint", "이 키가 있으면 누구나 노트를 열 수 있습니다. 인쇄하거나 적어서 이 기기와 떨어진 곳에 보관하세요. 한 번만 표시되며 이전 복구 키는 무효가 됩니다.");
+    data.insert("security.recovery_print", "인쇄");
+    data.insert(
+        "security.failed_attempts",
+        "마지막 잠금 해제 이후 틀린 PIN 시도",
+    );
+    data.insert(
+        "security.wipe_after",
+        "PIN을 연속으로 이만큼 틀리면 암호화 노트 삭제 (0 = 사용 안 함)",
+    );
+    data.insert("security.wipe_warning", "삭제하면 키, 암호화 노트, 데이터 폴더의 모든 백업이 지워지며 되돌릴 수 없습니다. 암호화하지 않은 노트는 남습니다.");
+    data.insert("security.wipe_save", "정책 저장");
+    data.insert(
+        "security.idle_lock",
+        "이 시간(분) 동안 사용하지 않으면 자동 잠금 (0 = 끔)",
+    );
+    data.insert(
+        "security.idle_lock_hint",
+        "컴퓨터가 절전에서 깨어날 때도 잠깁니다. 브라우저마다 따로 PIN으로 잠금을 풀어야 합니다.",
+    );
+    data.insert("security.idle_lock_save", "저장");
+    data.insert("security.duress_pin", "강요 PIN (비우면 해제)");
+    data.insert(
+        "security.duress_destroy",
+        "강요 PIN을 쓰면 진짜 금고의 키를 파기",
+    );
+    data.insert("security.duress_hint", "강요 PIN으로 풀면 따로 된 빈 미끼 금고가 열립니다. 미끼 금고와 진짜 금고의 암호화 노트는 서로 보이지 않습니다. 강요 PIN을 새로 정하면 미끼 금고도 새로 시작합니다.");
+    data.insert("security.duress_save", "저장");
 
     // 백업
     data.insert("backup.title", "백업");
@@ -217,6 +291,29 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "버전");
     data.insert("settings.storage", "저장소");
     data.insert("settings.about", "정보");
+    data.insert("settings.backup", "자동 백업");
+    data.insert(
+        "settings.backup_desc",
+        "정해진 주기로 백업합니다. 바뀐 것이 없으면 건너뜁니다.",
+    );
+    data.insert("settings.backup_interval", "주기");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d 또는 daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "두 번째 백업 폴더");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "예: USB 메모리. 마운트돼 있어야 합니다. 비우면 사용하지 않습니다.",
+    );
+    data.insert("settings.backup_save", "저장");
+    data.insert("settings.backup_now", "지금 백업");
+    data.insert("settings.backup_last_run", "마지막 실행");
+    data.insert("settings.backup_next_run", "다음 실행");
+    data.insert("settings.backup_skipped", "변경 없음, 건너뜀");
+    data.insert("settings.backup_failed", "백업 실패");
+    data.insert("settings.backup_mirror_failed", "두 번째 폴더로 복사 실패");
+    data.insert("settings.backup_never", "없음");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "지식 그래프");
@@ -279,10 +279,20 @@ fn translation_keys() -> &'static [&'static str] {
         "notes.no_title",
         "notes.edit",
         "notes.delete",
+        "notes.history",
+        "notes.revision",
+        "notes.current",
+        "notes.compare",
+        "notes.restore",
+        "notes.restore_confirm",
+        "notes.no_changes",
+        "notes.rating",
+        "notes.mood",
         "notes.delete_confirm",
         "notes.encrypted",
         "notes.created",
         "notes.updated",
+        "notes.related",
         "notes.find_duplicates",
         "notes.select_all",
         "notes.selected",
@@ -311,6 +321,9 @@ fn translation_keys() -> &'static [&'static str] {
         "search.tip1",
         "search.tip2",
         "search.tip3",
+        "search.tip4",
+        "search.scope_notes",
+        "search.scope_encrypted",
         // 위키
         "wiki.search",
         "wiki.recent_articles",
@@ -343,6 +356,21 @@ fn translation_keys() -> &'static [&'static str] {
         "wiki.zim_removed",
         "wiki.remove_failed",
         "wiki.open_folder_msg",
+        "wiki.mode_title",
+        "wiki.mode_fulltext",
+        "wiki.index_missing",
+        "wiki.fulltext_index",
+        "wiki.build_index",
+        "wiki.all_zims",
+        "wiki.verify",
+        "wiki.verify_ok",
+        "wiki.verify_checksum",
+        "wiki.verify_clusters",
+        "wiki.verify_entries",
+        "wiki.verify_md5_mismatch",
+        "wiki.verify_bad_clusters",
+        "wiki.verify_unreadable",
+        "wiki.verify_failed",
         // SRS
         "srs.title",
         "srs.cards",
@@ -388,6 +416,25 @@ fn translation_keys() -> &'static [&'static str] {
         "security.unlocked",
         "security.pin_min_length",
         "security.enter_current_pin",
+        "security.recovery_create",
+        "security.recovery_forgot",
+        "security.recovery_key",
+        "security.recovery_new_pin",
+        "security.recovery_submit",
+        "security.recovery_sheet_title",
+        "security.recovery_sheet_hint",
+        "security.recovery_print",
+        "security.failed_attempts",
+        "security.wipe_after",
+        "security.wipe_warning",
+        "security.wipe_save",
+        "security.idle_lock",
+        "security.idle_lock_hint",
+        "security.idle_lock_save",
+        "security.duress_pin",
+        "security.duress_destroy",
+        "security.duress_hint",
+        "security.duress_save",
         // 백업
         "backup.title",
         "backup.info",
@@ -411,6 +458,20 @@ fn translation_keys() -> &'static [&'static str] {
         "settings.version",
         "settings.storage",
         "settings.about",
+        "settings.backup",
+        "settings.backup_desc",
+        "settings.backup_interval",
+        "settings.backup_interval_hint",
+        "settings.backup_mirror",
+        "settings.backup_mirror_hint",
+        "settings.backup_save",
+        "settings.backup_now",
+        "settings.backup_last_run",
+        "settings.backup_next_run",
+        "settings.backup_skipped",
+        "settings.backup_failed",
+        "settings.backup_mirror_failed",
+        "settings.backup_never",
         // USB
         "usb.title",
         "usb.scan",
@@ -66,10 +66,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "ခေါင်းစဉ်မဲ့");
     data.insert("notes.edit", "တည်းဖြတ်");
     data.insert("notes.delete", "ဖျက်");
+    data.insert("notes.history", "မှတ်တမ်း");
+    data.insert("notes.revision", "ပြင်ဆင်မှု");
+    data.insert("notes.current", "လက်ရှိ");
+    data.insert("notes.compare", "နှိုင်းယှဉ်");
+    data.insert("notes.restore", "ပြန်ယူ");
+    data.insert(
+        "notes.restore_confirm",
+        "ဤပြင်ဆင်မှုကို ပြန်ယူမလား? လက်ရှိဗားရှင်းသည် မှတ်တမ်းတွင် ကျန်ပါမည်။",
+    );
+    data.insert("notes.no_changes", "ပြောင်းလဲမှုမရှိ");
+    data.insert("notes.rating", "အဆင့်သတ်မှတ်ချက်");
+    data.insert("notes.mood", "စိတ်ခံစားချက်");
     data.insert("notes.delete_confirm", "ဖျက်မည် သေချာပါသလား?");
     data.insert("notes.encrypted", "စာဝှက်ထား");
     data.insert("notes.created", "ဖန်တီးသည်");
     data.insert("notes.updated", "ပြင်ဆင်သည်");
+    data.insert("notes.related", "ဆက်စပ်မှတ်စုများ");
     data.insert("notes.find_duplicates", "ထပ်တူ ရှာဖွေ");
     data.insert("notes.select_all", "အားလုံး ရွေး");
     data.insert("notes.selected", "ရွေးထား");
@@ -102,6 +115,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "စကားလုံးများစွာ အားလုံးပါဝင်သော ရလဒ်များ ရှာပေးသည်");
     data.insert("search.tip2", "မှတ်စုနှင့် ဝီကီပီးဒီးယား နှစ်ခုလုံး ရှာသည်");
     data.insert("search.tip3", "ခေါင်းစဉ် တူညီမှု ဦးစားပေး");
+    data.insert("search.tip4", "စစ်ထုတ်မှု: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"ခေါင်းစဉ်\" has:link is:encrypted");
+    data.insert("search.scope_notes", "မှတ်စုများ");
+    data.insert("search.scope_encrypted", "စာဝှက်ထားသော မှတ်စုများ");
 
     // Wiki
     data.insert("wiki.search", "ဝီကီ ရှာဖွေ");
@@ -135,6 +151,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM ဖယ်ရှားပြီး: ");
     data.insert("wiki.remove_failed", "ဖယ်ရှား မအောင်မြင်");
     data.insert("wiki.open_folder_msg", "ဖိုဒါကို ဖိုင်မန်နေဂျာတွင် ဖွင့်ပါ:");
+    data.insert("wiki.mode_title", "ခေါင်းစဉ်များ");
+    data.insert("wiki.mode_fulltext", "စာသားအပြည့်");
+    data.insert(
+        "wiki.index_missing",
+        "ဤ ZIM အတွက် စာသားအပြည့် အညွှန်း မရှိသေးပါ။ ဤနေရာမှ ပြုလုပ်ပါ -",
+    );
+    data.insert("wiki.fulltext_index", "စာသားအပြည့် အညွှန်း");
+    data.insert("wiki.build_index", "အညွှန်း ပြုလုပ်ရန်");
+    data.insert("wiki.all_zims", "မော်ကွန်းအားလုံး");
+    data.insert("wiki.verify", "စစ်ဆေးရန်");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "SRS ပြန်လည်သုံးသပ်");
@@ -182,6 +216,46 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "လော့ခ်ဖွင့်ပြီး");
     data.insert("security.pin_min_length", "PIN အနည်းဆုံး ၆ စာလုံး လိုအပ်");
     data.insert("security.enter_current_pin", "လက်ရှိ PIN ထည့်ပါ");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "Print");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "အရန်သိမ်း");
@@ -209,6 +283,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "ဗားရှင်း");
     data.insert("settings.storage", "သိုလှောင်မှု");
     data.insert("settings.about", "အကြောင်း");
+    data.insert("settings.backup", "အလိုအလျောက် အရန်ကူးခြင်း");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "Save");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // === USB ===
     data.insert("usb.title", "USB စင့်ခ်");
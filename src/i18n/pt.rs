@@ -62,10 +62,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Sem Título");
     data.insert("notes.edit", "Editar");
     data.insert("notes.delete", "Excluir");
+    data.insert("notes.history", "Histórico");
+    data.insert("notes.revision", "Revisão");
+    data.insert("notes.current", "Atual");
+    data.insert("notes.compare", "Comparar");
+    data.insert("notes.restore", "Restaurar");
+    data.insert(
+        "notes.restore_confirm",
+        "Restaurar esta revisão? A versão atual permanece no histórico.",
+    );
+    data.insert("notes.no_changes", "Sem alterações");
+    data.insert("notes.rating", "Avaliação");
+    data.insert("notes.mood", "Humor");
     data.insert("notes.delete_confirm", "Tem certeza que deseja excluir?");
     data.insert("notes.encrypted", "Criptografado");
     data.insert("notes.created", "Criado");
     data.insert("notes.updated", "Atualizado");
+    data.insert("notes.related", "Relacionadas");
     data.insert("notes.find_duplicates", "Encontrar Duplicados");
     data.insert("notes.select_all", "Selecionar Todos");
     data.insert("notes.selected", "selecionados");
@@ -104,6 +117,9 @@ pub fn translations() -> Translations {
         "search.tip3",
         "Correspondências de título aparecem primeiro",
     );
+    data.insert("search.tip4", "Filtros: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Título\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Notas");
+    data.insert("search.scope_encrypted", "Notas criptografadas");
 
     // Wiki
     data.insert("wiki.search", "Pesquisar Wiki");
@@ -146,6 +162,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "Abra a pasta no explorador de arquivos:",
     );
+    data.insert("wiki.mode_title", "Títulos");
+    data.insert("wiki.mode_fulltext", "Texto completo");
+    data.insert(
+        "wiki.index_missing",
+        "Este ZIM ainda não tem índice de texto completo. Crie em",
+    );
+    data.insert("wiki.fulltext_index", "Índice de texto completo");
+    data.insert("wiki.build_index", "Criar índice");
+    data.insert("wiki.all_zims", "Todos os arquivos");
+    data.insert("wiki.verify", "Verificar");
+    data.insert("wiki.verify_ok", "Íntegro");
+    data.insert("wiki.verify_checksum", "Verificando MD5");
+    data.insert("wiki.verify_clusters", "Verificando clusters");
+    data.insert("wiki.verify_entries", "Verificando entradas");
+    data.insert("wiki.verify_md5_mismatch", "MD5 divergente");
+    data.insert("wiki.verify_bad_clusters", "clusters danificados");
+    data.insert("wiki.verify_unreadable", "entradas ilegíveis");
+    data.insert("wiki.verify_failed", "Falha na verificação");
 
     // SRS
     data.insert("srs.title", "Revisão SRS");
@@ -199,6 +233,46 @@ pub fn translations() -> Translations {
         "PIN deve ter pelo menos 6 caracteres",
     );
     data.insert("security.enter_current_pin", "Digite o PIN atual");
+    data.insert("security.recovery_create", "Criar folha de recuperação");
+    data.insert(
+        "security.recovery_forgot",
+        "Esqueceu o PIN? Use sua chave de recuperação",
+    );
+    data.insert("security.recovery_key", "Chave de recuperação");
+    data.insert(
+        "security.recovery_new_pin",
+        "Digite a chave de recuperação e depois um novo PIN acima.",
+    );
+    data.insert("security.recovery_submit", "Definir novo PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Qualquer pessoa com esta chave pode abrir suas notas. Imprima ou anote e guarde longe deste dispositivo. Ela aparece só uma vez e substitui qualquer chave anterior.");
+    data.insert("security.recovery_print", "Imprimir");
+    data.insert(
+        "security.failed_attempts",
+        "Tentativas de PIN erradas desde o último desbloqueio",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Apagar notas criptografadas após esta quantidade de PINs errados seguidos (0 = nunca)",
+    );
+    data.insert("security.wipe_warning", "Apagar destrói a chave, as notas criptografadas e todos os backups da pasta de dados. Não pode ser desfeito. Notas não criptografadas são mantidas.");
+    data.insert("security.wipe_save", "Salvar política");
+    data.insert(
+        "security.idle_lock",
+        "Bloquear após estes minutos sem uso (0 = desligado)",
+    );
+    data.insert("security.idle_lock_hint", "O cofre também bloqueia quando o computador sai da suspensão. Cada navegador precisa ser desbloqueado com o PIN.");
+    data.insert("security.idle_lock_save", "Salvar");
+    data.insert(
+        "security.duress_pin",
+        "PIN de coação (vazio para desativar)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destruir a chave do cofre real ao usar o PIN de coação",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Salvar");
 
     // Backup
     data.insert("backup.title", "Backup");
@@ -226,6 +300,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Versão");
     data.insert("settings.storage", "Armazenamento");
     data.insert("settings.about", "Sobre");
+    data.insert("settings.backup", "Backup automático");
+    data.insert(
+        "settings.backup_desc",
+        "Faz backups em intervalos. É ignorado quando nada mudou.",
+    );
+    data.insert("settings.backup_interval", "Intervalo");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Segunda pasta de backup");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "Por exemplo, um pendrive. Deve estar montado. Deixe vazio para desativar.",
+    );
+    data.insert("settings.backup_save", "Salvar");
+    data.insert("settings.backup_now", "Fazer backup agora");
+    data.insert("settings.backup_last_run", "Última execução");
+    data.insert("settings.backup_next_run", "Próxima execução");
+    data.insert("settings.backup_skipped", "Sem alterações, ignorado");
+    data.insert("settings.backup_failed", "Falha no backup");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Falha ao copiar para a segunda pasta",
+    );
+    data.insert("settings.backup_never", "Nunca");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Grafo de Conhecimento");
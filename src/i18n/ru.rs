@@ -62,10 +62,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Без Названия");
     data.insert("notes.edit", "Изменить");
     data.insert("notes.delete", "Удалить");
+    data.insert("notes.history", "История");
+    data.insert("notes.revision", "Версия");
+    data.insert("notes.current", "Текущая");
+    data.insert("notes.compare", "Сравнить");
+    data.insert("notes.restore", "Восстановить");
+    data.insert(
+        "notes.restore_confirm",
+        "Восстановить эту версию? Текущая останется в истории.",
+    );
+    data.insert("notes.no_changes", "Без изменений");
+    data.insert("notes.rating", "Оценка");
+    data.insert("notes.mood", "Настроение");
     data.insert("notes.delete_confirm", "Вы уверены что хотите удалить?");
     data.insert("notes.encrypted", "Зашифровано");
     data.insert("notes.created", "Создано");
     data.insert("notes.updated", "Обновлено");
+    data.insert("notes.related", "Похожие");
     data.insert("notes.find_duplicates", "Найти Дубликаты");
     data.insert("notes.select_all", "Выбрать Все");
     data.insert("notes.selected", "выбрано");
@@ -101,6 +114,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "Поиск в заметках и Википедии");
     data.insert("search.tip3", "Совпадения в заголовке показываются первыми");
+    data.insert("search.tip4", "Фильтры: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Заголовок\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Заметки");
+    data.insert("search.scope_encrypted", "Зашифрованные заметки");
 
     // Wiki
     data.insert("wiki.search", "Поиск в Вики");
@@ -140,6 +156,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM удалён: ");
     data.insert("wiki.remove_failed", "Ошибка удаления");
     data.insert("wiki.open_folder_msg", "Откройте папку в проводнике:");
+    data.insert("wiki.mode_title", "Заголовки");
+    data.insert("wiki.mode_fulltext", "Полный текст");
+    data.insert(
+        "wiki.index_missing",
+        "Для этого ZIM ещё нет полнотекстового индекса. Создайте его в разделе",
+    );
+    data.insert("wiki.fulltext_index", "Полнотекстовый индекс");
+    data.insert("wiki.build_index", "Создать индекс");
+    data.insert("wiki.all_zims", "Все архивы");
+    data.insert("wiki.verify", "Проверить");
+    data.insert("wiki.verify_ok", "В порядке");
+    data.insert("wiki.verify_checksum", "Проверка MD5");
+    data.insert("wiki.verify_clusters", "Проверка кластеров");
+    data.insert("wiki.verify_entries", "Проверка записей");
+    data.insert("wiki.verify_md5_mismatch", "MD5 не совпадает");
+    data.insert("wiki.verify_bad_clusters", "повреждённых кластеров");
+    data.insert("wiki.verify_unreadable", "нечитаемых записей");
+    data.insert("wiki.verify_failed", "Ошибка проверки");
 
     // SRS
     data.insert("srs.title", "Повторение SRS");
@@ -193,6 +227,46 @@ pub fn translations() -> Translations {
         "PIN должен быть минимум 6 символов",
     );
     data.insert("security.enter_current_pin", "Введите текущий PIN");
+    data.insert("security.recovery_create", "Создать лист восстановления");
+    data.insert(
+        "security.recovery_forgot",
+        "Забыли PIN? Используйте ключ восстановления",
+    );
+    data.insert("security.recovery_key", "Ключ восстановления");
+    data.insert(
+        "security.recovery_new_pin",
+        "Введите ключ восстановления, затем новый PIN в полях выше.",
+    );
+    data.insert("security.recovery_submit", "Установить новый PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Любой, у кого есть этот ключ, может открыть ваши заметки. Распечатайте или запишите его и храните отдельно от устройства. Он показывается один раз и заменяет прежний ключ.");
+    data.insert("security.recovery_print", "Печать");
+    data.insert(
+        "security.failed_attempts",
+        "Неверных попыток PIN с последней разблокировки",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Стереть зашифрованные заметки после стольких неверных PIN подряд (0 = никогда)",
+    );
+    data.insert("security.wipe_warning", "Стирание уничтожает ключ, зашифрованные заметки и все копии в папке данных. Отменить нельзя. Незашифрованные заметки сохраняются.");
+    data.insert("security.wipe_save", "Сохранить правило");
+    data.insert(
+        "security.idle_lock",
+        "Автоблокировка после стольких минут простоя (0 = выкл.)",
+    );
+    data.insert("security.idle_lock_hint", "Хранилище также блокируется при выходе из сна. Каждый браузер разблокируется PIN-кодом отдельно.");
+    data.insert("security.idle_lock_save", "Сохранить");
+    data.insert(
+        "security.duress_pin",
+        "PIN под принуждением (пусто — отключить)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Уничтожить ключ настоящего хранилища при вводе этого PIN",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Сохранить");
 
     // Backup
     data.insert("backup.title", "Резервная Копия");
@@ -220,6 +294,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Версия");
     data.insert("settings.storage", "Хранилище");
     data.insert("settings.about", "О программе");
+    data.insert("settings.backup", "Автоматическое резервное копирование");
+    data.insert(
+        "settings.backup_desc",
+        "Резервные копии по расписанию. Пропускается, если ничего не изменилось.",
+    );
+    data.insert("settings.backup_interval", "Интервал");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Вторая папка для копий");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "Например, USB-накопитель. Он должен быть подключён. Оставьте пустым, чтобы отключить.",
+    );
+    data.insert("settings.backup_save", "Сохранить");
+    data.insert("settings.backup_now", "Создать копию сейчас");
+    data.insert("settings.backup_last_run", "Последний запуск");
+    data.insert("settings.backup_next_run", "Следующий запуск");
+    data.insert("settings.backup_skipped", "Нет изменений, пропущено");
+    data.insert("settings.backup_failed", "Ошибка резервного копирования");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Не удалось скопировать во вторую папку",
+    );
+    data.insert("settings.backup_never", "Никогда");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Граф знаний");
@@ -62,10 +62,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Bila Kichwa");
     data.insert("notes.edit", "Hariri");
     data.insert("notes.delete", "Futa");
+    data.insert("notes.history", "Historia");
+    data.insert("notes.revision", "Toleo");
+    data.insert("notes.current", "Sasa");
+    data.insert("notes.compare", "Linganisha");
+    data.insert("notes.restore", "Rejesha");
+    data.insert(
+        "notes.restore_confirm",
+        "Rejesha toleo hili? Toleo la sasa litabaki kwenye historia.",
+    );
+    data.insert("notes.no_changes", "Hakuna mabadiliko");
+    data.insert("notes.rating", "Ukadiriaji");
+    data.insert("notes.mood", "Hisia");
     data.insert("notes.delete_confirm", "Una uhakika unataka kufuta?");
     data.insert("notes.encrypted", "Imesimbwa");
     data.insert("notes.created", "Imeundwa");
     data.insert("notes.updated", "Imesasishwa");
+    data.insert("notes.related", "Zinazohusiana");
     data.insert("notes.find_duplicates", "Tafuta Nakala");
     data.insert("notes.select_all", "Chagua Zote");
     data.insert("notes.selected", "zimechaguliwa");
@@ -101,6 +114,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "Inatafuta madokezo na Wikipedia");
     data.insert("search.tip3", "Mechi za kichwa zinaonekana kwanza");
+    data.insert("search.tip4", "Vichujio: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Kichwa\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Maelezo");
+    data.insert("search.scope_encrypted", "Maelezo yaliyosimbwa");
 
     // Wiki
     data.insert("wiki.search", "Tafuta Wiki");
@@ -140,6 +156,24 @@ pub fn translations() -> Translations {
         "wiki.open_folder_msg",
         "Tafadhali fungua folda katika kivinjari cha faili:",
     );
+    data.insert("wiki.mode_title", "Vichwa");
+    data.insert("wiki.mode_fulltext", "Maandishi kamili");
+    data.insert(
+        "wiki.index_missing",
+        "Bado hakuna faharasa ya maandishi kamili kwa ZIM hii. Itengeneze kutoka",
+    );
+    data.insert("wiki.fulltext_index", "Faharasa ya maandishi");
+    data.insert("wiki.build_index", "Tengeneza faharasa");
+    data.insert("wiki.all_zims", "Hifadhi zote");
+    data.insert("wiki.verify", "Thibitisha");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "Mapitio ya SRS");
@@ -193,6 +227,46 @@ pub fn translations() -> Translations {
         "PIN lazima iwe na herufi 6 au zaidi",
     );
     data.insert("security.enter_current_pin", "Ingiza PIN ya sasa");
+    data.insert("security.recovery_create", "Create recovery sheet");
+    data.insert(
+        "security.recovery_forgot",
+        "Forgot PIN? Use your recovery key",
+    );
+    data.insert("security.recovery_key", "Recovery key");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Set new PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "Chapisha");
+    data.insert(
+        "security.failed_attempts",
+        "Wrong PIN attempts since the last unlock",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Save policy");
+    data.insert(
+        "security.idle_lock",
+        "Auto-lock after this many idle minutes (0 = off)",
+    );
+    data.insert("security.idle_lock_hint", "The vault also locks when the computer wakes from sleep. Each browser has to unlock with the PIN on its own.");
+    data.insert("security.idle_lock_save", "Save");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "Hifadhi Rudufu");
@@ -223,6 +297,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Toleo");
     data.insert("settings.storage", "Hifadhi");
     data.insert("settings.about", "Kuhusu");
+    data.insert("settings.backup", "Hifadhi nakala kiotomatiki");
+    data.insert(
+        "settings.backup_desc",
+        "Backs up on a schedule. Runs are skipped when nothing has changed.",
+    );
+    data.insert("settings.backup_interval", "Interval");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "Second backup folder");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "Hifadhi");
+    data.insert("settings.backup_now", "Back up now");
+    data.insert("settings.backup_last_run", "Last run");
+    data.insert("settings.backup_next_run", "Next run");
+    data.insert("settings.backup_skipped", "No changes, skipped");
+    data.insert("settings.backup_failed", "Backup failed");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Never");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Grafu ya Maarifa");
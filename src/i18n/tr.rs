@@ -62,6 +62,18 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "Başlıksız");
     data.insert("notes.edit", "Düzenle");
     data.insert("notes.delete", "Sil");
+    data.insert("notes.history", "Geçmiş");
+    data.insert("notes.revision", "Sürüm");
+    data.insert("notes.current", "Güncel");
+    data.insert("notes.compare", "Karşılaştır");
+    data.insert("notes.restore", "Geri yükle");
+    data.insert(
+        "notes.restore_confirm",
+        "Bu sürüm geri yüklensin mi? Güncel sürüm geçmişte kalır.",
+    );
+    data.insert("notes.no_changes", "Değişiklik yok");
+    data.insert("notes.rating", "Puan");
+    data.insert("notes.mood", "Ruh hâli");
     data.insert(
         "notes.delete_confirm",
         "Silmek istediğinizden emin misiniz?",
@@ -69,6 +81,7 @@ pub fn translations() -> Translations {
     data.insert("notes.encrypted", "Şifreli");
     data.insert("notes.created", "Oluşturuldu");
     data.insert("notes.updated", "Güncellendi");
+    data.insert("notes.related", "İlgili");
     data.insert("notes.find_duplicates", "Kopyaları Bul");
     data.insert("notes.select_all", "Tümünü Seç");
     data.insert("notes.selected", "seçildi");
@@ -104,6 +117,9 @@ pub fn translations() -> Translations {
     );
     data.insert("search.tip2", "Hem notlarda hem Vikipedi'de arar");
     data.insert("search.tip3", "Başlık eşleşmeleri önce gösterilir");
+    data.insert("search.tip4", "Filtreler: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"Başlık\" has:link is:encrypted");
+    data.insert("search.scope_notes", "Notlar");
+    data.insert("search.scope_encrypted", "Şifreli notlar");
 
     // Wiki
     data.insert("wiki.search", "Wiki Ara");
@@ -143,6 +159,24 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM kaldırıldı: ");
     data.insert("wiki.remove_failed", "Kaldırma başarısız");
     data.insert("wiki.open_folder_msg", "Dosya gezgininde klasörü açın:");
+    data.insert("wiki.mode_title", "Başlıklar");
+    data.insert("wiki.mode_fulltext", "Tam metin");
+    data.insert(
+        "wiki.index_missing",
+        "Bu ZIM için henüz tam metin dizini yok. Buradan oluşturun:",
+    );
+    data.insert("wiki.fulltext_index", "Tam metin dizini");
+    data.insert("wiki.build_index", "Dizin oluştur");
+    data.insert("wiki.all_zims", "Tüm arşivler");
+    data.insert("wiki.verify", "Doğrula");
+    data.insert("wiki.verify_ok", "OK");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "SRS Tekrar");
@@ -196,6 +230,49 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "Kilit açıldı");
     data.insert("security.pin_min_length", "PIN en az 6 karakter olmalı");
     data.insert("security.enter_current_pin", "Mevcut PIN'i girin");
+    data.insert("security.recovery_create", "Kurtarma sayfası oluştur");
+    data.insert(
+        "security.recovery_forgot",
+        "PIN'i mi unuttunuz? Kurtarma anahtarınızı kullanın",
+    );
+    data.insert("security.recovery_key", "Kurtarma anahtarı");
+    data.insert(
+        "security.recovery_new_pin",
+        "Enter the recovery key, then type a new PIN in the boxes above.",
+    );
+    data.insert("security.recovery_submit", "Yeni PIN ayarla");
+    data.insert("security.recovery_sheet_title", "Lazarus recovery sheet");
+    data.insert("security.recovery_sheet_hint", "Anyone with this key can open your notes. Print it or write it down and keep it away from this device. It is shown only once and replaces any earlier recovery key.");
+    data.insert("security.recovery_print", "Yazdır");
+    data.insert(
+        "security.failed_attempts",
+        "Son kilit açmadan beri hatalı PIN denemeleri",
+    );
+    data.insert(
+        "security.wipe_after",
+        "Erase encrypted notes after this many wrong PINs in a row (0 = never)",
+    );
+    data.insert("security.wipe_warning", "Erasing destroys the key, the encrypted notes and all backups in the data folder. It cannot be undone. Unencrypted notes are kept.");
+    data.insert("security.wipe_save", "Politikayı kaydet");
+    data.insert(
+        "security.idle_lock",
+        "Bu kadar dakika boşta kalınca kilitle (0 = kapalı)",
+    );
+    data.insert(
+        "security.idle_lock_hint",
+        "Bilgisayar uykudan uyandığında da kilitlenir. Her tarayıcı PIN ile ayrıca açılmalıdır.",
+    );
+    data.insert("security.idle_lock_save", "Kaydet");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "Yedekleme");
@@ -223,6 +300,32 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "Sürüm");
     data.insert("settings.storage", "Depolama");
     data.insert("settings.about", "Hakkında");
+    data.insert("settings.backup", "Otomatik yedekleme");
+    data.insert(
+        "settings.backup_desc",
+        "Belirli aralıklarla yedekler. Değişiklik yoksa atlanır.",
+    );
+    data.insert("settings.backup_interval", "Aralık");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "İkinci yedek klasörü");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "For example a USB stick. It must be mounted. Leave empty to disable.",
+    );
+    data.insert("settings.backup_save", "Kaydet");
+    data.insert("settings.backup_now", "Şimdi yedekle");
+    data.insert("settings.backup_last_run", "Son çalışma");
+    data.insert("settings.backup_next_run", "Sonraki çalışma");
+    data.insert("settings.backup_skipped", "Değişiklik yok, atlandı");
+    data.insert("settings.backup_failed", "Yedekleme başarısız");
+    data.insert(
+        "settings.backup_mirror_failed",
+        "Copy to second folder failed",
+    );
+    data.insert("settings.backup_never", "Hiç");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "Bilgi Grafiği");
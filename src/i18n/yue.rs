@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "無標題");
     data.insert("notes.edit", "編輯");
     data.insert("notes.delete", "刪除");
+    data.insert("notes.history", "歷史");
+    data.insert("notes.revision", "版本");
+    data.insert("notes.current", "而家");
+    data.insert("notes.compare", "比較");
+    data.insert("notes.restore", "還原");
+    data.insert(
+        "notes.restore_confirm",
+        "還原呢個版本？而家嘅版本會留喺歷史入面。",
+    );
+    data.insert("notes.no_changes", "冇改動");
+    data.insert("notes.rating", "評分");
+    data.insert("notes.mood", "心情");
     data.insert("notes.delete_confirm", "確定要刪除？");
     data.insert("notes.encrypted", "已加密");
     data.insert("notes.created", "建立於");
     data.insert("notes.updated", "更新於");
+    data.insert("notes.related", "相關筆記");
     data.insert("notes.find_duplicates", "搵重複");
     data.insert("notes.select_all", "揀晒");
     data.insert("notes.selected", "已揀");
@@ -92,6 +105,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "多個詞搵包含所有詞嘅結果");
     data.insert("search.tip2", "同時搵筆記同維基百科");
     data.insert("search.tip3", "標題符合優先顯示");
+    data.insert("search.tip4", "篩選: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"標題\" has:link is:encrypted");
+    data.insert("search.scope_notes", "筆記");
+    data.insert("search.scope_encrypted", "加密筆記");
 
     // Wiki
     data.insert("wiki.search", "維基搵嘢");
@@ -128,6 +144,21 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM移除咗: ");
     data.insert("wiki.remove_failed", "移除失敗");
     data.insert("wiki.open_folder_msg", "請喺檔案總管開資料夾:");
+    data.insert("wiki.mode_title", "標題");
+    data.insert("wiki.mode_fulltext", "全文");
+    data.insert("wiki.index_missing", "呢個 ZIM 仲未有全文索引。喺度建立：");
+    data.insert("wiki.fulltext_index", "全文索引");
+    data.insert("wiki.build_index", "建立索引");
+    data.insert("wiki.all_zims", "所有檔案");
+    data.insert("wiki.verify", "校驗");
+    data.insert("wiki.verify_ok", "正常");
+    data.insert("wiki.verify_checksum", "Checking MD5");
+    data.insert("wiki.verify_clusters", "Checking clusters");
+    data.insert("wiki.verify_entries", "Checking entries");
+    data.insert("wiki.verify_md5_mismatch", "MD5 mismatch");
+    data.insert("wiki.verify_bad_clusters", "bad clusters");
+    data.insert("wiki.verify_unreadable", "unreadable entries");
+    data.insert("wiki.verify_failed", "Verify failed");
 
     // SRS
     data.insert("srs.title", "SRS溫習");
@@ -175,6 +206,43 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "解鎖咗");
     data.insert("security.pin_min_length", "PIN至少要6個字");
     data.insert("security.enter_current_pin", "輸入而家嘅PIN");
+    data.insert("security.recovery_create", "建立復原單");
+    data.insert("security.recovery_forgot", "唔記得 PIN？用復原鎖匙");
+    data.insert("security.recovery_key", "復原鎖匙");
+    data.insert(
+        "security.recovery_new_pin",
+        "輸入復原鎖匙，再喺上面輸入新 PIN。",
+    );
+    data.insert("security.recovery_submit", "設定新 PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus 復原單");
+    data.insert("security.recovery_sheet_hint", "任何人有呢條鎖匙都可以打開你嘅筆記。請列印或者抄低，同呢部裝置分開收好。只會顯示一次，會取代之前嘅復原鎖匙。");
+    data.insert("security.recovery_print", "列印");
+    data.insert("security.failed_attempts", "上次解鎖之後 PIN 錯誤次數");
+    data.insert(
+        "security.wipe_after",
+        "連續錯 PIN 咁多次就清除加密筆記（0 = 唔會）",
+    );
+    data.insert(
+        "security.wipe_warning",
+        "清除會銷毀鎖匙、加密筆記同資料夾入面所有備份，冇得還原。未加密嘅筆記會保留。",
+    );
+    data.insert("security.wipe_save", "儲存設定");
+    data.insert("security.idle_lock", "閒置幾多分鐘之後自動鎖定 (0 = 關閉)");
+    data.insert(
+        "security.idle_lock_hint",
+        "部電腦由休眠醒返都會鎖定。每個瀏覽器都要各自用 PIN 解鎖。",
+    );
+    data.insert("security.idle_lock_save", "儲存");
+    data.insert(
+        "security.duress_pin",
+        "Duress PIN (leave empty to turn off)",
+    );
+    data.insert(
+        "security.duress_destroy",
+        "Destroy the real vault key when the duress PIN is used",
+    );
+    data.insert("security.duress_hint", "Unlocking with the duress PIN opens a separate, empty decoy vault. Its encrypted notes and yours never show up in each other. Setting a new duress PIN starts a fresh decoy.");
+    data.insert("security.duress_save", "Save");
 
     // Backup
     data.insert("backup.title", "備份");
@@ -202,6 +270,26 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "版本");
     data.insert("settings.storage", "儲存空間");
     data.insert("settings.about", "關於");
+    data.insert("settings.backup", "自動備份");
+    data.insert("settings.backup_desc", "按時間表備份。冇改動就跳過。");
+    data.insert("settings.backup_interval", "間隔");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "第二備份資料夾");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "例如 USB 手指。要已經掛載。留空就唔用。",
+    );
+    data.insert("settings.backup_save", "儲存");
+    data.insert("settings.backup_now", "即刻備份");
+    data.insert("settings.backup_last_run", "上次執行");
+    data.insert("settings.backup_next_run", "下次執行");
+    data.insert("settings.backup_skipped", "冇改動，已跳過");
+    data.insert("settings.backup_failed", "備份失敗");
+    data.insert("settings.backup_mirror_failed", "複製去第二資料夾失敗");
+    data.insert("settings.backup_never", "未有");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "知識圖譜");
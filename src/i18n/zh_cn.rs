@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "无标题");
     data.insert("notes.edit", "编辑");
     data.insert("notes.delete", "删除");
+    data.insert("notes.history", "历史");
+    data.insert("notes.revision", "版本");
+    data.insert("notes.current", "当前");
+    data.insert("notes.compare", "比较");
+    data.insert("notes.restore", "恢复");
+    data.insert(
+        "notes.restore_confirm",
+        "恢复此版本？当前版本会保留在历史中。",
+    );
+    data.insert("notes.no_changes", "没有变化");
+    data.insert("notes.rating", "评分");
+    data.insert("notes.mood", "心情");
     data.insert("notes.delete_confirm", "确定要删除吗？");
     data.insert("notes.encrypted", "已加密");
     data.insert("notes.created", "创建于");
     data.insert("notes.updated", "更新于");
+    data.insert("notes.related", "相关笔记");
     data.insert("notes.find_duplicates", "查找重复");
     data.insert("notes.select_all", "全选");
     data.insert("notes.selected", "已选择");
@@ -92,6 +105,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "多个词搜索包含所有词的结果");
     data.insert("search.tip2", "同时搜索笔记和维基百科");
     data.insert("search.tip3", "标题匹配优先显示");
+    data.insert("search.tip4", "筛选: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"标题\" has:link is:encrypted");
+    data.insert("search.scope_notes", "笔记");
+    data.insert("search.scope_encrypted", "加密笔记");
 
     // Wiki
     data.insert("wiki.search", "维基搜索");
@@ -125,6 +141,21 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM已移除: ");
     data.insert("wiki.remove_failed", "移除失败");
     data.insert("wiki.open_folder_msg", "请在文件管理器中打开文件夹:");
+    data.insert("wiki.mode_title", "标题");
+    data.insert("wiki.mode_fulltext", "全文");
+    data.insert("wiki.index_missing", "此 ZIM 尚无全文索引。请在此创建：");
+    data.insert("wiki.fulltext_index", "全文索引");
+    data.insert("wiki.build_index", "建立索引");
+    data.insert("wiki.all_zims", "所有档案");
+    data.insert("wiki.verify", "校验");
+    data.insert("wiki.verify_ok", "正常");
+    data.insert("wiki.verify_checksum", "正在校验 MD5");
+    data.insert("wiki.verify_clusters", "正在检查簇");
+    data.insert("wiki.verify_entries", "正在检查条目");
+    data.insert("wiki.verify_md5_mismatch", "MD5 不匹配");
+    data.insert("wiki.verify_bad_clusters", "损坏的簇");
+    data.insert("wiki.verify_unreadable", "无法读取的条目");
+    data.insert("wiki.verify_failed", "校验失败");
 
     // SRS
     data.insert("srs.title", "SRS复习");
@@ -172,6 +203,40 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "已解锁");
     data.insert("security.pin_min_length", "PIN至少需要6个字符");
     data.insert("security.enter_current_pin", "输入当前PIN");
+    data.insert("security.recovery_create", "创建恢复单");
+    data.insert("security.recovery_forgot", "忘记 PIN？使用恢复密钥");
+    data.insert("security.recovery_key", "恢复密钥");
+    data.insert(
+        "security.recovery_new_pin",
+        "输入恢复密钥，然后在上方输入新 PIN。",
+    );
+    data.insert("security.recovery_submit", "设置新 PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus 恢复单");
+    data.insert("security.recovery_sheet_hint", "任何拥有此密钥的人都能打开你的笔记。请打印或抄写，并与本设备分开保存。它只显示一次，并会替换之前的恢复密钥。");
+    data.insert("security.recovery_print", "打印");
+    data.insert("security.failed_attempts", "上次解锁后 PIN 错误次数");
+    data.insert(
+        "security.wipe_after",
+        "连续输错 PIN 达到此次数后清除加密笔记（0 = 从不）",
+    );
+    data.insert(
+        "security.wipe_warning",
+        "清除会销毁密钥、加密笔记和数据文件夹中的所有备份，且无法撤销。未加密的笔记会保留。",
+    );
+    data.insert("security.wipe_save", "保存策略");
+    data.insert("security.idle_lock", "闲置多少分钟后自动锁定 (0 = 关闭)");
+    data.insert(
+        "security.idle_lock_hint",
+        "电脑从睡眠中唤醒时也会锁定。每个浏览器都需要单独输入 PIN 解锁。",
+    );
+    data.insert("security.idle_lock_save", "保存");
+    data.insert("security.duress_pin", "胁迫 PIN（留空则关闭）");
+    data.insert(
+        "security.duress_destroy",
+        "使用胁迫 PIN 时销毁真实保险库的密钥",
+    );
+    data.insert("security.duress_hint", "用胁迫 PIN 解锁会打开一个独立的空诱饵保险库。诱饵与真实保险库的加密笔记互不可见。重新设置胁迫 PIN 会重新开始诱饵保险库。");
+    data.insert("security.duress_save", "保存");
 
     // Backup
     data.insert("backup.title", "备份");
@@ -199,6 +264,26 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "版本");
     data.insert("settings.storage", "存储");
     data.insert("settings.about", "关于");
+    data.insert("settings.backup", "自动备份");
+    data.insert("settings.backup_desc", "按计划备份。没有变化时跳过。");
+    data.insert("settings.backup_interval", "间隔");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "第二备份文件夹");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "例如 U 盘。必须已挂载。留空则不使用。",
+    );
+    data.insert("settings.backup_save", "保存");
+    data.insert("settings.backup_now", "立即备份");
+    data.insert("settings.backup_last_run", "上次运行");
+    data.insert("settings.backup_next_run", "下次运行");
+    data.insert("settings.backup_skipped", "无变化，已跳过");
+    data.insert("settings.backup_failed", "备份失败");
+    data.insert("settings.backup_mirror_failed", "复制到第二文件夹失败");
+    data.insert("settings.backup_never", "从未");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "知识图谱");
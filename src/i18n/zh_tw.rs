@@ -59,10 +59,23 @@ pub fn translations() -> Translations {
     data.insert("notes.no_title", "無標題");
     data.insert("notes.edit", "編輯");
     data.insert("notes.delete", "刪除");
+    data.insert("notes.history", "歷史");
+    data.insert("notes.revision", "版本");
+    data.insert("notes.current", "目前");
+    data.insert("notes.compare", "比較");
+    data.insert("notes.restore", "還原");
+    data.insert(
+        "notes.restore_confirm",
+        "還原此版本？目前版本會保留在歷史中。",
+    );
+    data.insert("notes.no_changes", "沒有變更");
+    data.insert("notes.rating", "評分");
+    data.insert("notes.mood", "心情");
     data.insert("notes.delete_confirm", "確定要刪除嗎？");
     data.insert("notes.encrypted", "已加密");
     data.insert("notes.created", "建立於");
     data.insert("notes.updated", "更新於");
+    data.insert("notes.related", "相關筆記");
     data.insert("notes.find_duplicates", "尋找重複");
     data.insert("notes.select_all", "全選");
     data.insert("notes.selected", "已選取");
@@ -92,6 +105,9 @@ pub fn translations() -> Translations {
     data.insert("search.tip1", "多個詞搜尋包含所有詞的結果");
     data.insert("search.tip2", "同時搜尋筆記和維基百科");
     data.insert("search.tip3", "標題符合優先顯示");
+    data.insert("search.tip4", "篩選: tag:x type:journal after:2026-01-01 before:2026-02-01 rating:>=4 mood:😊 links-to:\"標題\" has:link is:encrypted");
+    data.insert("search.scope_notes", "筆記");
+    data.insert("search.scope_encrypted", "加密筆記");
 
     // Wiki
     data.insert("wiki.search", "維基搜尋");
@@ -125,6 +141,21 @@ pub fn translations() -> Translations {
     data.insert("wiki.zim_removed", "ZIM已移除: ");
     data.insert("wiki.remove_failed", "移除失敗");
     data.insert("wiki.open_folder_msg", "請在檔案總管中開啟資料夾:");
+    data.insert("wiki.mode_title", "標題");
+    data.insert("wiki.mode_fulltext", "全文");
+    data.insert("wiki.index_missing", "此 ZIM 尚無全文索引。請在此建立：");
+    data.insert("wiki.fulltext_index", "全文索引");
+    data.insert("wiki.build_index", "建立索引");
+    data.insert("wiki.all_zims", "所有檔案");
+    data.insert("wiki.verify", "校驗");
+    data.insert("wiki.verify_ok", "正常");
+    data.insert("wiki.verify_checksum", "正在校驗 MD5");
+    data.insert("wiki.verify_clusters", "正在檢查叢集");
+    data.insert("wiki.verify_entries", "正在檢查條目");
+    data.insert("wiki.verify_md5_mismatch", "MD5 不符");
+    data.insert("wiki.verify_bad_clusters", "損壞的叢集");
+    data.insert("wiki.verify_unreadable", "無法讀取的條目");
+    data.insert("wiki.verify_failed", "校驗失敗");
 
     // SRS
     data.insert("srs.title", "SRS複習");
@@ -172,6 +203,40 @@ pub fn translations() -> Translations {
     data.insert("security.unlocked", "已解鎖");
     data.insert("security.pin_min_length", "PIN至少需要6個字元");
     data.insert("security.enter_current_pin", "輸入目前的PIN");
+    data.insert("security.recovery_create", "建立復原單");
+    data.insert("security.recovery_forgot", "忘記 PIN？使用復原金鑰");
+    data.insert("security.recovery_key", "復原金鑰");
+    data.insert(
+        "security.recovery_new_pin",
+        "輸入復原金鑰，然後在上方輸入新 PIN。",
+    );
+    data.insert("security.recovery_submit", "設定新 PIN");
+    data.insert("security.recovery_sheet_title", "Lazarus 復原單");
+    data.insert("security.recovery_sheet_hint", "任何持有此金鑰的人都能開啟你的筆記。請列印或抄寫，並與本裝置分開保存。只會顯示一次，並會取代先前的復原金鑰。");
+    data.insert("security.recovery_print", "列印");
+    data.insert("security.failed_attempts", "上次解鎖後 PIN 錯誤次數");
+    data.insert(
+        "security.wipe_after",
+        "連續輸錯 PIN 達到此次數後清除加密筆記（0 = 永不）",
+    );
+    data.insert(
+        "security.wipe_warning",
+        "清除會銷毀金鑰、加密筆記和資料夾中的所有備份，且無法復原。未加密的筆記會保留。",
+    );
+    data.insert("security.wipe_save", "儲存原則");
+    data.insert("security.idle_lock", "閒置多少分鐘後自動鎖定 (0 = 關閉)");
+    data.insert(
+        "security.idle_lock_hint",
+        "電腦從睡眠喚醒時也會鎖定。每個瀏覽器都需要各自輸入 PIN 解鎖。",
+    );
+    data.insert("security.idle_lock_save", "儲存");
+    data.insert("security.duress_pin", "脅迫 PIN（留空則關閉）");
+    data.insert(
+        "security.duress_destroy",
+        "使用脅迫 PIN 時銷毀真實保險庫的金鑰",
+    );
+    data.insert("security.duress_hint", "用脅迫 PIN 解鎖會開啟一個獨立的空誘餌保險庫。誘餌與真實保險庫的加密筆記互不可見。重新設定脅迫 PIN 會重新開始誘餌保險庫。");
+    data.insert("security.duress_save", "儲存");
 
     // Backup
     data.insert("backup.title", "備份");
@@ -199,6 +264,26 @@ pub fn translations() -> Translations {
     data.insert("settings.version", "版本");
     data.insert("settings.storage", "儲存空間");
     data.insert("settings.about", "關於");
+    data.insert("settings.backup", "自動備份");
+    data.insert("settings.backup_desc", "按排程備份。沒有變更時略過。");
+    data.insert("settings.backup_interval", "間隔");
+    data.insert(
+        "settings.backup_interval_hint",
+        "off, 30m, 6h, 1d or daily 03:00",
+    );
+    data.insert("settings.backup_mirror", "第二備份資料夾");
+    data.insert(
+        "settings.backup_mirror_hint",
+        "例如 USB 隨身碟。必須已掛載。留空則不使用。",
+    );
+    data.insert("settings.backup_save", "儲存");
+    data.insert("settings.backup_now", "立即備份");
+    data.insert("settings.backup_last_run", "上次執行");
+    data.insert("settings.backup_next_run", "下次執行");
+    data.insert("settings.backup_skipped", "無變更，已略過");
+    data.insert("settings.backup_failed", "備份失敗");
+    data.insert("settings.backup_mirror_failed", "複製到第二資料夾失敗");
+    data.insert("settings.backup_never", "從未");
 
     // Knowledge Graph & Footer
     data.insert("home.knowledge_graph", "知識圖譜");
@@ -41,6 +41,14 @@ struct Args {
     #[arg(long)]
     zim_dir: Option<PathBuf>,
 
+    /// ZIM 클러스터 캐시 크기 (바이트, 0이면 비활성화)
+    #[arg(long, default_value_t = zim::DEFAULT_CACHE_BYTES)]
+    zim_cache_bytes: usize,
+
+    /// WAL 내구성 모드 (always-fsync, group-commit, group-commit:<ms>)
+    #[arg(long, default_value = "always-fsync")]
+    durability: db::Durability,
+
     /// 바인드 주소
     #[arg(short, long, default_value = "127.0.0.1")]
     bind: String,
@@ -48,6 +56,19 @@ struct Args {
     /// 로그 레벨 (trace, debug, info, warn, error)
     #[arg(long, default_value = "info")]
     log_level: String,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Command {
+    /// notes.lazarus 손상 검사 및 복구 (서버가 꺼진 상태에서 실행)
+    Repair {
+        /// 검사만 하고 파일은 바꾸지 않음
+        #[arg(long)]
+        check: bool,
+    },
 }
 
 #[tokio::main]
@@ -57,6 +78,10 @@ async fn main() -> Result<()> {
     // 로깅 초기화
     init_logging(&args.log_level);
 
+    if let Some(Command::Repair { check }) = args.command {
+        return run_repair(&args.data, check);
+    }
+
     tracing::info!("🚀 Lazarus 시작");
     tracing::info!("   데이터 경로: {}", args.data.display());
     tracing::info!("   서버 주소: {}:{}", args.bind, args.port);
@@ -85,13 +110,36 @@ async fn main() -> Result<()> {
         }
     }
 
-    let state = web::state::AppState::new(args.data.clone(), zim_paths).await?;
+    let state =
+        web::state::AppState::new(args.data.clone(), zim_paths, args.zim_cache_bytes).await?;
+
+    state.set_durability(args.durability).await?;
+
+    // 자동 백업 (주기/두 번째 대상은 설정 화면에서)
+    state.start_backup_scheduler();
+
+    // 유휴 시간 초과/절전 복귀 시 자동 잠금
+    state.start_auto_lock();
 
     // 링크 인덱스 빌드 (이거 추가!)
     state.build_link_index().await?;
 
+    // 예전 노트의 임베딩 채우기 (관련 노트/의미 검색용)
+    state.build_note_vectors().await;
+
+    // 검색 인덱스 재빌드 (필요한 경우) 및 평문으로 남은 암호화 노트 제거
+    if let Err(e) = state.rebuild_search_index_if_empty().await {
+        tracing::warn!("검색 인덱스 재빌드 실패: {}", e);
+    }
+    if let Err(e) = state.purge_encrypted_from_index().await {
+        tracing::warn!("암호화 노트 인덱스 정리 실패: {}", e);
+    }
+
+    // 중단된 ZIM 본문 인덱싱 재개
+    state.resume_zim_indexing().await;
+
     // 라우터 생성
-    let app = web::router::create_router(state);
+    let app = web::router::create_router(state.clone());
 
     // 서버 시작
     let addr = format!("{}:{}", args.bind, args.port);
@@ -107,10 +155,52 @@ async fn main() -> Result<()> {
         .await
         .map_err(|e| error::LazarusError::ServerStart(e.to_string()))?;
 
+    // 대기 중인 쓰기를 내리고 다음 시작을 위한 인덱스 체크포인트 저장
+    if let Err(e) = state.db.write().await.checkpoint() {
+        tracing::warn!("체크포인트 저장 실패: {}", e);
+    }
+
     tracing::info!("👋 Lazarus 종료");
     Ok(())
 }
 
+/// `lazarus repair`
+fn run_repair(data_dir: &std::path::Path, check_only: bool) -> Result<()> {
+    let path = data_dir.join("notes.lazarus");
+    if !path.exists() {
+        println!("파일 없음: {}", path.display());
+        return Ok(());
+    }
+
+    let report = if check_only {
+        db::repair::check(&path, &Default::default())?
+    } else {
+        db::repair::repair(&path, &Default::default())?
+    };
+
+    println!("파일: {} ({} 바이트)", path.display(), report.file_size);
+    println!(
+        "유효한 엔트리: {} (노트 {}개)",
+        report.valid_entries, report.notes
+    );
+    if report.is_clean() {
+        println!("손상 없음");
+        return Ok(());
+    }
+
+    println!(
+        "손상 구간: {}개, {} 바이트 (첫 위치: {})",
+        report.corrupted_regions,
+        report.skipped_bytes,
+        report.first_corruption.unwrap_or_default()
+    );
+    match report.corrupt_path {
+        Some(corrupt) => println!("복구 완료. 원본은 {} 에 보관됨", corrupt),
+        None => println!("`lazarus repair`로 복구할 수 있습니다"),
+    }
+    Ok(())
+}
+
 /// 로깅 초기화
 fn init_logging(level: &str) {
     let filter = tracing_subscriber::EnvFilter::try_from_default_env()
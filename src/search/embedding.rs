@@ -0,0 +1,136 @@
+//! 노트 임베딩 (오프라인, CPU 전용)
+//!
+//! 모델 파일 없이 단어와 문자 3-gram을 해싱해 고정 차원 벡터로 만듭니다
+//! (feature hashing). 결과는 i8로 양자화해 `NoteAtom.vector`에 그대로 저장하고,
+//! 유사도는 정수 내적으로 계산합니다.
+
+use crate::db::Note;
+
+/// 임베딩 차원
+pub const EMBEDDING_DIM: usize = 256;
+
+/// 문자 n-gram 길이
+const NGRAM: usize = 3;
+
+/// 단어 자체의 가중치 (n-gram 하나는 1)
+const WORD_WEIGHT: f32 = 2.0;
+
+/// FNV-1a 64비트 해시
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// 해시 하나를 차원에 더하기 (최상위 비트로 부호를 정해 충돌 편향 상쇄)
+fn add_feature(acc: &mut [f32], feature: &[u8], weight: f32) {
+    let hash = fnv1a(feature);
+    let dim = (hash % EMBEDDING_DIM as u64) as usize;
+    if hash >> 63 == 0 {
+        acc[dim] += weight;
+    } else {
+        acc[dim] -= weight;
+    }
+}
+
+/// 텍스트 임베딩
+///
+/// 빈 텍스트는 영벡터가 되며 어떤 노트와도 유사도 0입니다.
+pub fn embed(text: &str) -> Vec<i8> {
+    let mut acc = vec![0f32; EMBEDDING_DIM];
+    let lower = text.to_lowercase();
+
+    for word in lower.split(|c: char| !c.is_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        add_feature(&mut acc, word.as_bytes(), WORD_WEIGHT);
+
+        // 단어 경계를 포함한 문자 n-gram (한글/한자도 글자 단위로)
+        let chars: Vec<char> = std::iter::once(' ')
+            .chain(word.chars())
+            .chain(std::iter::once(' '))
+            .collect();
+        let mut buf = String::new();
+        for window in chars.windows(NGRAM) {
+            buf.clear();
+            buf.extend(window);
+            add_feature(&mut acc, buf.as_bytes(), 1.0);
+        }
+    }
+
+    quantize(&acc)
+}
+
+/// 최대 절댓값 기준으로 i8 양자화
+fn quantize(acc: &[f32]) -> Vec<i8> {
+    let max = acc.iter().fold(0f32, |m, v| m.max(v.abs()));
+    if max == 0.0 {
+        return vec![0; acc.len()];
+    }
+    acc.iter()
+        .map(|v| (v / max * 127.0).round() as i8)
+        .collect()
+}
+
+/// 노트 임베딩 (암호화된 노트는 벡터가 평문 내용을 드러내므로 만들지 않음)
+pub fn embed_note(note: &Note) -> Option<Vec<i8>> {
+    if note.encrypted {
+        return None;
+    }
+    // 제목과 태그는 본문보다 주제를 잘 나타내므로 두 번 넣어 가중치를 높임
+    let tags = note.tags.join(" ");
+    let text = format!(
+        "{} {} {} {} {}",
+        note.title, note.title, tags, tags, note.content
+    );
+    Some(embed(&text))
+}
+
+/// 양자화된 벡터의 코사인 유사도
+pub fn cosine(a: &[i8], b: &[i8]) -> f32 {
+    let mut dot = 0i64;
+    let mut norm_a = 0i64;
+    let mut norm_b = 0i64;
+    for (x, y) in a.iter().zip(b) {
+        let (x, y) = (*x as i64, *y as i64);
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0 || norm_b == 0 {
+        return 0.0;
+    }
+    dot as f32 / ((norm_a as f32).sqrt() * (norm_b as f32).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embed_similarity_ranking() {
+        let physics = embed("Newton's laws of motion describe force and acceleration");
+        let related = embed("Force equals mass times acceleration, Newton's second law");
+        let unrelated = embed("Kimchi recipe with napa cabbage and chili flakes");
+
+        assert_eq!(physics.len(), EMBEDDING_DIM);
+        assert!((cosine(&physics, &physics) - 1.0).abs() < 1e-3);
+        assert!(cosine(&physics, &related) > cosine(&physics, &unrelated));
+    }
+
+    #[test]
+    fn test_empty_and_encrypted() {
+        let empty = embed("   ");
+        assert!(empty.iter().all(|v| *v == 0));
+        assert_eq!(cosine(&empty, &embed("anything")), 0.0);
+
+        let mut note = Note::new(1, "비밀".to_string(), "일기".to_string());
+        assert!(embed_note(&note).is_some());
+        note.encrypted = true;
+        assert!(embed_note(&note).is_none());
+    }
+}
@@ -1,18 +1,39 @@
 //! 검색 레이어
 //!
 //! Tantivy 기반 풀텍스트 검색
-use std::path::Path;
-use tantivy::query::{BooleanQuery, FuzzyTermQuery, Occur};
+
+pub mod embedding;
+pub mod query;
+pub mod zim_index;
+
+pub use embedding::{cosine, embed, embed_note, EMBEDDING_DIM};
+pub use query::{NoteQuery, RatingFilter};
+pub use zim_index::{ZimFullTextIndex, ZimIndexProgress, ZimTextHit};
+
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use tantivy::query::{
+    AllQuery, BooleanQuery, ExistsQuery, FuzzyTermQuery, Occur, Query, RangeQuery, TermQuery,
+};
 use tantivy::{
     collector::TopDocs,
     directory::MmapDirectory,
     doc,
     query::QueryParser,
-    schema::{Field, NumericOptions, Schema, Value, STORED, TEXT},
-    Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument,
+    schema::{Field, IndexRecordOption, NumericOptions, Schema, Value, FAST, STORED, STRING, TEXT},
+    DocAddress, Index, IndexReader, IndexWriter, Order, ReloadPolicy, Score, TantivyDocument,
+    TantivyError, Term,
 };
 
+use crate::db::Note;
 use crate::error::{LazarusError, Result};
+use crate::links::extract_links;
+
+/// 디스크 인덱스 형식 버전 (2: 암호화 노트를 넣지 않음)
+const INDEX_VERSION: u32 = 2;
+
+/// 인덱스 디렉토리 안의 버전 파일 (없으면 버전 1)
+const VERSION_FILE: &str = "lazarus.version";
 
 /// 검색 엔진
 pub struct SearchEngine {
@@ -25,6 +46,22 @@ pub struct SearchEngine {
     field_title: Field,
     field_content: Field,
     field_tags: Field,
+
+    // 필터용 필드
+    /// 태그 원문 (소문자, 정확히 일치)
+    field_tag: Field,
+    field_note_type: Field,
+    field_created: Field,
+    field_updated: Field,
+    /// 별점 없으면 0
+    field_rating: Field,
+    field_mood: Field,
+    field_encrypted: Field,
+    /// `[[링크]]` 대상 제목 (소문자)
+    field_links: Field,
+
+    /// 버전 파일 경로 (메모리 인덱스면 None)
+    version_path: Option<PathBuf>,
 }
 
 impl SearchEngine {
@@ -35,27 +72,72 @@ impl SearchEngine {
         // 디렉토리 생성
         std::fs::create_dir_all(index_path)?;
 
-        // 스키마 정의
-        let mut schema_builder = Schema::builder();
-
-        let field_id = schema_builder.add_u64_field("id", NumericOptions::default().set_stored());
-        let field_title = schema_builder.add_text_field("title", TEXT | STORED);
-        let field_content = schema_builder.add_text_field("content", TEXT | STORED);
-        let field_tags = schema_builder.add_text_field("tags", TEXT | STORED);
-
-        let schema = schema_builder.build();
-
         // 인덱스 열기 또는 생성
         let dir = MmapDirectory::open(index_path)
             .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
 
-        let index = Index::open_or_create(dir, schema.clone())
-            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        let index = match Index::open_or_create(dir, Self::schema()) {
+            Ok(index) => index,
+            // 스키마가 바뀐 예전 인덱스는 지우고 새로 만듦 (시작 시 DB에서 다시 빌드됨)
+            Err(TantivyError::SchemaError(e)) => {
+                tracing::warn!("검색 인덱스 스키마 변경, 재생성: {}", e);
+                std::fs::remove_dir_all(index_path)?;
+                std::fs::create_dir_all(index_path)?;
+                Index::create_in_dir(index_path, Self::schema())
+                    .map_err(|e| LazarusError::IndexCreate(e.to_string()))?
+            }
+            Err(e) => return Err(LazarusError::IndexCreate(e.to_string())),
+        };
+
+        let mut engine = Self::from_index(index, ReloadPolicy::OnCommitWithDelay)?;
+        engine.version_path = Some(index_path.join(VERSION_FILE));
+
+        tracing::info!("SearchEngine 초기화 완료: {}", index_path.display());
+
+        Ok(engine)
+    }
+
+    /// 메모리 전용 검색 엔진 생성 (암호화된 노트용, 디스크에 아무것도 쓰지 않음)
+    pub fn open_in_ram() -> Result<Self> {
+        let index = Index::create_in_ram(Self::schema());
+        Self::from_index(index, ReloadPolicy::Manual)
+    }
+
+    /// 스키마 정의
+    fn schema() -> Schema {
+        let mut schema_builder = Schema::builder();
+        let filter = || NumericOptions::default().set_indexed().set_fast();
+
+        schema_builder.add_u64_field("id", NumericOptions::default().set_stored().set_indexed());
+        schema_builder.add_text_field("title", TEXT | STORED);
+        schema_builder.add_text_field("content", TEXT | STORED);
+        schema_builder.add_text_field("tags", TEXT | STORED);
+
+        // 필터 (has: 조건은 fast 필드 존재 여부로 검사)
+        schema_builder.add_text_field("tag", STRING | FAST);
+        schema_builder.add_text_field("note_type", STRING);
+        schema_builder.add_i64_field("created", filter());
+        schema_builder.add_i64_field("updated", filter());
+        schema_builder.add_u64_field("rating", filter());
+        schema_builder.add_text_field("mood", STRING | FAST);
+        schema_builder.add_u64_field("encrypted", filter());
+        schema_builder.add_text_field("links", STRING | FAST);
+
+        schema_builder.build()
+    }
+
+    fn from_index(index: Index, reload_policy: ReloadPolicy) -> Result<Self> {
+        let schema = index.schema();
+        let field = |name: &str| {
+            schema
+                .get_field(name)
+                .map_err(|e| LazarusError::IndexCreate(e.to_string()))
+        };
 
         // 리더 생성
         let reader = index
             .reader_builder()
-            .reload_policy(ReloadPolicy::OnCommitWithDelay)
+            .reload_policy(reload_policy)
             .try_into()
             .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
 
@@ -64,162 +146,238 @@ impl SearchEngine {
             .writer(15_000_000)
             .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
 
-        tracing::info!("SearchEngine 초기화 완료: {}", index_path.display());
-
         Ok(Self {
+            field_id: field("id")?,
+            field_title: field("title")?,
+            field_content: field("content")?,
+            field_tags: field("tags")?,
+            field_tag: field("tag")?,
+            field_note_type: field("note_type")?,
+            field_created: field("created")?,
+            field_updated: field("updated")?,
+            field_rating: field("rating")?,
+            field_mood: field("mood")?,
+            field_encrypted: field("encrypted")?,
+            field_links: field("links")?,
             index,
             reader,
             writer,
-            field_id,
-            field_title,
-            field_content,
-            field_tags,
+            version_path: None,
         })
     }
 
+    /// 예전 형식이라 정리가 필요한 인덱스인지
+    pub fn needs_upgrade(&self) -> bool {
+        let Some(path) = &self.version_path else {
+            return false;
+        };
+        let version = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|v| v.trim().parse::<u32>().ok())
+            .unwrap_or(1);
+        version < INDEX_VERSION
+    }
+
+    /// 현재 형식으로 정리됐음을 기록
+    pub fn mark_upgraded(&self) -> Result<()> {
+        if let Some(path) = &self.version_path {
+            std::fs::write(path, INDEX_VERSION.to_string())?;
+        }
+        Ok(())
+    }
+
+    /// 인덱스된 문서 수
+    pub fn num_docs(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
+
     /// 노트 인덱싱
-    pub fn index_note(
-        &mut self,
-        id: u64,
-        title: &str,
-        content: &str,
-        tags: &[String],
-    ) -> Result<()> {
-        // 기존 문서 삭제 (업데이트를 위해)
-        self.delete_note(id)?;
+    pub fn index_note(&mut self, note: &Note) -> Result<()> {
+        self.add_note(note)?;
+        self.commit()?;
 
-        let tags_str = tags.join(" ");
+        tracing::debug!("노트 인덱싱: id={}", note.id);
 
-        self.writer
-            .add_document(doc!(
-                self.field_id => id,
-                self.field_title => title,
-                self.field_content => content,
-                self.field_tags => tags_str,
-            ))
-            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 노트 추가 (커밋은 호출자가, 대량 인덱싱용)
+    pub fn add_note(&mut self, note: &Note) -> Result<()> {
+        // 기존 문서 삭제 (업데이트를 위해)
+        self.delete_note(note.id)?;
+
+        let mut document = doc!(
+            self.field_id => note.id,
+            self.field_title => note.title.as_str(),
+            self.field_content => note.content.as_str(),
+            self.field_tags => note.tags.join(" "),
+            self.field_note_type => format!("{:?}", note.note_type).to_lowercase(),
+            self.field_created => note.created_at.timestamp(),
+            self.field_updated => note.updated_at.timestamp(),
+            self.field_rating => note.rating.unwrap_or(0) as u64,
+            self.field_encrypted => note.encrypted as u64,
+        );
+        for tag in note.tags.iter().filter(|t| !t.is_empty()) {
+            document.add_text(self.field_tag, tag.to_lowercase());
+        }
+        for link in extract_links(&note.content) {
+            document.add_text(self.field_links, link.to_lowercase());
+        }
+        if let Some(mood) = note.mood.as_deref().filter(|m| !m.is_empty()) {
+            document.add_text(self.field_mood, mood);
+        }
 
         self.writer
-            .commit()
+            .add_document(document)
             .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
 
-        tracing::debug!("노트 인덱싱: id={}", id);
-
         Ok(())
     }
 
     /// 노트 삭제
     pub fn delete_note(&mut self, id: u64) -> Result<()> {
-        let term = tantivy::Term::from_field_u64(self.field_id, id);
+        let term = Term::from_field_u64(self.field_id, id);
         self.writer.delete_term(term);
         Ok(())
     }
 
-    /// 검색 실행
-    pub fn search(&self, query_str: &str, limit: usize) -> Result<Vec<SearchResult>> {
-        if query_str.trim().is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let searcher = self.reader.searcher();
-
-        // 제목과 내용 필드에서 검색
-        let query_parser = QueryParser::for_index(
-            &self.index,
-            vec![self.field_title, self.field_content, self.field_tags],
-        );
-
-        let query = query_parser
-            .parse_query(query_str)
-            .map_err(|e| LazarusError::SearchFailed(e.to_string()))?;
-
-        let top_docs = searcher
-            .search(&query, &TopDocs::with_limit(limit))
-            .map_err(|e| LazarusError::SearchFailed(e.to_string()))?;
-
-        let mut results = Vec::new();
-
-        for (score, doc_address) in top_docs {
-            let doc: TantivyDocument = searcher
-                .doc(doc_address)
-                .map_err(|e| LazarusError::SearchFailed(e.to_string()))?;
-
-            let id = doc
-                .get_first(self.field_id)
-                .and_then(|v| v.as_u64())
-                .unwrap_or(0);
+    /// 모든 문서 삭제 (전체 재빌드용, 커밋은 호출자가)
+    pub fn clear(&mut self) -> Result<()> {
+        self.writer
+            .delete_all_documents()
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        Ok(())
+    }
 
-            let title = doc
-                .get_first(self.field_title)
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string();
+    /// 변경 사항 커밋 후 리더 갱신
+    pub fn commit(&mut self) -> Result<()> {
+        self.writer
+            .commit()
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        self.reader
+            .reload()
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        Ok(())
+    }
 
-            let content = doc
-                .get_first(self.field_content)
-                .and_then(|v| v.as_str())
-                .unwrap_or("")
-                .to_string();
+    /// 모든 세그먼트 병합 (삭제된 문서를 디스크에서 실제로 지움)
+    pub fn merge_segments(&mut self) -> Result<()> {
+        let segment_ids = self
+            .index
+            .searchable_segment_ids()
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        if !segment_ids.is_empty() {
+            self.writer
+                .merge(&segment_ids)
+                .wait()
+                .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        }
+        self.writer
+            .garbage_collect_files()
+            .wait()
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        Ok(())
+    }
 
-            // 미리보기 생성 (첫 150자)
-            let preview = content.chars().take(150).collect::<String>() + "...";
+    /// 검색 실행 (`tag:`, `type:` 등 필터 문법 지원, [`NoteQuery`] 참고)
+    pub fn search(&self, query_str: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        self.search_query(&NoteQuery::parse(query_str)?, limit)
+    }
 
-            results.push(SearchResult {
-                id,
-                title,
-                preview,
-                score,
-            });
+    /// 파싱된 쿼리로 검색
+    ///
+    /// 검색어 없이 필터만 있으면 최근 작성순으로 반환합니다.
+    pub fn search_query(&self, query: &NoteQuery, limit: usize) -> Result<Vec<SearchResult>> {
+        if query.is_empty() {
+            return Ok(Vec::new());
         }
 
-        Ok(results)
+        let text_query = if query.text.trim().is_empty() {
+            None
+        } else {
+            // 제목과 내용 필드에서 검색 (`C++`, 닫히지 않은 따옴표 같은 입력도 허용)
+            let query_parser = QueryParser::for_index(
+                &self.index,
+                vec![self.field_title, self.field_content, self.field_tags],
+            );
+            let (parsed, _errors) = query_parser.parse_query_lenient(&query.text);
+            Some(parsed)
+        };
+
+        self.run(text_query, query, limit)
     }
+
     /// Fuzzy 검색 (오타 허용)
     pub fn search_fuzzy(&self, query_str: &str, limit: usize) -> Result<Vec<SearchResult>> {
-        if query_str.trim().is_empty() {
+        let query = NoteQuery::parse(query_str)?;
+        if query.is_empty() {
             return Ok(Vec::new());
         }
 
-        let searcher = self.reader.searcher();
-
         // 먼저 일반 검색 시도
-        let normal_results = self.search(query_str, limit)?;
+        let normal_results = self.search_query(&query, limit)?;
         if !normal_results.is_empty() {
             return Ok(normal_results);
         }
 
         // 결과 없으면 Fuzzy 검색
-        let terms: Vec<&str> = query_str.split_whitespace().collect();
+        let terms: Vec<&str> = query.text.split_whitespace().collect();
         if terms.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut subqueries: Vec<(Occur, Box<dyn tantivy::query::Query>)> = Vec::new();
+        let mut subqueries: Vec<(Occur, Box<dyn Query>)> = Vec::new();
 
         for term in &terms {
             // 제목 필드 Fuzzy (edit distance 1)
-            let title_term = tantivy::Term::from_field_text(self.field_title, term);
+            let title_term = Term::from_field_text(self.field_title, term);
             let title_fuzzy = FuzzyTermQuery::new(title_term, 1, true);
             subqueries.push((Occur::Should, Box::new(title_fuzzy)));
 
             // 내용 필드 Fuzzy (edit distance 1)
-            let content_term = tantivy::Term::from_field_text(self.field_content, term);
+            let content_term = Term::from_field_text(self.field_content, term);
             let content_fuzzy = FuzzyTermQuery::new(content_term, 1, true);
             subqueries.push((Occur::Should, Box::new(content_fuzzy)));
 
             // 태그 필드 Fuzzy
-            let tags_term = tantivy::Term::from_field_text(self.field_tags, term);
+            let tags_term = Term::from_field_text(self.field_tags, term);
             let tags_fuzzy = FuzzyTermQuery::new(tags_term, 1, true);
             subqueries.push((Occur::Should, Box::new(tags_fuzzy)));
         }
 
-        let query = BooleanQuery::new(subqueries);
+        self.run(Some(Box::new(BooleanQuery::new(subqueries))), &query, limit)
+    }
 
-        let top_docs = searcher
-            .search(&query, &TopDocs::with_limit(limit))
-            .map_err(|e| LazarusError::SearchFailed(e.to_string()))?;
+    /// 검색어 쿼리에 필터를 붙여 실행
+    fn run(
+        &self,
+        text_query: Option<Box<dyn Query>>,
+        query: &NoteQuery,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let searcher = self.reader.searcher();
+        let has_text = text_query.is_some();
+
+        let mut clauses = self.filter_clauses(query);
+        clauses.push((
+            Occur::Must,
+            text_query.unwrap_or_else(|| Box::new(AllQuery)),
+        ));
+        let combined = BooleanQuery::new(clauses);
+
+        let top_docs: Vec<(Score, DocAddress)> = if has_text {
+            searcher.search(&combined, &TopDocs::with_limit(limit))
+        } else {
+            let collector =
+                TopDocs::with_limit(limit).order_by_fast_field::<i64>("created", Order::Desc);
+            searcher
+                .search(&combined, &collector)
+                .map(|docs| docs.into_iter().map(|(_, addr)| (1.0, addr)).collect())
+        }
+        .map_err(|e| LazarusError::SearchFailed(e.to_string()))?;
 
         let mut results = Vec::new();
+
         for (score, doc_address) in top_docs {
             let doc: TantivyDocument = searcher
                 .doc(doc_address)
@@ -242,6 +400,7 @@ impl SearchEngine {
                 .unwrap_or("")
                 .to_string();
 
+            // 미리보기 생성 (첫 150자)
             let preview = content.chars().take(150).collect::<String>() + "...";
 
             results.push(SearchResult {
@@ -254,6 +413,74 @@ impl SearchEngine {
 
         Ok(results)
     }
+
+    /// 필터 → tantivy 쿼리 절
+    fn filter_clauses(&self, query: &NoteQuery) -> Vec<(Occur, Box<dyn Query>)> {
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        let term = |field: Field, value: &str| -> Box<dyn Query> {
+            Box::new(TermQuery::new(
+                Term::from_field_text(field, value),
+                IndexRecordOption::Basic,
+            ))
+        };
+        let u64_range = |field: &str, min: u64, max: u64| -> Box<dyn Query> {
+            Box::new(RangeQuery::new_u64_bounds(
+                field.to_string(),
+                Bound::Included(min),
+                Bound::Included(max),
+            ))
+        };
+        let exists = |field: &str| -> Box<dyn Query> {
+            Box::new(ExistsQuery::new_exists_query(field.to_string()))
+        };
+
+        for tag in &query.tags {
+            clauses.push((Occur::Must, term(self.field_tag, tag)));
+        }
+        if let Some(note_type) = query.note_type {
+            let key = format!("{:?}", note_type).to_lowercase();
+            clauses.push((Occur::Must, term(self.field_note_type, &key)));
+        }
+        if query.after.is_some() || query.before.is_some() {
+            let lower = query.after.map_or(Bound::Unbounded, Bound::Included);
+            let upper = query.before.map_or(Bound::Unbounded, Bound::Excluded);
+            clauses.push((
+                Occur::Must,
+                Box::new(RangeQuery::new_i64_bounds(
+                    "created".to_string(),
+                    lower,
+                    upper,
+                )),
+            ));
+        }
+        if let Some(rating) = query.rating {
+            let (min, max) = rating.bounds();
+            clauses.push((Occur::Must, u64_range("rating", min, max)));
+        } else if query.has_rating {
+            clauses.push((Occur::Must, u64_range("rating", 1, u64::MAX)));
+        }
+        if let Some(mood) = &query.mood {
+            clauses.push((Occur::Must, term(self.field_mood, mood)));
+        }
+        for link in &query.links_to {
+            clauses.push((Occur::Must, term(self.field_links, link)));
+        }
+        if query.has_link {
+            clauses.push((Occur::Must, exists("links")));
+        }
+        if query.has_tag {
+            clauses.push((Occur::Must, exists("tag")));
+        }
+        if query.has_mood {
+            clauses.push((Occur::Must, exists("mood")));
+        }
+        if let Some(encrypted) = query.encrypted {
+            let v = encrypted as u64;
+            clauses.push((Occur::Must, u64_range("encrypted", v, v)));
+        }
+
+        clauses
+    }
 }
 
 /// 검색 결과
@@ -264,3 +491,118 @@ pub struct SearchResult {
     pub preview: String,
     pub score: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::note::NoteType;
+
+    fn note(id: u64, title: &str, content: &str, tags: &[&str]) -> Note {
+        let mut note = Note::new(id, title.to_string(), content.to_string());
+        note.tags = tags.iter().map(|t| t.to_string()).collect();
+        note
+    }
+
+    #[test]
+    fn test_in_ram_index() {
+        let mut engine = SearchEngine::open_in_ram().unwrap();
+        engine
+            .index_note(&note(7, "비밀 일기", "secret diary entry", &["private"]))
+            .unwrap();
+
+        let results = engine.search("diary", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, 7);
+
+        engine.delete_note(7).unwrap();
+        engine.commit().unwrap();
+        assert!(engine.search("diary", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_structured_filters() {
+        let mut engine = SearchEngine::open_in_ram().unwrap();
+
+        let mut journal = note(
+            1,
+            "Apple day",
+            "Read about [[Newton]] and gravity",
+            &["Physics"],
+        );
+        journal.note_type = NoteType::Journal;
+        journal.mood = Some("😊".to_string());
+        journal.created_at = "2026-02-10T09:00:00Z".parse().unwrap();
+
+        let mut review = note(
+            2,
+            "Principia",
+            "A hard book about gravity",
+            &["physics", "books"],
+        );
+        review.note_type = NoteType::Review;
+        review.rating = Some(5);
+        review.created_at = "2025-12-01T09:00:00Z".parse().unwrap();
+
+        let mut idea = note(3, "Orbits", "Gravity keeps the moon close", &[]);
+        idea.note_type = NoteType::Idea;
+        idea.rating = Some(2);
+        idea.created_at = "2026-03-01T09:00:00Z".parse().unwrap();
+
+        for n in [&journal, &review, &idea] {
+            engine.add_note(n).unwrap();
+        }
+        engine.commit().unwrap();
+
+        let ids = |q: &str| -> Vec<u64> {
+            let mut ids: Vec<u64> = engine
+                .search(q, 10)
+                .unwrap()
+                .into_iter()
+                .map(|r| r.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids("gravity"), vec![1, 2, 3]);
+        assert_eq!(ids("gravity tag:physics"), vec![1, 2]);
+        assert_eq!(ids("type:journal"), vec![1]);
+        assert_eq!(ids("after:2026-01-01"), vec![1, 3]);
+        assert_eq!(ids("before:2026-01-01 gravity"), vec![2]);
+        assert_eq!(ids("rating:>=4"), vec![2]);
+        assert_eq!(ids("has:rating"), vec![2, 3]);
+        assert_eq!(ids(r#"links-to:"newton""#), vec![1]);
+        assert_eq!(ids("has:link"), vec![1]);
+        assert_eq!(ids("has:tag"), vec![1, 2]);
+        assert_eq!(ids("mood:😊"), vec![1]);
+        assert_eq!(ids("is:plain tag:books"), vec![2]);
+
+        // 쿼리 문법에 맞지 않는 자유 입력도 에러 없이 검색
+        assert_eq!(ids(r#""gravity"#), vec![1, 2, 3]);
+        assert!(engine.search("C++", 10).is_ok());
+        assert!(engine.search("a:b", 10).is_ok());
+
+        // 검색어 없이 필터만 있으면 최근 작성순
+        let by_date: Vec<u64> = engine
+            .search("has:rating", 10)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(by_date, vec![3, 2]);
+    }
+
+    #[test]
+    fn test_index_version_marker() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = SearchEngine::open(tmp.path()).unwrap();
+        assert!(engine.needs_upgrade());
+        engine.mark_upgraded().unwrap();
+        drop(engine);
+
+        // 다시 열어도 정리는 한 번으로 끝
+        let engine = SearchEngine::open(tmp.path()).unwrap();
+        assert!(!engine.needs_upgrade());
+        assert!(!SearchEngine::open_in_ram().unwrap().needs_upgrade());
+    }
+}
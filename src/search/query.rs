@@ -0,0 +1,261 @@
+//! 노트 검색 쿼리 언어
+//!
+//! 일반 검색어에 필터를 섞어 쓸 수 있습니다.
+//!
+//! ```text
+//! tag:physics type:journal after:2026-01-01 rating:>=4 links-to:"Newton" 뉴턴
+//! ```
+//!
+//! | 필터 | 의미 |
+//! |------|------|
+//! | `tag:x` | 태그 일치 (대소문자 무시) |
+//! | `type:journal` | 노트 타입 (note/journal/review/idea) |
+//! | `after:2026-01-01` | 그날 이후 작성 (그날 포함) |
+//! | `before:2026-01-01` | 그날 이전 작성 (그날 제외) |
+//! | `rating:>=4` | 별점 (`4`, `>4`, `>=4`, `<4`, `<=4`) |
+//! | `mood:😊` | 저널 기분 |
+//! | `links-to:"제목"` | `[[제목]]` 링크 포함 |
+//! | `has:link` / `has:tag` / `has:rating` / `has:mood` | 해당 값이 있는 노트 |
+//! | `is:encrypted` / `is:plain` | 암호화 여부 |
+//!
+//! 알 수 없는 `키:값`은 그대로 검색어로 넘깁니다 (tantivy 필드 문법 `title:x` 등).
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::db::note::NoteType;
+use crate::error::{LazarusError, Result};
+
+/// 별점 비교
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RatingFilter {
+    Eq(u8),
+    Gt(u8),
+    Gte(u8),
+    Lt(u8),
+    Lte(u8),
+}
+
+impl RatingFilter {
+    /// `4`, `>=4`, `<3` 같은 문자열 파싱
+    pub fn parse(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (ctor, num): (fn(u8) -> Self, &str) = if let Some(n) = s.strip_prefix(">=") {
+            (Self::Gte, n)
+        } else if let Some(n) = s.strip_prefix("<=") {
+            (Self::Lte, n)
+        } else if let Some(n) = s.strip_prefix('>') {
+            (Self::Gt, n)
+        } else if let Some(n) = s.strip_prefix('<') {
+            (Self::Lt, n)
+        } else {
+            (Self::Eq, s.strip_prefix('=').unwrap_or(s))
+        };
+        let value = num
+            .trim()
+            .parse()
+            .map_err(|_| LazarusError::BadRequest(format!("잘못된 별점 조건: {}", s)))?;
+        Ok(ctor(value))
+    }
+
+    /// 포함 범위 (최소, 최대)
+    pub fn bounds(self) -> (u64, u64) {
+        match self {
+            Self::Eq(v) => (v as u64, v as u64),
+            Self::Gt(v) => (v as u64 + 1, u64::MAX),
+            Self::Gte(v) => (v as u64, u64::MAX),
+            Self::Lt(v) => (1, (v as u64).saturating_sub(1)),
+            Self::Lte(v) => (1, v as u64),
+        }
+    }
+}
+
+/// 파싱된 노트 검색 쿼리
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NoteQuery {
+    /// 필터를 뺀 나머지 검색어 (tantivy 쿼리 문법)
+    pub text: String,
+    pub tags: Vec<String>,
+    pub note_type: Option<NoteType>,
+    /// 이 시각 이후 작성 (Unix timestamp, 포함)
+    pub after: Option<i64>,
+    /// 이 시각 이전 작성 (Unix timestamp, 제외)
+    pub before: Option<i64>,
+    pub rating: Option<RatingFilter>,
+    pub mood: Option<String>,
+    /// 링크 대상 제목 (소문자)
+    pub links_to: Vec<String>,
+    pub has_link: bool,
+    pub has_tag: bool,
+    pub has_rating: bool,
+    pub has_mood: bool,
+    pub encrypted: Option<bool>,
+}
+
+impl NoteQuery {
+    /// 쿼리 문자열 파싱
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut query = Self::default();
+        let mut text = Vec::new();
+
+        for token in tokenize(input) {
+            let Some((key, value)) = token.split_once(':') else {
+                text.push(token);
+                continue;
+            };
+            let value = value.trim_matches('"');
+            if value.is_empty() {
+                text.push(token);
+                continue;
+            }
+            if !query.apply(&key.to_lowercase(), value)? {
+                text.push(token);
+            }
+        }
+
+        query.text = text.join(" ");
+        Ok(query)
+    }
+
+    /// 필터 하나 적용 (알 수 없는 키면 false)
+    pub fn apply(&mut self, key: &str, value: &str) -> Result<bool> {
+        match key {
+            "tag" => self.tags.push(value.to_lowercase()),
+            "type" => self.note_type = Some(parse_note_type(value)?),
+            "after" => self.after = Some(parse_date(value)?),
+            "before" => self.before = Some(parse_date(value)?),
+            "rating" => self.rating = Some(RatingFilter::parse(value)?),
+            "mood" => self.mood = Some(value.to_string()),
+            "links-to" | "links_to" => self.links_to.push(value.to_lowercase()),
+            "has" => match value.to_lowercase().as_str() {
+                "link" | "links" => self.has_link = true,
+                "tag" | "tags" => self.has_tag = true,
+                "rating" => self.has_rating = true,
+                "mood" => self.has_mood = true,
+                other => {
+                    return Err(LazarusError::BadRequest(format!(
+                        "알 수 없는 has: 조건: {}",
+                        other
+                    )))
+                }
+            },
+            "is" => match value.to_lowercase().as_str() {
+                "encrypted" => self.encrypted = Some(true),
+                "plain" => self.encrypted = Some(false),
+                other => {
+                    return Err(LazarusError::BadRequest(format!(
+                        "알 수 없는 is: 조건: {}",
+                        other
+                    )))
+                }
+            },
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// 필터가 하나라도 있는지
+    pub fn has_filters(&self) -> bool {
+        let without_text = Self {
+            text: String::new(),
+            ..self.clone()
+        };
+        without_text != Self::default()
+    }
+
+    /// 검색어도 필터도 없는지
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty() && !self.has_filters()
+    }
+}
+
+/// 공백으로 나누되 따옴표 안의 공백은 유지
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in input.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+fn parse_note_type(value: &str) -> Result<NoteType> {
+    match value.to_lowercase().as_str() {
+        "note" => Ok(NoteType::Note),
+        "journal" => Ok(NoteType::Journal),
+        "review" => Ok(NoteType::Review),
+        "idea" => Ok(NoteType::Idea),
+        other => Err(LazarusError::BadRequest(format!(
+            "알 수 없는 노트 타입: {}",
+            other
+        ))),
+    }
+}
+
+/// `YYYY-MM-DD` → 그날 00:00 UTC 타임스탬프
+fn parse_date(value: &str) -> Result<i64> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|d| d.and_time(NaiveTime::MIN).and_utc().timestamp())
+        .map_err(|_| LazarusError::BadRequest(format!("잘못된 날짜 (YYYY-MM-DD): {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_filters() {
+        let q = NoteQuery::parse(
+            r#"tag:Physics type:journal after:2026-01-01 rating:>=4 links-to:"Isaac Newton" 중력"#,
+        )
+        .unwrap();
+
+        assert_eq!(q.text, "중력");
+        assert_eq!(q.tags, vec!["physics"]);
+        assert_eq!(q.note_type, Some(NoteType::Journal));
+        assert_eq!(q.after, Some(1767225600));
+        assert_eq!(q.rating, Some(RatingFilter::Gte(4)));
+        assert_eq!(q.links_to, vec!["isaac newton"]);
+        assert!(q.has_filters());
+    }
+
+    #[test]
+    fn test_unknown_keys_stay_in_text() {
+        let q = NoteQuery::parse("title:rust has:link http://example.com").unwrap();
+        assert_eq!(q.text, "title:rust http://example.com");
+        assert!(q.has_link);
+
+        let plain = NoteQuery::parse("just words").unwrap();
+        assert!(!plain.has_filters());
+        assert!(NoteQuery::parse("  ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_filters() {
+        assert!(NoteQuery::parse("type:poem").is_err());
+        assert!(NoteQuery::parse("after:yesterday").is_err());
+        assert!(NoteQuery::parse("rating:>=five").is_err());
+    }
+
+    #[test]
+    fn test_rating_bounds() {
+        assert_eq!(RatingFilter::parse("4").unwrap().bounds(), (4, 4));
+        assert_eq!(RatingFilter::parse(">3").unwrap().bounds(), (4, u64::MAX));
+        assert_eq!(RatingFilter::parse("<=2").unwrap().bounds(), (1, 2));
+    }
+}
@@ -0,0 +1,348 @@
+//! ZIM 본문 전문 검색용 사이드카 인덱스
+//!
+//! ZIM에 내장된 Xapian 인덱스는 읽을 수 없으므로, ZIM마다
+//! `data/zims/<name>.idx`에 tantivy 인덱스를 따로 만듭니다.
+//! 본문은 저장하지 않고 (디스크 절약) 스니펫은 검색 시 ZIM에서 다시 읽어 만듭니다.
+//! 진행 상황은 커밋 페이로드에 함께 기록되므로 중단돼도 이어서 빌드할 수 있습니다.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tantivy::{
+    collector::TopDocs,
+    directory::MmapDirectory,
+    doc,
+    query::QueryParser,
+    schema::{Field, NumericOptions, Schema, Value, STORED, STRING, TEXT},
+    snippet::SnippetGenerator,
+    Index, IndexWriter, TantivyDocument, Term,
+};
+
+use crate::error::{LazarusError, Result};
+use crate::zim::{is_searchable_article, ZimReader};
+
+/// 한 번에 인덱싱하는 엔트리 수 (배치마다 커밋)
+pub const INDEX_BATCH_SIZE: u32 = 500;
+
+/// 스니펫 최대 글자 수
+const SNIPPET_MAX_CHARS: usize = 200;
+
+/// 인덱스 진행 상황 (커밋 페이로드로 저장)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ZimIndexProgress {
+    /// 다음에 인덱싱할 URL 인덱스
+    pub next_entry: u32,
+    /// ZIM 전체 엔트리 수
+    pub total: u32,
+    /// 인덱싱된 문서 수
+    pub indexed_docs: u64,
+    /// 완료 여부
+    pub done: bool,
+}
+
+/// 본문 검색 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct ZimTextHit {
+    pub entry: u32,
+    pub url: String,
+    pub title: String,
+    /// `<b>`로 강조된 HTML 스니펫
+    pub snippet: String,
+    pub score: f32,
+}
+
+/// ZIM 사이드카 인덱스
+pub struct ZimFullTextIndex {
+    index: Index,
+    field_entry: Field,
+    field_url: Field,
+    field_title: Field,
+    field_body: Field,
+}
+
+impl ZimFullTextIndex {
+    /// ZIM 이름에 해당하는 인덱스 경로
+    pub fn path_for(zim_dir: &Path, name: &str) -> PathBuf {
+        zim_dir.join(format!("{}.idx", name))
+    }
+
+    /// 인덱스가 존재하는지
+    pub fn exists(path: &Path) -> bool {
+        path.join("meta.json").exists()
+    }
+
+    /// 인덱스 열기 (없으면 생성)
+    pub fn open(path: &Path) -> Result<Self> {
+        std::fs::create_dir_all(path)?;
+
+        let mut schema_builder = Schema::builder();
+        let field_entry = schema_builder.add_u64_field(
+            "entry",
+            NumericOptions::default().set_stored().set_indexed(),
+        );
+        let field_url = schema_builder.add_text_field("url", STRING | STORED);
+        let field_title = schema_builder.add_text_field("title", TEXT | STORED);
+        let field_body = schema_builder.add_text_field("body", TEXT);
+        let schema = schema_builder.build();
+
+        let dir =
+            MmapDirectory::open(path).map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+        let index = Index::open_or_create(dir, schema)
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))?;
+
+        Ok(Self {
+            index,
+            field_entry,
+            field_url,
+            field_title,
+            field_body,
+        })
+    }
+
+    /// 마지막 커밋의 진행 상황
+    pub fn progress(&self) -> Result<ZimIndexProgress> {
+        let metas = self.index.load_metas()?;
+        Ok(metas
+            .payload
+            .and_then(|p| serde_json::from_str(&p).ok())
+            .unwrap_or_default())
+    }
+
+    /// 인덱스 라이터 (단일 스레드, 최소 힙)
+    fn writer(&self) -> Result<IndexWriter> {
+        self.index
+            .writer_with_num_threads(1, 15_000_000)
+            .map_err(|e| LazarusError::IndexCreate(e.to_string()))
+    }
+
+    /// 배치 하나 인덱싱 후 커밋
+    ///
+    /// 다음 진행 상황을 반환합니다. 배치 범위는 커밋 전에 지워서
+    /// 직전 실행이 커밋 직전에 죽었어도 중복 문서가 생기지 않습니다.
+    pub fn index_batch(&self, zim: &ZimReader) -> Result<ZimIndexProgress> {
+        let mut progress = self.progress()?;
+        let total = zim.article_count();
+        progress.total = total;
+
+        if progress.next_entry >= total {
+            progress.done = true;
+            return Ok(progress);
+        }
+
+        let mut writer = self.writer()?;
+        let end = progress
+            .next_entry
+            .saturating_add(INDEX_BATCH_SIZE)
+            .min(total);
+
+        for i in progress.next_entry..end {
+            writer.delete_term(Term::from_field_u64(self.field_entry, i as u64));
+
+            let entry = match zim.read_dir_entry(i) {
+                Ok(e) => e,
+                Err(_) => continue,
+            };
+            // HTML 문서만 인덱싱
+            if !is_searchable_article(&entry) {
+                continue;
+            }
+
+            let body = match zim.read_entry_content(&entry) {
+                Ok(Some(bytes)) => html_to_text(&String::from_utf8_lossy(&bytes)),
+                _ => continue,
+            };
+
+            writer.add_document(doc!(
+                self.field_entry => i as u64,
+                self.field_url => entry.url.as_str(),
+                self.field_title => entry.title.as_str(),
+                self.field_body => body,
+            ))?;
+            progress.indexed_docs += 1;
+        }
+
+        progress.next_entry = end;
+        progress.done = end >= total;
+
+        let mut prepared = writer.prepare_commit()?;
+        prepared.set_payload(&serde_json::to_string(&progress)?);
+        prepared.commit()?;
+        writer.wait_merging_threads()?;
+
+        Ok(progress)
+    }
+
+    /// 본문 검색 (스니펫은 `fetch_text`로 원문을 받아 생성)
+    pub fn search<F>(&self, query_str: &str, limit: usize, fetch_text: F) -> Result<Vec<ZimTextHit>>
+    where
+        F: Fn(u32) -> Option<String>,
+    {
+        if query_str.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let reader = self.index.reader()?;
+        let searcher = reader.searcher();
+
+        let query_parser =
+            QueryParser::for_index(&self.index, vec![self.field_title, self.field_body]);
+        let (query, _errors) = query_parser.parse_query_lenient(query_str);
+
+        let top_docs = searcher.search(&query, &TopDocs::with_limit(limit))?;
+
+        let mut snippet_gen = SnippetGenerator::create(&searcher, &*query, self.field_body)?;
+        snippet_gen.set_max_num_chars(SNIPPET_MAX_CHARS);
+
+        let mut hits = Vec::new();
+        for (score, doc_address) in top_docs {
+            let doc: TantivyDocument = searcher.doc(doc_address)?;
+
+            let entry = doc
+                .get_first(self.field_entry)
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u32;
+            let url = doc
+                .get_first(self.field_url)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            let title = doc
+                .get_first(self.field_title)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+
+            let snippet = fetch_text(entry)
+                .map(|text| snippet_gen.snippet(&text).to_html())
+                .unwrap_or_default();
+
+            hits.push(ZimTextHit {
+                entry,
+                url,
+                title,
+                snippet,
+                score,
+            });
+        }
+
+        Ok(hits)
+    }
+}
+
+/// HTML을 검색용 평문으로 변환
+///
+/// `<script>`/`<style>` 내용은 버리고 태그는 공백으로 바꾸며, 자주 쓰는 엔티티만 풉니다.
+pub fn html_to_text(html: &str) -> String {
+    let mut out = String::with_capacity(html.len() / 2);
+    let lower = html.to_ascii_lowercase();
+    let mut i = 0;
+
+    while i < html.len() {
+        let rest = &html[i..];
+        if rest.starts_with('<') {
+            // script/style 블록 통째로 건너뛰기
+            let skip_to = ["script", "style"].iter().find_map(|tag| {
+                if lower[i + 1..].starts_with(tag) {
+                    lower[i..]
+                        .find(&format!("</{}", tag))
+                        .map(|end| i + end + tag.len() + 2)
+                } else {
+                    None
+                }
+            });
+            let tag_start = skip_to.unwrap_or(i);
+            match html[tag_start..].find('>') {
+                Some(end) => i = tag_start + end + 1,
+                None => break,
+            }
+            out.push(' ');
+        } else if rest.starts_with('&') {
+            let entity_end = rest.find(';').filter(|&e| e <= 8);
+            let decoded = entity_end.and_then(|e| match &rest[..=e] {
+                "&amp;" => Some('&'),
+                "&lt;" => Some('<'),
+                "&gt;" => Some('>'),
+                "&quot;" => Some('"'),
+                "&#39;" | "&apos;" => Some('\''),
+                "&nbsp;" => Some(' '),
+                _ => None,
+            });
+            match (decoded, entity_end) {
+                (Some(c), Some(e)) => {
+                    out.push(c);
+                    i += e + 1;
+                }
+                _ => {
+                    out.push('&');
+                    i += 1;
+                }
+            }
+        } else {
+            let c = rest.chars().next().unwrap();
+            out.push(c);
+            i += c.len_utf8();
+        }
+    }
+
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zim::tests::{build_test_zim, open_test_zim};
+
+    #[test]
+    fn test_html_to_text() {
+        let html = "<html><head><style>p { color: red }</style><script>var x = 1;</script></head>\
+                    <body><h1>Title</h1><p>Fish &amp; chips&nbsp;are <b>tasty</b></p></body></html>";
+        assert_eq!(html_to_text(html), "Title Fish & chips are tasty");
+    }
+
+    #[test]
+    fn test_index_is_resumable_and_searchable() {
+        let entries: &[(char, &str, &str, &[u8])] = &[
+            (
+                'C',
+                "Apple",
+                "Apple",
+                b"<p>A red fruit that grows on trees.</p>",
+            ),
+            (
+                'C',
+                "Photosynthesis",
+                "Photosynthesis",
+                b"<p>Plants turn sunlight into sugar.</p>",
+            ),
+            (
+                'C',
+                "Sun",
+                "Sun",
+                b"<p>The star at the centre of the solar system.</p>",
+            ),
+        ];
+        let (_zim_dir, zim) = open_test_zim(&build_test_zim(entries, 5));
+
+        let idx_dir = tempfile::tempdir().unwrap();
+        let path = ZimFullTextIndex::path_for(idx_dir.path(), "test");
+        {
+            let index = ZimFullTextIndex::open(&path).unwrap();
+            let progress = index.index_batch(&zim).unwrap();
+            assert!(progress.done);
+            assert_eq!(progress.indexed_docs, 3);
+        }
+
+        // 다시 열어도 진행 상황 유지
+        let index = ZimFullTextIndex::open(&path).unwrap();
+        assert!(ZimFullTextIndex::exists(&path));
+        assert!(index.progress().unwrap().done);
+
+        let hits = index
+            .search("sunlight", 10, |_| {
+                Some("Plants turn sunlight into sugar.".to_string())
+            })
+            .unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].title, "Photosynthesis");
+        assert!(hits[0].snippet.contains("<b>sunlight</b>"));
+    }
+}
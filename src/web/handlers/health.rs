@@ -4,21 +4,41 @@ use axum::{extract::State, Json};
 use serde::Serialize;
 
 use crate::web::state::AppState;
+use crate::zim::CacheStats;
 
 #[derive(Serialize)]
 pub struct HealthResponse {
     pub status: &'static str,
     pub version: &'static str,
     pub notes_count: usize,
+    /// WAL 내구성 모드
+    pub durability: String,
+    /// 아직 fsync되지 않은 WAL 바이트
+    pub wal_pending_bytes: u64,
+    /// 시작할 때 손상된 엔트리를 만남 (`/api/db/check` 참고)
+    pub db_needs_repair: bool,
+    /// 마지막 백업(또는 두 번째 대상 복사)이 실패함
+    pub backup_failed: bool,
+    pub zim_cache: CacheStats,
 }
 
 /// GET /health
 pub async fn check(State(state): State<AppState>) -> Json<HealthResponse> {
     let db = state.db.read().await;
+    let backup_failed = state.backup_status.read().await.failed();
 
     Json(HealthResponse {
-        status: "ok",
+        status: if db.needs_repair() || backup_failed {
+            "degraded"
+        } else {
+            "ok"
+        },
         version: state.version,
         notes_count: db.count(),
+        durability: db.durability().to_string(),
+        wal_pending_bytes: db.pending_bytes(),
+        db_needs_repair: db.needs_repair(),
+        backup_failed,
+        zim_cache: state.zim_cache.stats(),
     })
 }
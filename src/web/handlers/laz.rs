@@ -10,6 +10,7 @@ use uuid::Uuid;
 
 use crate::error::{LazarusError, Result};
 use crate::laz::{Chapter, Curriculum, LazPackage, NoteContent};
+use crate::search::embed_note;
 use crate::web::state::AppState;
 
 /// POST /api/laz/export - 노트를 .laz로 내보내기
@@ -127,26 +128,30 @@ pub async fn import_package(
     let mut search = state.search.write().await;
     let mut imported_count = 0;
 
-    for (_, note_content) in package.content {
-        let note = crate::db::Note {
-            id: 0, // 새 ID 할당됨
-            title: note_content.title,
-            content: note_content.content,
-            tags: note_content.tags,
-            created_at: chrono::DateTime::from_timestamp(note_content.created_at, 0)
-                .unwrap_or_else(chrono::Utc::now),
-            updated_at: chrono::DateTime::from_timestamp(note_content.updated_at, 0)
-                .unwrap_or_else(chrono::Utc::now),
-            encrypted: false,
-            note_type: crate::db::note::NoteType::default(),
-            rating: None,
-            mood: None,
-        };
-
-        let id = db.save(&note, None)?;
-        search.index_note(id, &note.title, &note.content, &note.tags)?;
-        imported_count += 1;
-    }
+    // 노트마다 fsync하지 않고 한 번에 내림
+    db.batch(|db| {
+        for (_, note_content) in package.content {
+            let mut note = crate::db::Note {
+                id: 0, // 새 ID 할당됨
+                title: note_content.title,
+                content: note_content.content,
+                tags: note_content.tags,
+                created_at: chrono::DateTime::from_timestamp(note_content.created_at, 0)
+                    .unwrap_or_else(chrono::Utc::now),
+                updated_at: chrono::DateTime::from_timestamp(note_content.updated_at, 0)
+                    .unwrap_or_else(chrono::Utc::now),
+                encrypted: false,
+                note_type: crate::db::note::NoteType::default(),
+                rating: None,
+                mood: None,
+            };
+
+            note.id = db.save(&note, embed_note(&note))?;
+            search.index_note(&note)?;
+            imported_count += 1;
+        }
+        Ok(())
+    })?;
 
     tracing::info!(
         "패키지 가져오기 완료: {} (노트 {}개, SRS 카드 {}개)",
@@ -2,15 +2,23 @@
 use axum::response::{IntoResponse, Redirect};
 use axum::Form;
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Json,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::db::CompactResult;
+use crate::crypto::CryptoManager;
+use crate::db::history::diff_lines;
+use crate::db::repair::{self, RepairReport};
+use crate::db::snapshot::BackupSnapshot;
 use crate::db::Note;
+use crate::db::StorageEngine;
+use crate::db::{BackupRetention, BackupSchedule, BackupStatus};
+use crate::db::{CompactResult, DiffLine, RetentionPolicy};
 use crate::error::{LazarusError, Result};
+use crate::links::LinkIndex;
+use crate::search::embed_note;
 use crate::web::state::AppState;
 
 /// 노트 응답
@@ -23,6 +31,8 @@ pub struct NoteResponse {
     pub updated_at: String,
     pub tags: Vec<String>,
     pub note_type: String,
+    pub rating: Option<u8>,
+    pub mood: Option<String>,
 }
 
 impl From<Note> for NoteResponse {
@@ -35,6 +45,8 @@ impl From<Note> for NoteResponse {
             updated_at: note.updated_at.to_rfc3339(),
             tags: note.tags,
             note_type: format!("{:?}", note.note_type).to_lowercase(),
+            rating: note.rating,
+            mood: note.mood,
         }
     }
 }
@@ -72,10 +84,12 @@ pub async fn create(
 ) -> Result<(StatusCode, Json<NoteResponse>)> {
     let mut note = Note::new(0, req.title, req.content);
     note.tags = req.tags;
+    note.rating = req.rating.filter(|r| (1..=5).contains(r));
+    note.mood = parse_mood(req.mood.as_deref());
 
-    let mut db = state.db.write().await;
-    let id = db.save(&note, None)?;
+    let id = state.db.write().await.save(&note, embed_note(&note))?;
     note.id = id;
+    state.index_note(&note).await?;
 
     tracing::info!("노트 생성: id={}, title={}", id, note.title);
 
@@ -105,6 +119,10 @@ pub async fn create_form(
     // 암호화 여부
     note.encrypted = req.encrypted == "true";
 
+    // 별점 / 기분
+    note.rating = parse_rating(req.rating.as_deref());
+    note.mood = parse_mood(req.mood.as_deref());
+
     // 노트 타입
     if let Some(ref t) = req.note_type {
         note.note_type = match t.as_str() {
@@ -119,17 +137,15 @@ pub async fn create_form(
         if note.encrypted {
             // 암호화된 노트 저장
             let crypto = state.crypto.read().await;
-            db.save_encrypted(&note, None, crypto.as_ref())?
+            db.save_encrypted(&note, embed_note(&note), crypto.as_ref())?
         } else {
-            db.save(&note, None)?
+            db.save(&note, embed_note(&note))?
         }
     };
 
     // 검색 인덱스에 추가
-    {
-        let mut search = state.search.write().await;
-        search.index_note(id, &note.title, &note.content, &note.tags)?;
-    }
+    note.id = id;
+    state.index_note(&note).await?;
     //링크 인덱스 업데이트
     {
         let mut index = state.link_index.write().await;
@@ -172,8 +188,19 @@ pub async fn update(
     let mut note = Note::new(id, req.title, req.content);
     note.tags = req.tags;
     note.created_at = existing.created_at;
+    note.note_type = existing.note_type;
+    note.rating = match req.rating {
+        Some(r) => Some(r).filter(|r| (1..=5).contains(r)),
+        None => existing.rating,
+    };
+    note.mood = match req.mood {
+        Some(ref m) => parse_mood(Some(m)),
+        None => existing.mood,
+    };
 
-    db.save(&note, None)?;
+    db.save(&note, embed_note(&note))?;
+    drop(db);
+    state.index_note(&note).await?;
 
     tracing::info!("노트 수정: id={}", id);
 
@@ -199,9 +226,10 @@ pub async fn update_form(
 
     let note = {
         let mut db = state.db.write().await;
+        let crypto = state.crypto.read().await;
         // 기존 노트 확인
         let existing = db
-            .get(id)?
+            .get_decrypted(id, crypto.as_ref())?
             .ok_or_else(|| LazarusError::NotFound(format!("노트 ID: {}", id)))?;
 
         let mut note = Note::new(id, req.title, req.content);
@@ -209,6 +237,16 @@ pub async fn update_form(
         note.created_at = existing.created_at;
         note.encrypted = encrypted;
 
+        // 별점 / 기분 (폼에 없으면 기존 값 유지)
+        note.rating = match req.rating {
+            Some(ref r) => parse_rating(Some(r)),
+            None => existing.rating,
+        };
+        note.mood = match req.mood {
+            Some(ref m) => parse_mood(Some(m)),
+            None => existing.mood,
+        };
+
         // 노트 타입
         if let Some(ref t) = req.note_type {
             note.note_type = match t.as_str() {
@@ -222,19 +260,15 @@ pub async fn update_form(
             note.note_type = existing.note_type;
         }
         if encrypted {
-            let crypto = state.crypto.read().await;
-            db.save_encrypted(&note, None, crypto.as_ref())?;
+            db.save_encrypted(&note, embed_note(&note), crypto.as_ref())?;
         } else {
-            db.save(&note, None)?;
+            db.save(&note, embed_note(&note))?;
         }
         note
     };
 
     // 검색 인덱스 업데이트
-    {
-        let mut search = state.search.write().await;
-        search.index_note(id, &note.title, &note.content, &note.tags)?;
-    }
+    state.index_note(&note).await?;
 
     tracing::info!("노트 수정 (Form): id={}, encrypted={}", id, note.encrypted);
     Ok(axum::response::Redirect::to(&format!("/notes/{}", id)))
@@ -246,10 +280,7 @@ pub async fn delete(
     Path(id): Path<u64>,
 ) -> Result<impl axum::response::IntoResponse> {
     // 검색 인덱스에서 삭제
-    {
-        let mut search = state.search.write().await;
-        let _ = search.delete_note(id);
-    }
+    let _ = state.unindex_note(id).await;
 
     // DB에서 삭제
     let mut db = state.db.write().await;
@@ -274,6 +305,11 @@ pub struct CreateNoteRequest {
     pub encrypted: bool,
     #[serde(default)]
     pub note_type: Option<String>,
+    /// 별점 (1-5)
+    #[serde(default)]
+    pub rating: Option<u8>,
+    #[serde(default)]
+    pub mood: Option<String>,
 }
 
 /// 노트 생성 요청 (Form)
@@ -287,6 +323,23 @@ pub struct CreateNoteFormRequest {
     pub encrypted: String, // "true" 또는 "false"
     #[serde(default)]
     pub note_type: Option<String>,
+    #[serde(default)]
+    pub rating: Option<String>, // "1"~"5" 또는 ""
+    #[serde(default)]
+    pub mood: Option<String>,
+}
+
+/// 별점 파싱 (1-5 밖이거나 비어 있으면 없음)
+fn parse_rating(value: Option<&str>) -> Option<u8> {
+    value
+        .and_then(|v| v.trim().parse().ok())
+        .filter(|r| (1..=5).contains(r))
+}
+
+fn parse_mood(value: Option<&str>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
 }
 
 /// GET /api/notes/duplicates - 중복 노트 찾기
@@ -360,15 +413,18 @@ pub async fn remove_duplicates(
     let mut search = state.search.write().await;
     let mut removed_count = 0;
 
-    for group in params.groups {
-        // 첫 번째(최신)는 유지, 나머지 삭제
-        for note_id in group.note_ids.iter().skip(1) {
-            if db.delete(*note_id)? {
-                search.delete_note(*note_id)?;
-                removed_count += 1;
+    db.batch(|db| {
+        for group in &params.groups {
+            // 첫 번째(최신)는 유지, 나머지 삭제
+            for note_id in group.note_ids.iter().skip(1) {
+                if db.delete(*note_id)? {
+                    search.delete_note(*note_id)?;
+                    removed_count += 1;
+                }
             }
         }
-    }
+        Ok(())
+    })?;
 
     Ok(Json(RemoveResult {
         success: true,
@@ -414,12 +470,7 @@ pub struct RemoveResult {
 pub async fn backup_info(State(state): State<AppState>) -> Result<Json<BackupInfoResponse>> {
     let backup = state.backup.read().await;
     let info = backup.info()?;
-    let total_size: u64 = info
-        .backups
-        .iter()
-        .filter_map(|p| std::fs::metadata(p).ok())
-        .map(|m| m.len())
-        .sum();
+    let total_size = backup.stored_bytes()?;
 
     Ok(Json(BackupInfoResponse {
         count: info.backups.len(),
@@ -436,8 +487,7 @@ pub async fn backup_info(State(state): State<AppState>) -> Result<Json<BackupInf
 
 /// POST /api/backup/now - 수동 백업
 pub async fn backup_now(State(state): State<AppState>) -> Result<Json<BackupResult>> {
-    let backup = state.backup.read().await;
-    match backup.backup()? {
+    match state.run_backup().await? {
         Some(path) => Ok(Json(BackupResult {
             success: true,
             message: format!("백업 완료: {}", path.display()),
@@ -467,20 +517,534 @@ pub struct BackupResult {
     pub path: Option<String>,
 }
 
+/// 백업 목록 항목
+#[derive(Serialize)]
+pub struct BackupEntryResponse {
+    pub name: String,
+    pub size_kb: u64,
+    pub created_at: String,
+    pub encrypted: bool,
+}
+
+/// GET /api/backup/list - 백업 목록 (최신순)
+pub async fn backup_list(State(state): State<AppState>) -> Result<Json<Vec<BackupEntryResponse>>> {
+    let backup = state.backup.read().await;
+    let entries = backup
+        .entries()?
+        .into_iter()
+        .map(|e| BackupEntryResponse {
+            name: e.name,
+            size_kb: e.size / 1024,
+            created_at: e.created_at.to_rfc3339(),
+            encrypted: e.encrypted,
+        })
+        .collect();
+    Ok(Json(entries))
+}
+
+/// GET /api/backup/retention - 백업 보존 정책
+pub async fn backup_retention(State(state): State<AppState>) -> Json<BackupRetention> {
+    Json(state.backup.read().await.retention())
+}
+
+/// PUT /api/backup/retention - 백업 보존 정책 변경 (바로 정리)
+pub async fn set_backup_retention(
+    State(state): State<AppState>,
+    Json(retention): Json<BackupRetention>,
+) -> Result<Json<BackupRetention>> {
+    let retention = BackupRetention {
+        keep_last: retention.keep_last.max(1),
+        ..retention
+    };
+    state.backup.write().await.set_retention(retention)?;
+    tracing::info!("백업 보존 정책 변경: {:?}", retention);
+    Ok(Json(retention))
+}
+
+/// 자동 백업 설정과 마지막 결과
+#[derive(Serialize)]
+pub struct BackupScheduleResponse {
+    pub schedule: BackupSchedule,
+    pub status: BackupStatus,
+}
+
+/// GET /api/backup/schedule - 자동 백업 설정/상태
+pub async fn backup_schedule(State(state): State<AppState>) -> Json<BackupScheduleResponse> {
+    let schedule = state.backup.read().await.schedule().clone();
+    let status = state.backup_status.read().await.clone();
+    Json(BackupScheduleResponse { schedule, status })
+}
+
+/// 자동 백업 설정 폼
+#[derive(Deserialize)]
+pub struct BackupScheduleForm {
+    /// `off`, `30m`, `6h`, `1d`, `daily 03:00`
+    pub interval: String,
+    /// 비우면 두 번째 대상 없음
+    #[serde(default)]
+    pub mirror_dir: String,
+}
+
+/// POST /api/backup/schedule - 자동 백업 설정 변경 (설정 화면으로 돌아감)
+pub async fn set_backup_schedule(
+    State(state): State<AppState>,
+    Form(form): Form<BackupScheduleForm>,
+) -> Result<Redirect> {
+    let interval = form.interval.parse().map_err(LazarusError::BadRequest)?;
+    let mirror_dir = form.mirror_dir.trim();
+    let schedule = BackupSchedule {
+        interval,
+        mirror_dir: (!mirror_dir.is_empty()).then(|| std::path::PathBuf::from(mirror_dir)),
+    };
+    state.set_backup_schedule(schedule).await?;
+    Ok(Redirect::to("/settings"))
+}
+
+/// 백업 검증 결과
+#[derive(Serialize)]
+pub struct BackupVerifyResponse {
+    pub name: String,
+    pub ok: bool,
+    /// 원본 크기 (검증 성공 시)
+    pub size: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// GET /api/backup/:name/verify - 백업 청크/해시 검증
+pub async fn backup_verify(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<BackupVerifyResponse>> {
+    let backup = state.backup.read().await;
+    let path = backup.find(&name)?;
+    let response = match backup.verify(&path) {
+        Ok(size) => BackupVerifyResponse {
+            name,
+            ok: true,
+            size: Some(size),
+            error: None,
+        },
+        Err(e) => {
+            tracing::warn!("백업 검증 실패: {} - {}", name, e);
+            BackupVerifyResponse {
+                name,
+                ok: false,
+                size: None,
+                error: Some(e.to_string()),
+            }
+        }
+    };
+    Ok(Json(response))
+}
+
+/// 백업 스냅샷 열기 (백업 암호화 키 사용)
+async fn open_backup(state: &AppState, name: &str) -> Result<BackupSnapshot> {
+    let backup = state.backup.read().await;
+    let path = backup.find(name)?;
+    backup.open_snapshot(&path)
+}
+
+/// 백업 노트 검색 파라미터
+#[derive(Deserialize)]
+pub struct BackupNotesParams {
+    pub q: Option<String>,
+}
+
+/// 백업 안의 노트 요약
+#[derive(Serialize)]
+pub struct BackupNoteSummary {
+    pub id: u64,
+    pub title: String,
+    pub updated_at: String,
+    pub tags: Vec<String>,
+    pub encrypted: bool,
+    /// 현재 DB와 비교: "same", "changed", "missing"
+    pub live: &'static str,
+}
+
+/// GET /api/backup/:name/notes - 백업 안의 노트 목록/검색 (읽기 전용)
+pub async fn backup_notes(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Query(params): Query<BackupNotesParams>,
+) -> Result<Json<Vec<BackupNoteSummary>>> {
+    let snapshot = open_backup(&state, &name).await?;
+    let db = state.db.read().await;
+    let crypto = state.crypto.read().await;
+
+    let notes = snapshot.search(params.q.as_deref().unwrap_or(""), crypto.as_ref())?;
+    let mut summaries = Vec::with_capacity(notes.len());
+    for note in notes {
+        let live = match db.get_decrypted(note.id, crypto.as_ref())? {
+            Some(current) if current.to_markdown() == note.to_markdown() => "same",
+            Some(_) => "changed",
+            None => "missing",
+        };
+        summaries.push(BackupNoteSummary {
+            id: note.id,
+            title: note.title,
+            updated_at: note.updated_at.to_rfc3339(),
+            tags: note.tags,
+            encrypted: note.encrypted,
+            live,
+        });
+    }
+    Ok(Json(summaries))
+}
+
+/// GET /api/backup/:name/notes/:id - 백업 안의 노트 하나
+pub async fn backup_note(
+    State(state): State<AppState>,
+    Path((name, id)): Path<(String, u64)>,
+) -> Result<Json<NoteResponse>> {
+    let snapshot = open_backup(&state, &name).await?;
+    let crypto = state.crypto.read().await;
+
+    let note = snapshot
+        .get(id, crypto.as_ref())?
+        .ok_or_else(|| LazarusError::NotFound(format!("백업 {}의 노트 ID: {}", name, id)))?;
+    if note.encrypted && crypto.is_none() {
+        return Err(LazarusError::Decryption);
+    }
+    Ok(Json(NoteResponse::from(note)))
+}
+
+/// POST /api/backup/:name/restore_all - 데이터 디렉토리 전체를 백업 시점으로 복원
+///
+/// 노트뿐 아니라 SRS, 게시판, Q&A, 보안 설정, 패키지도 함께 되돌립니다.
+pub async fn backup_restore_all(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<BackupResult>> {
+    state.restore_backup(&name).await?;
+    Ok(Json(BackupResult {
+        success: true,
+        message: format!("복원 완료: {}", name),
+        path: None,
+    }))
+}
+
+/// 선택 복원 요청
+#[derive(Deserialize)]
+pub struct BackupRestoreRequest {
+    pub ids: Vec<u64>,
+}
+
+/// 선택 복원 결과
+#[derive(Serialize)]
+pub struct BackupRestoreResult {
+    pub restored: Vec<u64>,
+    /// 백업에 없는 ID
+    pub missing: Vec<u64>,
+}
+
+/// POST /api/backup/:name/restore - 백업에서 고른 노트만 현재 DB로 복원
+///
+/// 덮어쓴 노트도 새 리비전으로 저장되므로 히스토리에서 되돌릴 수 있습니다.
+pub async fn backup_restore_notes(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    Json(request): Json<BackupRestoreRequest>,
+) -> Result<Json<BackupRestoreResult>> {
+    let snapshot = open_backup(&state, &name).await?;
+
+    let (notes, missing) = {
+        let mut db = state.db.write().await;
+        let crypto = state.crypto.read().await;
+
+        let mut notes = Vec::new();
+        let mut missing = Vec::new();
+        for &id in &request.ids {
+            match snapshot.get(id, crypto.as_ref())? {
+                Some(note) if note.encrypted && crypto.is_none() => {
+                    return Err(LazarusError::Decryption);
+                }
+                Some(mut note) => {
+                    note.updated_at = chrono::Utc::now();
+                    notes.push(note);
+                }
+                None => missing.push(id),
+            }
+        }
+
+        db.batch(|db| {
+            for note in &notes {
+                if note.encrypted {
+                    db.save_encrypted(note, embed_note(note), crypto.as_ref())?;
+                } else {
+                    db.save(note, embed_note(note))?;
+                }
+            }
+            Ok(())
+        })?;
+        (notes, missing)
+    };
+
+    // 검색/링크 인덱스 업데이트
+    for note in &notes {
+        state.index_note(note).await?;
+    }
+    {
+        let mut index = state.link_index.write().await;
+        for note in &notes {
+            index.register_note(note.id, &note.title);
+            index.update_links(note.id, &note.content);
+        }
+    }
+
+    tracing::info!("백업 {}에서 노트 {}개 복원", name, notes.len());
+
+    Ok(Json(BackupRestoreResult {
+        restored: notes.iter().map(|n| n.id).collect(),
+        missing,
+    }))
+}
+
+/// 압축 파라미터 (없으면 기본 보존 정책)
+#[derive(Deserialize)]
+pub struct CompactParams {
+    pub keep_last: Option<usize>,
+    pub keep_days: Option<i64>,
+}
+
 /// POST /api/db/compact - DB 압축
-pub async fn compact_db(State(state): State<AppState>) -> Result<Json<CompactResult>> {
+pub async fn compact_db(
+    State(state): State<AppState>,
+    Query(params): Query<CompactParams>,
+) -> Result<Json<CompactResult>> {
     // 먼저 백업
-    let backup = state.backup.read().await;
-    if let Err(e) = backup.backup() {
+    if let Err(e) = state.backup_now().await {
         tracing::warn!("압축 전 백업 실패: {}", e);
     }
 
+    let default = RetentionPolicy::default();
+    let policy = RetentionPolicy {
+        keep_last: params.keep_last.unwrap_or(default.keep_last).max(1),
+        keep_days: params.keep_days.unwrap_or(default.keep_days).max(0),
+    };
+
     let mut db = state.db.write().await;
-    let result = db.compact()?;
+    let result = db.compact(&policy)?;
 
     Ok(Json(result))
 }
 
+/// DB 검사 응답
+#[derive(Serialize)]
+pub struct DbCheckResponse {
+    /// 시작할 때 손상을 만났는지
+    pub needs_repair: bool,
+    #[serde(flatten)]
+    pub report: RepairReport,
+}
+
+/// GET /api/db/check - 데이터 파일 손상 검사 (읽기 전용)
+pub async fn check_db(State(state): State<AppState>) -> Result<Json<DbCheckResponse>> {
+    let db = state.db.read().await;
+    let report = repair::check(db.path(), db.hidden())?;
+
+    Ok(Json(DbCheckResponse {
+        needs_repair: db.needs_repair() || !report.is_clean(),
+        report,
+    }))
+}
+
+/// POST /api/db/repair - 손상 구간을 건너뛰고 살린 노트로 새 파일 작성
+///
+/// 원본은 `notes.lazarus.<시각>.corrupt`로 남기고 엔진을 다시 엽니다.
+/// 버린 엔트리가 검색/링크에 남지 않도록 인덱스도 모두 다시 빌드합니다.
+pub async fn repair_db(State(state): State<AppState>) -> Result<Json<DbCheckResponse>> {
+    let report = {
+        let mut db = state.db.write().await;
+        db.flush()?;
+
+        let report = repair::repair(db.path(), db.hidden())?;
+        if report.repaired {
+            let durability = db.durability();
+            let mut reopened = StorageEngine::open(db.path())?;
+            reopened.set_durability(durability)?;
+            *db = reopened;
+        }
+        report
+    };
+
+    if report.repaired {
+        // 새 엔진에 암호화 노트 인덱스(숨김 포함)를 다시 올린 뒤 링크/검색 구성
+        state.build_encrypted_index().await?;
+        *state.link_index.write().await = LinkIndex::new();
+        state.build_link_index().await?;
+        state.build_note_vectors().await;
+        state.rebuild_search_index().await?;
+    }
+
+    let needs_repair = state.db.read().await.needs_repair();
+    Ok(Json(DbCheckResponse {
+        needs_repair,
+        report,
+    }))
+}
+
+/// 리비전 응답
+#[derive(Serialize)]
+pub struct RevisionResponse {
+    pub rev: usize,
+    pub title: String,
+    pub updated_at: String,
+    pub size: usize,
+    pub encrypted: bool,
+    pub current: bool,
+}
+
+/// 리비전 로드 (없으면 404, 잠긴 암호화 리비전이면 401)
+pub(crate) fn load_revision(
+    db: &StorageEngine,
+    id: u64,
+    rev: usize,
+    crypto: Option<&CryptoManager>,
+) -> Result<Note> {
+    let note = db
+        .get_revision(id, rev, crypto)?
+        .ok_or_else(|| LazarusError::NotFound(format!("노트 ID: {}, 리비전: {}", id, rev)))?;
+    if note.encrypted && crypto.is_none() {
+        return Err(LazarusError::Decryption);
+    }
+    Ok(note)
+}
+
+/// GET /api/notes/:id/history - 리비전 목록 (최신순)
+pub async fn history(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+) -> Result<Json<Vec<RevisionResponse>>> {
+    let db = state.db.read().await;
+    let crypto = state.crypto.read().await;
+
+    let revisions = db.history_notes(id, crypto.as_ref())?;
+    if revisions.is_empty() {
+        return Err(LazarusError::NotFound(format!("노트 ID: {}", id)));
+    }
+
+    let latest = revisions.last().map(|(r, _)| r.rev).unwrap_or(0);
+    let mut response = Vec::with_capacity(revisions.len());
+    for (revision, note) in revisions.into_iter().rev() {
+        let title = note.map(|n| n.title).unwrap_or_default();
+        response.push(RevisionResponse {
+            rev: revision.rev,
+            title,
+            updated_at: chrono::DateTime::from_timestamp(revision.updated_at, 0)
+                .unwrap_or_default()
+                .to_rfc3339(),
+            size: revision.size,
+            encrypted: revision.encrypted,
+            current: revision.rev == latest,
+        });
+    }
+
+    Ok(Json(response))
+}
+
+/// GET /api/notes/:id/history/:rev - 특정 리비전
+pub async fn get_revision(
+    State(state): State<AppState>,
+    Path((id, rev)): Path<(u64, usize)>,
+) -> Result<Json<NoteResponse>> {
+    let db = state.db.read().await;
+    let crypto = state.crypto.read().await;
+    let note = load_revision(&db, id, rev, crypto.as_ref())?;
+    Ok(Json(NoteResponse::from(note)))
+}
+
+#[derive(Deserialize)]
+pub struct DiffParams {
+    pub from: usize,
+    /// 없으면 최신 리비전
+    pub to: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct DiffResponse {
+    pub from: usize,
+    pub to: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// GET /api/notes/:id/diff?from=&to= - 두 리비전 비교 (제목, 태그 포함)
+pub async fn diff(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+    Query(params): Query<DiffParams>,
+) -> Result<Json<DiffResponse>> {
+    let db = state.db.read().await;
+    let crypto = state.crypto.read().await;
+
+    let to = params.to.unwrap_or_else(|| {
+        db.history(id)
+            .ok()
+            .and_then(|h| h.last().map(|r| r.rev))
+            .unwrap_or(0)
+    });
+    let old = load_revision(&db, id, params.from, crypto.as_ref())?;
+    let new = load_revision(&db, id, to, crypto.as_ref())?;
+
+    Ok(Json(DiffResponse {
+        from: params.from,
+        to,
+        lines: diff_lines(&revision_text(&old), &revision_text(&new)),
+    }))
+}
+
+/// diff용 텍스트 (제목, 태그, 본문)
+pub(crate) fn revision_text(note: &Note) -> String {
+    format!(
+        "# {}\n[{}]\n\n{}",
+        note.title,
+        note.tags.join(", "),
+        note.content
+    )
+}
+
+/// POST /api/notes/:id/history/:rev/restore - 리비전 복원 (새 리비전으로 저장)
+pub async fn restore_revision(
+    State(state): State<AppState>,
+    Path((id, rev)): Path<(u64, usize)>,
+) -> Result<impl IntoResponse> {
+    let note = {
+        let mut db = state.db.write().await;
+        let crypto = state.crypto.read().await;
+
+        let current = db
+            .get_decrypted(id, crypto.as_ref())?
+            .ok_or_else(|| LazarusError::NotFound(format!("노트 ID: {}", id)))?;
+        let mut note = load_revision(&db, id, rev, crypto.as_ref())?;
+        note.created_at = current.created_at;
+        note.updated_at = chrono::Utc::now();
+
+        if note.encrypted {
+            db.save_encrypted(&note, embed_note(&note), crypto.as_ref())?;
+        } else {
+            db.save(&note, embed_note(&note))?;
+        }
+        note
+    };
+
+    // 검색/링크 인덱스 업데이트
+    state.index_note(&note).await?;
+    {
+        let mut index = state.link_index.write().await;
+        index.register_note(id, &note.title);
+        index.update_links(id, &note.content);
+    }
+
+    tracing::info!("노트 복원: id={}, rev={}", id, rev);
+
+    let location = format!("/notes/{}", id);
+    Ok((
+        StatusCode::OK,
+        [("HX-Redirect", location)],
+        Json(NoteResponse::from(note)),
+    ))
+}
+
 /// POST /api/notes/:id/lock - 편집 락 획득
 pub async fn acquire_lock(
     State(state): State<AppState>,
@@ -583,3 +1147,260 @@ pub async fn get_by_title(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_encrypted_note_survives_compact() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let crypto = CryptoManager::from_pin("1234", &CryptoManager::generate_salt()).unwrap();
+        *state.crypto.write().await = Some(crypto.clone());
+
+        let request = |content: &str| CreateNoteFormRequest {
+            title: "비밀 일기".to_string(),
+            content: content.to_string(),
+            tags: String::new(),
+            encrypted: "true".to_string(),
+            note_type: Some("journal".to_string()),
+            rating: None,
+            mood: Some("😊".to_string()),
+        };
+        let _ = create_form(State(state.clone()), Form(request("첫 버전")))
+            .await
+            .unwrap();
+        let id = state.db.read().await.list_ids()[0];
+        let _ = update_form(State(state.clone()), Path(id), Form(request("둘째 버전")))
+            .await
+            .unwrap();
+
+        let params = CompactParams {
+            keep_last: Some(1),
+            keep_days: Some(0),
+        };
+        let result = compact_db(State(state.clone()), Query(params))
+            .await
+            .unwrap();
+        assert_eq!(result.0.revisions_dropped, 1);
+
+        let db = state.db.read().await;
+        let note = db.get_decrypted(id, Some(&crypto)).unwrap().unwrap();
+        assert!(note.encrypted);
+        assert_eq!(note.content, "둘째 버전");
+        assert_eq!(note.mood.as_deref(), Some("😊"));
+
+        // 키 없이는 여전히 내용이 보이지 않음
+        let locked = db.get(id).unwrap().unwrap();
+        assert_ne!(locked.content, "둘째 버전");
+    }
+
+    #[tokio::test]
+    async fn test_compact_over_http() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::Service;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let id = {
+            let mut db = state.db.write().await;
+            let mut note = Note::new(0, "장보기".to_string(), "우유".to_string());
+            note.id = db.save(&note, None).unwrap();
+            note.content = "우유, 빵".to_string();
+            db.save(&note, None).unwrap();
+            note.id
+        };
+
+        let request = Request::post("/api/db/compact?keep_last=1&keep_days=0")
+            .body(Body::empty())
+            .unwrap();
+        // Router는 항상 준비 상태라 poll_ready 없이 바로 호출
+        let response = crate::web::router::create_router(state.clone())
+            .call(request)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result["revisions_dropped"], 1);
+        assert_eq!(result["revisions_kept"], 1);
+
+        let db = state.db.read().await;
+        assert_eq!(db.history(id).unwrap().len(), 1);
+        assert_eq!(db.get(id).unwrap().unwrap().content, "우유, 빵");
+        // 압축 전에 백업을 남김
+        drop(db);
+        assert!(!state.backup.read().await.list_backups().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_selected_notes_from_backup() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+
+        let (a, b) = {
+            let mut db = state.db.write().await;
+            let a = db
+                .save(&Note::new(0, "A".to_string(), "원본 A".to_string()), None)
+                .unwrap();
+            let b = db
+                .save(&Note::new(0, "B".to_string(), "원본 B".to_string()), None)
+                .unwrap();
+            (a, b)
+        };
+        let name = {
+            let path = state.backup_now().await.unwrap().unwrap();
+            path.file_name().unwrap().to_str().unwrap().to_string()
+        };
+
+        {
+            let mut db = state.db.write().await;
+            let mut note = db.get(a).unwrap().unwrap();
+            note.content = "망가진 A".to_string();
+            db.save(&note, None).unwrap();
+            db.delete(b).unwrap();
+        }
+
+        let listed = backup_notes(
+            State(state.clone()),
+            Path(name.clone()),
+            Query(BackupNotesParams { q: None }),
+        )
+        .await
+        .unwrap();
+        let live: Vec<&str> = listed.0.iter().map(|n| n.live).collect();
+        assert_eq!(live, vec!["changed", "missing"]);
+
+        let result = backup_restore_notes(
+            State(state.clone()),
+            Path(name),
+            Json(BackupRestoreRequest { ids: vec![b, 99] }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.0.restored, vec![b]);
+        assert_eq!(result.0.missing, vec![99]);
+
+        let db = state.db.read().await;
+        assert_eq!(db.get(a).unwrap().unwrap().content, "망가진 A");
+        assert_eq!(db.get(b).unwrap().unwrap().content, "원본 B");
+    }
+
+    #[tokio::test]
+    async fn test_restore_all_puts_every_store_back() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+
+        let a = state
+            .db
+            .write()
+            .await
+            .save(
+                &Note::new(0, "A".to_string(), "그때의 노트".to_string()),
+                None,
+            )
+            .unwrap();
+        state
+            .posts
+            .write()
+            .await
+            .save(crate::db::Post::new(
+                "me".to_string(),
+                "첫 글".to_string(),
+                String::new(),
+            ))
+            .unwrap();
+        let name = {
+            let path = state.backup_now().await.unwrap().unwrap();
+            path.file_name().unwrap().to_str().unwrap().to_string()
+        };
+
+        // 나중에 바뀐 내용
+        tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+        let b = {
+            let mut db = state.db.write().await;
+            db.delete(a).unwrap();
+            db.save(&Note::new(0, "B".to_string(), "새 노트".to_string()), None)
+                .unwrap()
+        };
+        state
+            .posts
+            .write()
+            .await
+            .save(crate::db::Post::new(
+                "me".to_string(),
+                "둘째 글".to_string(),
+                String::new(),
+            ))
+            .unwrap();
+
+        let _ = backup_restore_all(State(state.clone()), Path(name))
+            .await
+            .unwrap();
+
+        let db = state.db.read().await;
+        assert_eq!(db.get(a).unwrap().unwrap().content, "그때의 노트");
+        assert!(db.get(b).unwrap().is_none());
+        assert_eq!(state.posts.read().await.count(), 1);
+        assert_eq!(state.search.read().await.num_docs(), 1);
+
+        // 복원 전 상태도 백업으로 남음
+        assert!(state.backup.read().await.entries().unwrap().len() >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_backup_reports_status() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let usb = tmp.path().join("usb");
+
+        let form = BackupScheduleForm {
+            interval: "30m".to_string(),
+            mirror_dir: usb.display().to_string(),
+        };
+        let _ = set_backup_schedule(State(state.clone()), Form(form))
+            .await
+            .unwrap();
+        let bad = BackupScheduleForm {
+            interval: "매일".to_string(),
+            mirror_dir: String::new(),
+        };
+        assert!(set_backup_schedule(State(state.clone()), Form(bad))
+            .await
+            .is_err());
+
+        // 아직 예정 시각 전
+        let last = state.backup_status.read().await.last_run.unwrap();
+        assert!(!state.run_backup_if_due(last).await);
+
+        // 변경 없음 → 건너뜀, USB가 없으므로 복사 실패 보고
+        let later = last + chrono::Duration::minutes(31);
+        assert!(state.run_backup_if_due(later).await);
+        let status = backup_schedule(State(state.clone())).await.0.status;
+        assert!(status.skipped);
+        assert!(status.error.is_none());
+        assert!(status.mirror_error.is_some());
+        assert!(status.failed());
+
+        // USB를 꽂으면 다음 실행에서 복구
+        std::fs::create_dir_all(&usb).unwrap();
+        state.run_backup().await.unwrap();
+        assert!(!state.backup_status.read().await.failed());
+        assert!(std::fs::read_dir(&usb).unwrap().count() > 0);
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! Askama 템플릿을 사용한 서버사이드 렌더링
 
+use crate::db::history::diff_lines;
+use crate::db::{DiffLine, DiffOp};
 use crate::error::{LazarusError, Result};
 use crate::i18n::all_translations;
 use crate::links;
+use crate::web::handlers::notes;
 use crate::web::state::AppState;
 
 use ammonia::clean;
@@ -152,6 +155,14 @@ struct SettingsTemplate {
     version: &'static str,
     lang: &'static str,
     t: HashMap<String, String>,
+    backup_interval: String,
+    backup_mirror_dir: String,
+    /// 현지 시각, 없으면 빈 문자열
+    backup_last_run: String,
+    backup_next_run: String,
+    backup_skipped: bool,
+    backup_error: String,
+    backup_mirror_error: String,
 }
 
 /// GET /settings
@@ -159,10 +170,31 @@ pub async fn settings_view(State(state): State<AppState>) -> Result<Html<String>
     let lang = state.get_lang().await;
     let t = all_translations(lang);
 
+    let schedule = state.backup.read().await.schedule().clone();
+    let status = state.backup_status.read().await.clone();
+    let local_time = |time: Option<chrono::DateTime<chrono::Utc>>| {
+        time.map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default()
+    };
+
     let template = SettingsTemplate {
         version: state.version,
         lang: lang.code(),
         t,
+        backup_interval: schedule.interval.to_string(),
+        backup_mirror_dir: schedule
+            .mirror_dir
+            .map(|p| p.display().to_string())
+            .unwrap_or_default(),
+        backup_last_run: local_time(status.last_run),
+        backup_next_run: local_time(status.next_run),
+        backup_skipped: status.skipped,
+        backup_error: status.error.unwrap_or_default(),
+        backup_mirror_error: status.mirror_error.unwrap_or_default(),
     };
 
     Ok(Html(
@@ -186,6 +218,9 @@ struct NotesEditTemplate {
     lang: &'static str,
     t: HashMap<String, String>,
     note_type: String,
+    /// 별점 (0이면 없음)
+    rating: u8,
+    mood: String,
 }
 
 /// GET /notes/new
@@ -205,6 +240,8 @@ pub async fn notes_new(State(state): State<AppState>) -> Result<Html<String>> {
         lang: lang.code(),
         t,
         note_type: "note".to_string(),
+        rating: 0,
+        mood: String::new(),
     };
     Ok(Html(
         template
@@ -223,6 +260,7 @@ struct NotesViewTemplate {
     t: HashMap<String, String>,
     backlinks: Vec<BacklinkInfo>,
     outgoing_links: Vec<BacklinkInfo>,
+    related: Vec<RelatedNote>,
 }
 
 struct NoteViewData {
@@ -239,6 +277,20 @@ struct BacklinkInfo {
     title: String,
 }
 
+/// 임베딩이 비슷한 노트
+struct RelatedNote {
+    id: u64,
+    title: String,
+    /// 유사도 (%)
+    similarity: u32,
+}
+
+/// 관련 노트 최대 개수
+const RELATED_LIMIT: usize = 5;
+
+/// 관련 노트로 보여줄 최소 유사도
+const RELATED_MIN_SCORE: f32 = 0.2;
+
 /// GET /notes/:id
 pub async fn notes_view(
     State(state): State<AppState>,
@@ -246,7 +298,7 @@ pub async fn notes_view(
 ) -> Result<Html<String>> {
     let db = state.db.read().await;
     let note = db
-        .get(id)?
+        .get_decrypted(id, state.crypto.read().await.as_ref())?
         .ok_or_else(|| LazarusError::NotFound(format!("Note ID: {}", id)))?;
 
     // 백링크 조회
@@ -280,6 +332,23 @@ pub async fn notes_view(
             .collect()
     };
 
+    // 관련 노트 (임베딩 최근접)
+    let related: Vec<RelatedNote> = match db.vector(id) {
+        Some(vector) => db
+            .nearest(vector, RELATED_LIMIT, Some(id))
+            .into_iter()
+            .filter(|(_, score)| *score >= RELATED_MIN_SCORE)
+            .filter_map(|(rid, score)| {
+                db.get(rid).ok().flatten().map(|n| RelatedNote {
+                    id: rid,
+                    title: n.title,
+                    similarity: (score * 100.0).round() as u32,
+                })
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
     // [[링크]] → HTML로 렌더링
     let existing_titles = state.link_index.read().await.existing_titles();
     let rendered_content = links::render_links(&note.content, Some(&existing_titles));
@@ -301,6 +370,107 @@ pub async fn notes_view(
         t,
         backlinks,
         outgoing_links,
+        related,
+    };
+    Ok(Html(
+        template
+            .render()
+            .map_err(|e| LazarusError::ServerStart(e.to_string()))?,
+    ))
+}
+
+/// 노트 히스토리 템플릿
+#[derive(Template)]
+#[template(path = "notes_history.html")]
+struct NotesHistoryTemplate {
+    version: &'static str,
+    note_id: u64,
+    note_title: String,
+    /// 최신순
+    revisions: Vec<RevisionItem>,
+    /// 비교 중인 리비전 (비교하지 않으면 from_rev = 0)
+    from_rev: usize,
+    to_rev: usize,
+    diff: Vec<DiffLine>,
+    /// diff에 바뀐 줄이 있는지
+    changed: bool,
+    lang: &'static str,
+    t: HashMap<String, String>,
+}
+
+struct RevisionItem {
+    rev: usize,
+    title: String,
+    updated_at: String,
+    size: usize,
+    encrypted: bool,
+    current: bool,
+}
+
+/// 히스토리 쿼리 (from이 있으면 diff 표시)
+#[derive(Deserialize, Default)]
+pub struct HistoryQuery {
+    pub from: Option<usize>,
+    pub to: Option<usize>,
+}
+
+/// GET /notes/:id/history
+pub async fn notes_history(
+    State(state): State<AppState>,
+    Path(id): Path<u64>,
+    Query(params): Query<HistoryQuery>,
+) -> Result<Html<String>> {
+    let db = state.db.read().await;
+    let crypto = state.crypto.read().await;
+
+    let note = db
+        .get_decrypted(id, crypto.as_ref())?
+        .ok_or_else(|| LazarusError::NotFound(format!("Note ID: {}", id)))?;
+
+    let history = db.history_notes(id, crypto.as_ref())?;
+    let latest = history.last().map(|(r, _)| r.rev).unwrap_or(0);
+    let mut revisions = Vec::with_capacity(history.len());
+    for (revision, note) in history.into_iter().rev() {
+        let title = note.map(|n| n.title).unwrap_or_default();
+        revisions.push(RevisionItem {
+            rev: revision.rev,
+            title,
+            updated_at: chrono::DateTime::from_timestamp(revision.updated_at, 0)
+                .unwrap_or_default()
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            size: revision.size,
+            encrypted: revision.encrypted,
+            current: revision.rev == latest,
+        });
+    }
+
+    let to_rev = params.to.unwrap_or(latest);
+    let (from_rev, diff) = match params.from {
+        Some(from) => {
+            let old = notes::load_revision(&db, id, from, crypto.as_ref())?;
+            let new = notes::load_revision(&db, id, to_rev, crypto.as_ref())?;
+            let diff = diff_lines(&notes::revision_text(&old), &notes::revision_text(&new));
+            (from, diff)
+        }
+        None => (0, Vec::new()),
+    };
+    let changed = diff.iter().any(|l| l.op != DiffOp::Same);
+
+    let lang = state.get_lang().await;
+    let t = all_translations(lang);
+
+    let template = NotesHistoryTemplate {
+        version: state.version,
+        note_id: id,
+        note_title: note.title,
+        revisions,
+        from_rev,
+        to_rev,
+        diff,
+        changed,
+        lang: lang.code(),
+        t,
     };
     Ok(Html(
         template
@@ -316,7 +486,7 @@ pub async fn notes_edit(
 ) -> Result<Html<String>> {
     let db = state.db.read().await;
     let note = db
-        .get(id)?
+        .get_decrypted(id, state.crypto.read().await.as_ref())?
         .ok_or_else(|| LazarusError::NotFound(format!("Note ID: {}", id)))?;
     drop(db);
 
@@ -335,6 +505,8 @@ pub async fn notes_edit(
         lang: lang.code(),
         t,
         note_type: format!("{:?}", note.note_type).to_lowercase(),
+        rating: note.rating.unwrap_or(0),
+        mood: note.mood.unwrap_or_default(),
     };
     Ok(Html(
         template
@@ -348,6 +520,9 @@ pub async fn notes_edit(
 pub struct SearchQuery {
     #[serde(default)]
     pub q: String,
+    /// "encrypted"면 암호화된 노트 메모리 인덱스에서 검색
+    #[serde(default)]
+    pub mode: String,
 }
 
 /// 검색 결과 템플릿
@@ -356,6 +531,9 @@ pub struct SearchQuery {
 struct SearchTemplate {
     version: &'static str,
     query: String,
+    mode: String,
+    /// 잠금 해제되어 암호화 노트 검색 가능
+    encrypted_available: bool,
     results: Vec<SearchResultItem>,
     lang: &'static str,
     t: HashMap<String, String>,
@@ -376,10 +554,19 @@ pub async fn search(
     let lang = state.get_lang().await;
     let t = all_translations(lang);
 
+    // 락 순서(search → encrypted_search)를 거스르지 않도록 각 분기에서만 잡음
+    let encrypted_available = state.encrypted_search.read().await.is_some();
+
     let mut results = Vec::new();
     if !params.q.is_empty() {
-        let search_engine = state.search.read().await;
-        let search_results = search_engine.search_fuzzy(&params.q, 20)?;
+        let search_results = if params.mode == "encrypted" {
+            match state.encrypted_search.read().await.as_ref() {
+                Some(engine) => engine.search_fuzzy(&params.q, 20)?,
+                None => Vec::new(),
+            }
+        } else {
+            state.search.read().await.search_fuzzy(&params.q, 20)?
+        };
         for r in search_results {
             results.push(SearchResultItem {
                 id: r.id,
@@ -392,6 +579,8 @@ pub async fn search(
     let template = SearchTemplate {
         version: state.version,
         query: params.q,
+        mode: params.mode,
+        encrypted_available,
         results,
         lang: lang.code(),
         t,
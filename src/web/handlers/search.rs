@@ -6,14 +6,70 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
+use crate::error::{LazarusError, Result};
+use crate::search::{embed, NoteQuery};
 use crate::web::state::AppState;
 
 #[derive(Deserialize)]
 pub struct SearchParams {
+    #[serde(default)]
     pub q: String,
     #[serde(default = "default_limit")]
     pub limit: usize,
+    /// "text" (기본), "semantic" 또는 "encrypted" (잠금 해제 중에만)
+    pub mode: Option<String>,
+
+    // 필터 (q 안의 `tag:` 등과 같은 의미, semantic 모드에서는 무시)
+    /// 쉼표로 구분된 태그 (모두 일치)
+    pub tag: Option<String>,
+    #[serde(rename = "type")]
+    pub note_type: Option<String>,
+    /// YYYY-MM-DD
+    pub after: Option<String>,
+    /// YYYY-MM-DD
+    pub before: Option<String>,
+    /// "4", ">=4", "<3" 등
+    pub rating: Option<String>,
+    pub mood: Option<String>,
+    pub links_to: Option<String>,
+    /// 쉼표로 구분 (link, tag, rating, mood)
+    pub has: Option<String>,
+    /// "encrypted" 또는 "plain"
+    pub is: Option<String>,
+}
+
+impl SearchParams {
+    /// q와 필터 파라미터를 합친 쿼리
+    fn note_query(&self) -> Result<NoteQuery> {
+        let mut query = NoteQuery::parse(&self.q)?;
+
+        let lists = [("tag", &self.tag), ("has", &self.has)];
+        for (key, value) in lists {
+            for item in value.iter().flat_map(|v| v.split(',')) {
+                let item = item.trim();
+                if !item.is_empty() {
+                    query.apply(key, item)?;
+                }
+            }
+        }
+
+        let singles = [
+            ("type", &self.note_type),
+            ("after", &self.after),
+            ("before", &self.before),
+            ("rating", &self.rating),
+            ("mood", &self.mood),
+            ("links-to", &self.links_to),
+            ("is", &self.is),
+        ];
+        for (key, value) in singles {
+            if let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+                query.apply(key, value.trim())?;
+            }
+        }
+
+        Ok(query)
+    }
 }
 
 fn default_limit() -> usize {
@@ -41,37 +97,27 @@ pub async fn search(
     State(state): State<AppState>,
     Query(params): Query<SearchParams>,
 ) -> Result<Json<SearchResponse>> {
-    let mut results = Vec::new();
-
-    if !params.q.is_empty() {
-        let db = state.db.read().await;
-        let query_lower = params.q.to_lowercase();
-
-        // 간단한 텍스트 검색 (나중에 Tantivy로 대체)
-        for id in db.list_ids() {
-            if let Some(note) = db.get(id)? {
-                let title_match = note.title.to_lowercase().contains(&query_lower);
-                let content_match = note.content.to_lowercase().contains(&query_lower);
-
-                if title_match || content_match {
-                    // 간단한 점수 계산
-                    let score = if title_match { 2.0 } else { 1.0 };
-
-                    results.push(SearchResult {
-                        id: note.id,
-                        title: note.title,
-                        preview: note.content.chars().take(150).collect::<String>() + "...",
-                        source: "note".to_string(),
-                        score,
-                    });
-                }
-            }
+    let results = match params.mode.as_deref() {
+        Some("semantic") if !params.q.is_empty() => {
+            semantic_search(&state, &params.q, params.limit).await?
         }
-
-        // 점수순 정렬
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-        results.truncate(params.limit);
-    }
+        Some("encrypted") => encrypted_search(&state, &params.note_query()?, params.limit).await?,
+        _ => {
+            let query = params.note_query()?;
+            let search = state.search.read().await;
+            search
+                .search_query(&query, params.limit)?
+                .into_iter()
+                .map(|r| SearchResult {
+                    id: r.id,
+                    title: r.title,
+                    preview: r.preview,
+                    source: "note".to_string(),
+                    score: r.score,
+                })
+                .collect()
+        }
+    };
 
     let total = results.len();
 
@@ -81,3 +127,45 @@ pub async fn search(
         total,
     }))
 }
+
+/// 임베딩 유사도 검색 (키워드가 달라도 비슷한 주제의 노트를 찾음)
+async fn semantic_search(state: &AppState, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+    let db = state.db.read().await;
+    let mut results = Vec::new();
+
+    for (id, score) in db.nearest(&embed(query), limit, None) {
+        if let Some(note) = db.get(id)? {
+            results.push(SearchResult {
+                id: note.id,
+                title: note.title,
+                preview: note.content.chars().take(150).collect::<String>() + "...",
+                source: "note".to_string(),
+                score,
+            });
+        }
+    }
+
+    Ok(results)
+}
+
+/// 암호화된 노트 검색 (메모리 인덱스, 잠겨 있으면 401)
+async fn encrypted_search(
+    state: &AppState,
+    query: &NoteQuery,
+    limit: usize,
+) -> Result<Vec<SearchResult>> {
+    let encrypted_search = state.encrypted_search.read().await;
+    let engine = encrypted_search.as_ref().ok_or(LazarusError::Decryption)?;
+
+    Ok(engine
+        .search_query(query, limit)?
+        .into_iter()
+        .map(|r| SearchResult {
+            id: r.id,
+            title: r.title,
+            preview: r.preview,
+            source: "encrypted".to_string(),
+            score: r.score,
+        })
+        .collect())
+}
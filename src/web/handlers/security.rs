@@ -2,14 +2,16 @@
 
 use axum::{
     extract::State,
+    http::{header, HeaderMap},
     response::{Html, IntoResponse, Response},
     Json,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::crypto::{CryptoManager, SecurityConfig};
-use crate::error::Result;
+use crate::crypto::{guard::MIN_WIPE_AFTER, AuditEvent, CryptoManager, SecurityConfig, Unlocked};
+use crate::error::{LazarusError, Result};
 use crate::i18n::all_translations;
+use crate::web::session::{clear_cookie, session_cookie};
 use crate::web::state::AppState;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 
@@ -18,6 +20,16 @@ use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 pub struct PinStatus {
     pub enabled: bool,
     pub locked: bool,
+    /// 복구 키를 만들어 둠
+    pub recovery_key: bool,
+    /// 마지막 성공 이후 연속 실패 횟수
+    pub failures: u32,
+    /// 다음 시도까지 기다려야 하는 시간 (초)
+    pub retry_after_secs: Option<u64>,
+    /// 연속 실패 시 금고 삭제 기준
+    pub wipe_after: Option<u32>,
+    /// 자동 잠금까지의 유휴 시간 (분, 꺼져 있으면 None)
+    pub idle_lock_mins: Option<i64>,
 }
 
 /// PIN 요청
@@ -41,13 +53,25 @@ pub struct ApiResponse {
 }
 
 /// GET /api/security/status - PIN 상태 확인
-pub async fn get_status(State(state): State<AppState>) -> Result<Json<PinStatus>> {
+pub async fn get_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<PinStatus>> {
+    let (failures, retry_after_secs) = {
+        let guard = state.pin_guard.read().await;
+        (guard.failures(), guard.retry_after(chrono::Utc::now()))
+    };
+    let locked = state.is_locked_for(&headers).await;
     let security = state.security.read().await;
-    let crypto = state.crypto.read().await;
 
     Ok(Json(PinStatus {
         enabled: security.pin_enabled,
-        locked: security.pin_enabled && crypto.is_none(),
+        locked,
+        recovery_key: security.has_recovery_key(),
+        failures,
+        retry_after_secs,
+        wipe_after: security.wipe_after,
+        idle_lock_mins: security.idle_timeout().map(|t| t.num_minutes()),
     }))
 }
 
@@ -55,113 +79,150 @@ pub async fn get_status(State(state): State<AppState>) -> Result<Json<PinStatus>
 pub async fn unlock(
     State(state): State<AppState>,
     Json(req): Json<PinRequest>,
-) -> Result<Json<ApiResponse>> {
-    let security = state.security.read().await;
-
-    if !security.pin_enabled {
+) -> Result<Response> {
+    if !state.security.read().await.pin_enabled {
         return Ok(Json(ApiResponse {
             success: true,
             message: "PIN이 설정되지 않았습니다".to_string(),
-        }));
+        })
+        .into_response());
     }
 
-    // PIN 검증
-    if !security.verify_pin(&req.pin)? {
+    // PIN 검증 (시도 제한), 강요 PIN이면 미끼 금고
+    let pin = req.pin.clone();
+    let unlocked = state
+        .guarded_verify_with("unlock", move |security| security.unlock(&pin, None))
+        .await?;
+    let Some(unlocked) = unlocked else {
         return Ok(Json(ApiResponse {
             success: false,
             message: "잘못된 PIN입니다".to_string(),
-        }));
+        })
+        .into_response());
+    };
+
+    // CryptoManager 저장, 암호화된 노트를 메모리 인덱스에 올림
+    open_vault(&state, unlocked, &req.pin).await?;
+    migrate_legacy_header(&state, &req.pin, None).await;
+
+    Ok(unlocked_response(&state, "잠금이 해제되었습니다").await)
+}
+
+/// 잠금 해제된 키 반영 (강요 PIN이면 설정에 따라 진짜 금고 파기)
+async fn open_vault(state: &AppState, unlocked: Unlocked, pin: &str) -> Result<()> {
+    if unlocked.destroy_real {
+        let mut security = state.security.write().await;
+        security.destroy_real_vault(pin)?;
+        security.save(&state.data_dir.join("security.json"))?;
     }
 
-    // CryptoManager 생성 및 저장
-    if let Some(crypto) = security.get_crypto(&req.pin)? {
-        // 백업에도 암호화 매니저 연결
-        let mut backup = state.backup.write().await;
-        backup.set_crypto(Some(crypto.clone()));
+    state.activate_key(&unlocked).await?;
 
-        let mut crypto_lock = state.crypto.write().await;
-        *crypto_lock = Some(crypto);
+    if unlocked.destroy_real {
+        state.destroy_hidden_vault().await?;
     }
+    Ok(())
+}
 
-    Ok(Json(ApiResponse {
-        success: true,
-        message: "잠금이 해제되었습니다".to_string(),
-    }))
+/// 잠금 해제 성공 응답 (이 브라우저에 세션 쿠키를 붙임)
+async fn unlocked_response(state: &AppState, message: &str) -> Response {
+    let token = state.start_session().await;
+    (
+        [(header::SET_COOKIE, session_cookie(&token))],
+        Json(ApiResponse {
+            success: true,
+            message: message.to_string(),
+        }),
+    )
+        .into_response()
 }
 
-/// POST /api/security/lock - 잠금
-pub async fn lock(State(state): State<AppState>) -> Result<Json<ApiResponse>> {
-    let mut crypto = state.crypto.write().await;
-    *crypto = None;
+/// 예전 형식 헤더면 봉투 암호화로 전환해 저장 (실패해도 잠금 해제는 유지)
+async fn migrate_legacy_header(state: &AppState, pin: &str, keyfile: Option<&[u8]>) {
+    let mut security = state.security.write().await;
+    match security.migrate(pin, keyfile) {
+        Ok(true) => match security.save(&state.data_dir.join("security.json")) {
+            Ok(()) => tracing::info!("보안 설정을 봉투 암호화 형식으로 전환"),
+            Err(e) => tracing::warn!("전환된 보안 설정 저장 실패: {}", e),
+        },
+        Ok(false) => {}
+        Err(e) => tracing::warn!("보안 설정 전환 실패: {}", e),
+    }
+}
 
-    // 백업 암호화도 해제
-    let mut backup = state.backup.write().await;
-    backup.set_crypto(None);
+/// POST /api/security/lock - 잠금 (키는 하나뿐이라 모든 브라우저가 잠김)
+pub async fn lock(State(state): State<AppState>) -> Result<Response> {
+    // 세션, 키, 복호화된 노트 인덱스, 백업 암호화 모두 해제
+    state.lock_vault().await;
 
-    Ok(Json(ApiResponse {
-        success: true,
-        message: "잠금되었습니다".to_string(),
-    }))
+    Ok((
+        [(header::SET_COOKIE, clear_cookie())],
+        Json(ApiResponse {
+            success: true,
+            message: "잠금되었습니다".to_string(),
+        }),
+    )
+        .into_response())
 }
 
 /// POST /api/security/set-pin - PIN 설정/변경
 pub async fn set_pin(
     State(state): State<AppState>,
     Json(req): Json<SetPinRequest>,
-) -> Result<Json<ApiResponse>> {
+) -> Result<Response> {
     // PIN 유효성 검사 (6-32자리 영숫자)
-    if req.new_pin.len() < 6 || req.new_pin.len() > 32 {
+    if let Some(message) = invalid_pin(&req.new_pin) {
         return Ok(Json(ApiResponse {
             success: false,
-            message: "PIN must be 6-32 characters".to_string(),
-        }));
-    }
-    if !req.new_pin.chars().all(|c| c.is_ascii_alphanumeric()) {
-        return Ok(Json(ApiResponse {
-            success: false,
-            message: "PIN must be alphanumeric only".to_string(),
-        }));
+            message: message.to_string(),
+        })
+        .into_response());
     }
 
-    let mut security = state.security.write().await;
-
-    // 기존 PIN이 있으면 검증
-    if security.pin_enabled {
+    // 기존 PIN이 있으면 검증 (시도 제한)
+    if state.security.read().await.pin_enabled {
         match &req.current_pin {
             Some(current) => {
-                if !security.verify_pin(current)? {
+                let current = current.clone();
+                if !state
+                    .guarded_verify("set-pin", move |security| security.verify_pin(&current))
+                    .await?
+                {
                     return Ok(Json(ApiResponse {
                         success: false,
                         message: "현재 PIN이 잘못되었습니다".to_string(),
-                    }));
+                    })
+                    .into_response());
                 }
             }
             None => {
                 return Ok(Json(ApiResponse {
                     success: false,
                     message: "현재 PIN을 입력해주세요".to_string(),
-                }));
+                })
+                .into_response());
             }
         }
     }
 
-    // 새 PIN 설정
-    security.set_pin(&req.new_pin)?;
+    // 새 PIN 설정 (데이터 키는 그대로 두고 다시 감쌈)
+    let mut security = state.security.write().await;
+    let current = if security.pin_enabled {
+        req.current_pin.as_deref().map(|pin| (pin, None))
+    } else {
+        None
+    };
+    let unlocked = security.change_pin(current, &req.new_pin, None)?;
 
     // 파일에 저장
     let security_path = state.data_dir.join("security.json");
     security.save(&security_path)?;
+    drop(security);
 
-    // CryptoManager 업데이트
-    if let Some(crypto) = security.get_crypto(&req.new_pin)? {
-        let mut crypto_lock = state.crypto.write().await;
-        *crypto_lock = Some(crypto);
-    }
+    // CryptoManager 업데이트, 암호화된 노트를 메모리 인덱스에 올림
+    open_vault(&state, unlocked, &req.new_pin).await?;
 
-    Ok(Json(ApiResponse {
-        success: true,
-        message: "PIN이 설정되었습니다".to_string(),
-    }))
+    Ok(unlocked_response(&state, "PIN이 설정되었습니다").await)
 }
 
 /// POST /api/security/remove-pin - PIN 제거
@@ -169,17 +230,21 @@ pub async fn remove_pin(
     State(state): State<AppState>,
     Json(req): Json<PinRequest>,
 ) -> Result<Json<ApiResponse>> {
-    let mut security = state.security.write().await;
-
-    if !security.pin_enabled {
+    if !state.security.read().await.pin_enabled {
         return Ok(Json(ApiResponse {
             success: false,
             message: "PIN이 설정되지 않았습니다".to_string(),
         }));
     }
 
-    // PIN 검증
-    if !security.verify_pin(&req.pin)? {
+    // PIN 검증 (시도 제한)
+    if !state
+        .guarded_verify("remove-pin", {
+            let pin = req.pin.clone();
+            move |security| security.verify_vault_pin(&pin, None)
+        })
+        .await?
+    {
         return Ok(Json(ApiResponse {
             success: false,
             message: "잘못된 PIN입니다".to_string(),
@@ -187,15 +252,16 @@ pub async fn remove_pin(
     }
 
     // PIN 제거
+    let mut security = state.security.write().await;
     security.remove_pin();
 
     // 파일에 저장
     let security_path = state.data_dir.join("security.json");
     security.save(&security_path)?;
+    drop(security);
 
-    // CryptoManager 제거
-    let mut crypto_lock = state.crypto.write().await;
-    *crypto_lock = None;
+    // 세션과 CryptoManager 제거
+    state.lock_vault().await;
 
     Ok(Json(ApiResponse {
         success: true,
@@ -204,14 +270,18 @@ pub async fn remove_pin(
 }
 
 /// GET /security - 보안 설정 페이지
-pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>> {
+pub async fn security_page(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Html<String>> {
+    // 락 순서: pin_guard → security
+    let failures = state.pin_guard.read().await.failures();
+    let is_locked = state.is_locked_for(&headers).await;
     let security = state.security.read().await;
-    let crypto = state.crypto.read().await;
     let lang = state.get_lang().await;
     let t = all_translations(lang);
 
     let pin_enabled = security.pin_enabled;
-    let is_locked = pin_enabled && crypto.is_none();
 
     let status_icon = if is_locked {
         "🔒"
@@ -220,13 +290,22 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
     } else {
         "🔐"
     };
-    let status_text = if is_locked {
+    let mut status_text = if is_locked {
         t.get("security.locked").cloned().unwrap_or_default()
     } else if pin_enabled {
         t.get("security.pin_enabled").cloned().unwrap_or_default()
     } else {
         t.get("security.pin_not_set").cloned().unwrap_or_default()
     };
+    if pin_enabled && failures > 0 {
+        status_text.push_str(&format!(
+            r#"<div class="keyfile-warning">⚠️ {}: {}</div>"#,
+            t.get("security.failed_attempts")
+                .cloned()
+                .unwrap_or_default(),
+            failures
+        ));
+    }
 
     let buttons = if is_locked {
         format!(
@@ -236,9 +315,13 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
     } else if pin_enabled {
         format!(
             r#"<button class="btn btn-secondary" onclick="lockNow()">🔒 {}</button>
-               <button class="btn btn-danger" onclick="removePin()">{}</button>"#,
+               <button class="btn btn-danger" onclick="removePin()">{}</button>
+               <button class="btn btn-secondary" onclick="createRecovery()">📄 {}</button>"#,
             t.get("security.lock").cloned().unwrap_or_default(),
-            t.get("security.remove_pin").cloned().unwrap_or_default()
+            t.get("security.remove_pin").cloned().unwrap_or_default(),
+            t.get("security.recovery_create")
+                .cloned()
+                .unwrap_or_default()
         )
     } else {
         format!(
@@ -247,6 +330,110 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
         )
     };
 
+    // 잠겨 있으면 복구 키 입력, 풀려 있으면 인쇄용 복구 시트 자리
+    let mut extra_html = if is_locked && security.has_recovery_key() {
+        format!(
+            r##"<p class="recovery-link"><a href="#" onclick="toggleRecover(); return false;">{}</a></p>
+               <div id="recover-section" style="display: none;">
+                   <label for="recovery-key-input">{}</label>
+                   <input type="text" id="recovery-key-input" class="recovery-input" autocomplete="off" spellcheck="false">
+                   <p class="keyfile-warning">{}</p>
+                   <button class="btn btn-primary" onclick="recover()">{}</button>
+               </div>"##,
+            t.get("security.recovery_forgot")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.recovery_key").cloned().unwrap_or_default(),
+            t.get("security.recovery_new_pin")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.recovery_submit")
+                .cloned()
+                .unwrap_or_default(),
+        )
+    } else if pin_enabled {
+        format!(
+            r#"<div id="recovery-sheet" class="recovery-sheet" style="display: none;">
+                   <h2>{}</h2>
+                   <p id="recovery-date"></p>
+                   <div id="recovery-key-text" class="recovery-key"></div>
+                   <p>{}</p>
+                   <button class="btn btn-primary no-print" onclick="window.print()">🖨️ {}</button>
+               </div>"#,
+            t.get("security.recovery_sheet_title")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.recovery_sheet_hint")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.recovery_print")
+                .cloned()
+                .unwrap_or_default(),
+        )
+    } else {
+        String::new()
+    };
+
+    // 잠금 해제 상태에서만 삭제 정책 설정
+    if pin_enabled && !is_locked {
+        extra_html.push_str(&format!(
+            r#"<div class="keyfile-section">
+                   <label for="wipe-after">{}</label>
+                   <div class="keyfile-row">
+                       <input type="number" id="wipe-after" min="0" max="99" value="{}" class="recovery-input" style="width: 6rem;">
+                       <button class="btn btn-secondary btn-sm" onclick="setWipePolicy()">{}</button>
+                   </div>
+                   <p class="keyfile-warning">⚠️ {}</p>
+               </div>"#,
+            t.get("security.wipe_after").cloned().unwrap_or_default(),
+            security.wipe_after.unwrap_or(0),
+            t.get("security.wipe_save").cloned().unwrap_or_default(),
+            t.get("security.wipe_warning")
+                .cloned()
+                .unwrap_or_default(),
+        ));
+        extra_html.push_str(&format!(
+            r#"<div class="keyfile-section">
+                   <label for="idle-lock">{}</label>
+                   <div class="keyfile-row">
+                       <input type="number" id="idle-lock" min="0" max="{}" value="{}" class="recovery-input" style="width: 6rem;">
+                       <button class="btn btn-secondary btn-sm" onclick="setIdleLock()">{}</button>
+                   </div>
+                   <p class="keyfile-hint">{}</p>
+               </div>"#,
+            t.get("security.idle_lock").cloned().unwrap_or_default(),
+            MAX_IDLE_LOCK_MINS,
+            security
+                .idle_timeout()
+                .map(|timeout| timeout.num_minutes())
+                .unwrap_or(0),
+            t.get("security.idle_lock_save")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.idle_lock_hint")
+                .cloned()
+                .unwrap_or_default(),
+        ));
+        // 설정 여부는 보여주지 않음 (화면으로도 미끼 금고를 알 수 없도록)
+        extra_html.push_str(&format!(
+            r#"<div class="keyfile-section">
+                   <label for="duress-pin">{}</label>
+                   <div class="keyfile-row">
+                       <input type="password" id="duress-pin" maxlength="32" autocomplete="off" class="recovery-input">
+                       <button class="btn btn-secondary btn-sm" onclick="setDuressPin()">{}</button>
+                   </div>
+                   <label class="keyfile-hint"><input type="checkbox" id="duress-destroy"> {}</label>
+                   <p class="keyfile-hint">{}</p>
+               </div>"#,
+            t.get("security.duress_pin").cloned().unwrap_or_default(),
+            t.get("security.duress_save").cloned().unwrap_or_default(),
+            t.get("security.duress_destroy")
+                .cloned()
+                .unwrap_or_default(),
+            t.get("security.duress_hint").cloned().unwrap_or_default(),
+        ));
+    }
+
     let html = format!(
         r#"
 <!DOCTYPE html>
@@ -344,6 +531,48 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
             font-size: 0.85rem;
             margin-top: 0.5rem;
         }}
+        .keyfile-hint {{
+            color: var(--text-muted);
+            font-size: 0.85rem;
+            margin-top: 0.5rem;
+        }}
+        .recovery-link {{
+            text-align: center;
+            margin-top: 1rem;
+            font-size: 0.9rem;
+        }}
+        .recovery-input {{
+            width: 100%;
+            padding: 0.6rem;
+            margin: 0.5rem 0;
+            font-family: monospace;
+            text-transform: uppercase;
+            border: 1px solid var(--border);
+            border-radius: var(--radius);
+            background: var(--bg);
+            color: var(--text);
+        }}
+        .recovery-sheet {{
+            margin-top: 1.5rem;
+            padding-top: 1.5rem;
+            border-top: 1px solid var(--border);
+        }}
+        .recovery-key {{
+            font-family: monospace;
+            font-size: 1.3rem;
+            letter-spacing: 0.1em;
+            text-align: center;
+            word-break: break-all;
+            padding: 1rem;
+            margin: 1rem 0;
+            border: 2px dashed var(--border);
+        }}
+        @media print {{
+            body * {{ visibility: hidden; }}
+            #recovery-sheet, #recovery-sheet * {{ visibility: visible; }}
+            #recovery-sheet {{ position: absolute; top: 0; left: 0; width: 100%; }}
+            .no-print {{ display: none; }}
+        }}
     </style>
 </head>
 <body>
@@ -395,6 +624,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
                 <div class="btn-group">
                     {}
                 </div>
+                {}
             </div>
         </div>
     </main>
@@ -411,7 +641,8 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
     <script>
         const t = {{
             pin_min: "{}",
-            enter_current_pin: "{}"
+            enter_current_pin: "{}",
+            recovery_key: "{}"
         }};
         const digits = document.querySelectorAll('.pin-digit');
 
@@ -428,6 +659,16 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
             }});
         }});
 
+        // 오류 응답(429 등)은 JSON이 아니므로 메시지로 감쌈
+        async function readJson(res) {{
+            const text = await res.text();
+            try {{
+                return JSON.parse(text);
+            }} catch {{
+                return {{ success: false, message: text }};
+            }}
+        }}
+
         function getPin() {{
             return Array.from(digits).map(d => d.value).join('');
         }}
@@ -456,7 +697,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
                 headers: {{ 'Content-Type': 'application/json' }},
                 body: JSON.stringify({{ pin }})
             }});
-            const data = await res.json();
+            const data = await readJson(res);
 
             if (data.success) {{
                 showToast(data.message, 'success');
@@ -479,7 +720,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
                 headers: {{ 'Content-Type': 'application/json' }},
                 body: JSON.stringify({{ new_pin: pin, new_keyfile: selectedKeyfile }})
             }});
-            const data = await res.json();
+            const data = await readJson(res);
 
             showToast(data.message, data.success ? 'success' : 'error');
             if (data.success) {{
@@ -498,7 +739,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
                 headers: {{ 'Content-Type': 'application/json' }},
                 body: JSON.stringify({{ pin, keyfile: selectedKeyfile }})
             }});
-            const data = await res.json();
+            const data = await readJson(res);
             showToast(data.message, data.success ? 'success' : 'error');
             if (data.success) {{
                 setTimeout(() => location.reload(), 1000);
@@ -507,7 +748,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
 
         async function lockNow() {{
             const res = await fetch('/api/security/lock', {{ method: 'POST' }});
-            const data = await res.json();
+            const data = await readJson(res);
             showToast(data.message, 'success');
             setTimeout(() => location.reload(), 1000);
         }}
@@ -534,7 +775,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
 
          async function generateKeyfile() {{
              const res = await fetch('/api/security/generate-keyfile', {{ method: 'POST' }});
-             const data = await res.json();
+             const data = await readJson(res);
              if (data.success) {{
                  const bytes = Uint8Array.from(atob(data.keyfile), c => c.charCodeAt(0));
                  const blob = new Blob([bytes], {{ type: 'application/octet-stream' }});
@@ -548,6 +789,116 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
              }}
          }}
 
+         async function createRecovery() {{
+             const pin = getPin();
+             if (pin.length < 4) {{
+                 showToast(t.enter_current_pin, 'error');
+                 return;
+             }}
+             const res = await fetch('/api/security/recovery-key', {{
+                 method: 'POST',
+                 headers: {{ 'Content-Type': 'application/json' }},
+                 body: JSON.stringify({{ pin, keyfile: selectedKeyfile }})
+             }});
+             const data = await readJson(res);
+             if (!data.success) {{
+                 showToast(data.message, 'error');
+                 clearPin();
+                 return;
+             }}
+             clearPin();
+             document.getElementById('recovery-key-text').textContent = data.recovery_key;
+             document.getElementById('recovery-date').textContent = new Date().toLocaleString();
+             document.getElementById('recovery-sheet').style.display = 'block';
+         }}
+
+         async function setWipePolicy() {{
+             const pin = getPin();
+             if (pin.length < 4) {{
+                 showToast(t.enter_current_pin, 'error');
+                 return;
+             }}
+             const wipeAfter = parseInt(document.getElementById('wipe-after').value || '0', 10);
+             const res = await fetch('/api/security/wipe-policy', {{
+                 method: 'POST',
+                 headers: {{ 'Content-Type': 'application/json' }},
+                 body: JSON.stringify({{ pin, keyfile: selectedKeyfile, wipe_after: wipeAfter }})
+             }});
+             const data = await readJson(res);
+             clearPin();
+             showToast(data.message, data.success ? 'success' : 'error');
+         }}
+
+         async function setIdleLock() {{
+             const pin = getPin();
+             if (pin.length < 4) {{
+                 showToast(t.enter_current_pin, 'error');
+                 return;
+             }}
+             const idleMinutes = parseInt(document.getElementById('idle-lock').value || '0', 10);
+             const res = await fetch('/api/security/idle-lock', {{
+                 method: 'POST',
+                 headers: {{ 'Content-Type': 'application/json' }},
+                 body: JSON.stringify({{ pin, keyfile: selectedKeyfile, idle_minutes: idleMinutes }})
+             }});
+             const data = await readJson(res);
+             clearPin();
+             showToast(data.message, data.success ? 'success' : 'error');
+         }}
+
+         async function setDuressPin() {{
+             const pin = getPin();
+             if (pin.length < 4) {{
+                 showToast(t.enter_current_pin, 'error');
+                 return;
+             }}
+             const input = document.getElementById('duress-pin');
+             const destroy = document.getElementById('duress-destroy');
+             const res = await fetch('/api/security/duress-pin', {{
+                 method: 'POST',
+                 headers: {{ 'Content-Type': 'application/json' }},
+                 body: JSON.stringify({{
+                     pin,
+                     keyfile: selectedKeyfile,
+                     duress_pin: input.value,
+                     destroy_real: destroy.checked
+                 }})
+             }});
+             const data = await readJson(res);
+             clearPin();
+             input.value = '';
+             destroy.checked = false;
+             showToast(data.message, data.success ? 'success' : 'error');
+         }}
+
+         function toggleRecover() {{
+             const section = document.getElementById('recover-section');
+             section.style.display = section.style.display === 'none' ? 'block' : 'none';
+         }}
+
+         async function recover() {{
+             const recoveryKey = document.getElementById('recovery-key-input').value;
+             const pin = getPin();
+             if (!recoveryKey) {{
+                 showToast(t.recovery_key, 'error');
+                 return;
+             }}
+             if (pin.length < 4) {{
+                 showToast(t.pin_min, 'error');
+                 return;
+             }}
+             const res = await fetch('/api/security/recover', {{
+                 method: 'POST',
+                 headers: {{ 'Content-Type': 'application/json' }},
+                 body: JSON.stringify({{ recovery_key: recoveryKey, new_pin: pin, new_keyfile: selectedKeyfile }})
+             }});
+             const data = await readJson(res);
+             showToast(data.message, data.success ? 'success' : 'error');
+             if (data.success) {{
+                 setTimeout(() => location.href = '/', 1000);
+             }}
+         }}
+
         digits[0].focus();
     </script>
 </body>
@@ -563,6 +914,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
         status_text,
         t.get("security.pin_input").cloned().unwrap_or_default(),
         buttons,
+        extra_html,
         if lang.code() == "en" {
             "btn-primary"
         } else {
@@ -579,6 +931,7 @@ pub async fn security_page(State(state): State<AppState>) -> Result<Html<String>
         t.get("security.enter_current_pin")
             .cloned()
             .unwrap_or_default(),
+        t.get("security.recovery_key").cloned().unwrap_or_default(),
     );
     Ok(Html(html))
 }
@@ -613,14 +966,18 @@ pub struct UnlockWithKeyfileRequest {
 pub async fn unlock_with_keyfile(
     State(state): State<AppState>,
     Json(req): Json<UnlockWithKeyfileRequest>,
-) -> Result<Json<ApiResponse>> {
-    let security = state.security.read().await;
+) -> Result<Response> {
+    let (pin_enabled, requires_keyfile) = {
+        let security = state.security.read().await;
+        (security.pin_enabled, security.requires_keyfile())
+    };
 
-    if !security.pin_enabled {
+    if !pin_enabled {
         return Ok(Json(ApiResponse {
             success: true,
             message: "PIN이 설정되지 않았습니다".to_string(),
-        }));
+        })
+        .into_response());
     }
 
     // 키파일 디코딩
@@ -631,42 +988,43 @@ pub async fn unlock_with_keyfile(
                 return Ok(Json(ApiResponse {
                     success: false,
                     message: "Invalid keyfile format".to_string(),
-                }))
+                })
+                .into_response())
             }
         },
         None => None,
     };
 
     // 키파일 필요한데 없으면 에러
-    if security.requires_keyfile() && keyfile_bytes.is_none() {
+    if requires_keyfile && keyfile_bytes.is_none() {
         return Ok(Json(ApiResponse {
             success: false,
             message: "Keyfile required".to_string(),
-        }));
+        })
+        .into_response());
     }
 
-    // PIN + 키파일 검증
+    // PIN + 키파일 검증 (시도 제한)
+    let (pin, keyfile) = (req.pin.clone(), keyfile_bytes.clone());
+    let unlocked = state
+        .guarded_verify_with("unlock", move |security| {
+            security.unlock(&pin, keyfile.as_deref())
+        })
+        .await?;
     let kf_ref = keyfile_bytes.as_deref();
-    if !security.verify_pin_with_keyfile(&req.pin, kf_ref)? {
+    let Some(unlocked) = unlocked else {
         return Ok(Json(ApiResponse {
             success: false,
             message: "잘못된 PIN 또는 키파일입니다".to_string(),
-        }));
-    }
+        })
+        .into_response());
+    };
 
-    // CryptoManager 생성 및 저장
-    if let Some(crypto) = security.get_crypto_with_keyfile(&req.pin, kf_ref)? {
-        let mut backup = state.backup.write().await;
-        backup.set_crypto(Some(crypto.clone()));
+    // CryptoManager 저장, 암호화된 노트를 메모리 인덱스에 올림
+    open_vault(&state, unlocked, &req.pin).await?;
+    migrate_legacy_header(&state, &req.pin, kf_ref).await;
 
-        let mut crypto_lock = state.crypto.write().await;
-        *crypto_lock = Some(crypto);
-    }
-
-    Ok(Json(ApiResponse {
-        success: true,
-        message: "잠금이 해제되었습니다".to_string(),
-    }))
+    Ok(unlocked_response(&state, "잠금이 해제되었습니다").await)
 }
 
 /// PIN + 키파일 설정 요청
@@ -682,54 +1040,59 @@ pub struct SetPinWithKeyfileRequest {
 pub async fn set_pin_with_keyfile(
     State(state): State<AppState>,
     Json(req): Json<SetPinWithKeyfileRequest>,
-) -> Result<Json<ApiResponse>> {
+) -> Result<Response> {
     // PIN 유효성 검사
-    if req.new_pin.len() < 6 || req.new_pin.len() > 32 {
-        return Ok(Json(ApiResponse {
-            success: false,
-            message: "PIN must be 6-32 characters".to_string(),
-        }));
-    }
-    if !req.new_pin.chars().all(|c| c.is_ascii_alphanumeric()) {
+    if let Some(message) = invalid_pin(&req.new_pin) {
         return Ok(Json(ApiResponse {
             success: false,
-            message: "PIN must be alphanumeric only".to_string(),
-        }));
+            message: message.to_string(),
+        })
+        .into_response());
     }
 
-    let mut security = state.security.write().await;
+    // 기존 키파일 디코딩
+    let current_kf = match &req.current_keyfile {
+        Some(kf) => match BASE64.decode(kf) {
+            Ok(bytes) => Some(bytes),
+            Err(_) => {
+                return Ok(Json(ApiResponse {
+                    success: false,
+                    message: "Invalid keyfile format".to_string(),
+                })
+                .into_response())
+            }
+        },
+        None => None,
+    };
 
-    // 기존 PIN 검증
-    if security.pin_enabled {
+    // 기존 PIN 검증 (시도 제한)
+    let mut current = None;
+    if state.security.read().await.pin_enabled {
         let current_pin = match &req.current_pin {
             Some(p) => p,
             None => {
                 return Ok(Json(ApiResponse {
                     success: false,
                     message: "현재 PIN을 입력해주세요".to_string(),
-                }))
+                })
+                .into_response())
             }
         };
 
-        let current_kf = match &req.current_keyfile {
-            Some(kf) => match BASE64.decode(kf) {
-                Ok(bytes) => Some(bytes),
-                Err(_) => {
-                    return Ok(Json(ApiResponse {
-                        success: false,
-                        message: "Invalid keyfile format".to_string(),
-                    }))
-                }
-            },
-            None => None,
-        };
-
-        if !security.verify_pin_with_keyfile(current_pin, current_kf.as_deref())? {
+        if !state
+            .guarded_verify("set-pin", {
+                let (pin, keyfile) = (current_pin.clone(), current_kf.clone());
+                move |security| security.verify_pin_with_keyfile(&pin, keyfile.as_deref())
+            })
+            .await?
+        {
             return Ok(Json(ApiResponse {
                 success: false,
                 message: "현재 PIN 또는 키파일이 잘못되었습니다".to_string(),
-            }));
+            })
+            .into_response());
         }
+        current = Some((current_pin.as_str(), current_kf.as_deref()));
     }
 
     // 새 키파일 디코딩
@@ -740,27 +1103,739 @@ pub async fn set_pin_with_keyfile(
                 return Ok(Json(ApiResponse {
                     success: false,
                     message: "Invalid keyfile format".to_string(),
-                }))
+                })
+                .into_response())
             }
         },
         None => None,
     };
 
-    // 새 PIN + 키파일 설정
-    security.set_pin_with_keyfile(&req.new_pin, new_kf.as_deref())?;
+    // 새 PIN + 키파일 설정 (데이터 키는 그대로 두고 다시 감쌈)
+    let mut security = state.security.write().await;
+    let unlocked = security.change_pin(current, &req.new_pin, new_kf.as_deref())?;
 
     // 저장
     let security_path = state.data_dir.join("security.json");
     security.save(&security_path)?;
+    drop(security);
+
+    // CryptoManager 업데이트, 암호화된 노트를 메모리 인덱스에 올림
+    open_vault(&state, unlocked, &req.new_pin).await?;
+
+    Ok(unlocked_response(&state, "PIN이 설정되었습니다").await)
+}
 
-    // CryptoManager 업데이트
-    if let Some(crypto) = security.get_crypto_with_keyfile(&req.new_pin, new_kf.as_deref())? {
-        let mut crypto_lock = state.crypto.write().await;
-        *crypto_lock = Some(crypto);
+/// 새 PIN 형식 검사 (6-32자리 영숫자), 문제가 있으면 메시지
+fn invalid_pin(pin: &str) -> Option<&'static str> {
+    if pin.len() < 6 || pin.len() > 32 {
+        return Some("PIN must be 6-32 characters");
     }
+    if !pin.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Some("PIN must be alphanumeric only");
+    }
+    None
+}
+
+/// base64 키파일 디코딩 (형식이 틀리면 Err)
+fn decode_keyfile(keyfile: Option<&str>) -> std::result::Result<Option<Vec<u8>>, ()> {
+    keyfile
+        .map(|kf| BASE64.decode(kf).map_err(|_| ()))
+        .transpose()
+}
 
+/// 복구 키 생성 요청 (현재 PIN + 키파일로 확인)
+#[derive(Deserialize)]
+pub struct RecoveryKeyRequest {
+    pub pin: String,
+    pub keyfile: Option<String>, // base64
+}
+
+/// 복구 키 생성 응답 (복구 키는 이때 한 번만 보여 줌)
+#[derive(Serialize)]
+pub struct RecoveryKeyResponse {
+    pub success: bool,
+    pub recovery_key: Option<String>,
+    pub message: String,
+}
+
+/// POST /api/security/recovery-key - 새 복구 키 생성 (이전 복구 키는 무효)
+pub async fn create_recovery_key(
+    State(state): State<AppState>,
+    Json(req): Json<RecoveryKeyRequest>,
+) -> Result<Json<RecoveryKeyResponse>> {
+    let fail = |message: &str| {
+        Ok(Json(RecoveryKeyResponse {
+            success: false,
+            recovery_key: None,
+            message: message.to_string(),
+        }))
+    };
+
+    if !state.security.read().await.pin_enabled {
+        return fail("PIN이 설정되지 않았습니다");
+    }
+    let Ok(keyfile) = decode_keyfile(req.keyfile.as_deref()) else {
+        return fail("Invalid keyfile format");
+    };
+    if !state
+        .guarded_verify("recovery-key", {
+            let (pin, keyfile) = (req.pin.clone(), keyfile.clone());
+            move |security| security.verify_vault_pin(&pin, keyfile.as_deref())
+        })
+        .await?
+    {
+        return fail("잘못된 PIN 또는 키파일입니다");
+    }
+
+    let mut security = state.security.write().await;
+    let recovery_key = match security.create_recovery_key(&req.pin, keyfile.as_deref()) {
+        Ok(recovery_key) => recovery_key,
+        Err(LazarusError::Decryption) => return fail("잘못된 PIN 또는 키파일입니다"),
+        Err(e) => return Err(e),
+    };
+    security.save(&state.data_dir.join("security.json"))?;
+    tracing::info!("복구 키 생성");
+
+    Ok(Json(RecoveryKeyResponse {
+        success: true,
+        recovery_key: Some(recovery_key),
+        message: "복구 키가 생성되었습니다".to_string(),
+    }))
+}
+
+/// 복구 키로 PIN 재설정 요청
+#[derive(Deserialize)]
+pub struct RecoverRequest {
+    pub recovery_key: String,
+    pub new_pin: String,
+    pub new_keyfile: Option<String>, // base64
+}
+
+/// POST /api/security/recover - 복구 키로 새 PIN 설정 후 잠금 해제
+pub async fn recover(
+    State(state): State<AppState>,
+    Json(req): Json<RecoverRequest>,
+) -> Result<Response> {
+    let fail = |message: &str| {
+        Ok(Json(ApiResponse {
+            success: false,
+            message: message.to_string(),
+        })
+        .into_response())
+    };
+
+    if let Some(message) = invalid_pin(&req.new_pin) {
+        return fail(message);
+    }
+    let Ok(new_kf) = decode_keyfile(req.new_keyfile.as_deref()) else {
+        return fail("Invalid keyfile format");
+    };
+
+    if !state.security.read().await.has_recovery_key() {
+        return fail("복구 키가 설정되지 않았습니다");
+    }
+
+    // 복구 키 검증 (PIN과 같은 시도 제한)
+    if !state
+        .guarded_verify("recover", {
+            let recovery_key = req.recovery_key.clone();
+            move |security| {
+                Ok(security
+                    .recovery
+                    .as_ref()
+                    .is_some_and(|recovery| recovery.unwrap(&recovery_key).is_ok()))
+            }
+        })
+        .await?
+    {
+        return fail("잘못된 복구 키입니다");
+    }
+
+    let mut security = state.security.write().await;
+    let crypto = match security.recover(&req.recovery_key, &req.new_pin, new_kf.as_deref()) {
+        Ok(crypto) => crypto,
+        Err(LazarusError::Decryption) => return fail("잘못된 복구 키입니다"),
+        Err(e) => return Err(e),
+    };
+    security.save(&state.data_dir.join("security.json"))?;
+    tracing::info!("복구 키로 PIN 재설정");
+
+    state.backup.write().await.set_crypto(Some(crypto.clone()));
+    *state.crypto.write().await = Some(crypto);
+    drop(security);
+
+    // 암호화된 노트를 메모리 인덱스에 올림
+    state.build_encrypted_index().await?;
+
+    Ok(unlocked_response(&state, "새 PIN이 설정되었습니다").await)
+}
+
+/// 삭제 정책 요청 (현재 PIN + 키파일로 확인)
+#[derive(Deserialize)]
+pub struct WipePolicyRequest {
+    pub pin: String,
+    pub keyfile: Option<String>, // base64
+    /// 0 또는 없으면 삭제하지 않음
+    pub wipe_after: Option<u32>,
+}
+
+/// 보안 정책 변경(삭제 정책, 자동 잠금, 강요 PIN)의 감사 로그 작업 이름
+///
+/// 셋이 같은 이름을 써서 로그만 보고는 강요 PIN을 설정했는지 알 수 없습니다.
+const SETTINGS_ACTION: &str = "security-settings";
+
+/// POST /api/security/wipe-policy - "연속 N번 틀리면 금고 삭제" 정책 설정
+pub async fn set_wipe_policy(
+    State(state): State<AppState>,
+    Json(req): Json<WipePolicyRequest>,
+) -> Result<Json<ApiResponse>> {
+    let fail = |message: String| {
+        Ok(Json(ApiResponse {
+            success: false,
+            message,
+        }))
+    };
+
+    let wipe_after = req.wipe_after.filter(|n| *n > 0);
+    if wipe_after.is_some_and(|n| n < MIN_WIPE_AFTER) {
+        return fail(format!("최소 {}회 이상이어야 합니다", MIN_WIPE_AFTER));
+    }
+    if !state.security.read().await.pin_enabled {
+        return fail("PIN이 설정되지 않았습니다".to_string());
+    }
+    let Ok(keyfile) = decode_keyfile(req.keyfile.as_deref()) else {
+        return fail("Invalid keyfile format".to_string());
+    };
+    if !state
+        .guarded_verify(SETTINGS_ACTION, {
+            let (pin, keyfile) = (req.pin.clone(), keyfile.clone());
+            move |security| security.verify_pin_with_keyfile(&pin, keyfile.as_deref())
+        })
+        .await?
+    {
+        return fail("잘못된 PIN 또는 키파일입니다".to_string());
+    }
+
+    {
+        let mut security = state.security.write().await;
+        security.wipe_after = wipe_after;
+        security.save(&state.data_dir.join("security.json"))?;
+    }
+    state
+        .pin_guard
+        .read()
+        .await
+        .record_event("policy", SETTINGS_ACTION)?;
+
+    tracing::info!("금고 삭제 정책: {:?}", wipe_after);
+    Ok(Json(ApiResponse {
+        success: true,
+        message: match wipe_after {
+            Some(n) => format!("연속 {}회 틀리면 암호화 노트를 삭제합니다", n),
+            None => "삭제 정책이 꺼졌습니다".to_string(),
+        },
+    }))
+}
+
+/// 자동 잠금 설정 요청 (현재 PIN + 키파일로 확인)
+#[derive(Deserialize)]
+pub struct IdleLockRequest {
+    pub pin: String,
+    pub keyfile: Option<String>, // base64
+    /// 유휴 시간 (분), 0이면 자동 잠금 끔
+    pub idle_minutes: u32,
+}
+
+/// 자동 잠금 유휴 시간 최대값 (하루)
+const MAX_IDLE_LOCK_MINS: u32 = 24 * 60;
+
+/// POST /api/security/idle-lock - 자동 잠금 유휴 시간 설정
+pub async fn set_idle_lock(
+    State(state): State<AppState>,
+    Json(req): Json<IdleLockRequest>,
+) -> Result<Json<ApiResponse>> {
+    let fail = |message: String| {
+        Ok(Json(ApiResponse {
+            success: false,
+            message,
+        }))
+    };
+
+    if req.idle_minutes > MAX_IDLE_LOCK_MINS {
+        return fail(format!(
+            "최대 {}분까지 설정할 수 있습니다",
+            MAX_IDLE_LOCK_MINS
+        ));
+    }
+    if !state.security.read().await.pin_enabled {
+        return fail("PIN이 설정되지 않았습니다".to_string());
+    }
+    let Ok(keyfile) = decode_keyfile(req.keyfile.as_deref()) else {
+        return fail("Invalid keyfile format".to_string());
+    };
+    if !state
+        .guarded_verify(SETTINGS_ACTION, {
+            let (pin, keyfile) = (req.pin.clone(), keyfile.clone());
+            move |security| security.verify_pin_with_keyfile(&pin, keyfile.as_deref())
+        })
+        .await?
+    {
+        return fail("잘못된 PIN 또는 키파일입니다".to_string());
+    }
+
+    {
+        let mut security = state.security.write().await;
+        security.idle_lock_mins = Some(req.idle_minutes);
+        security.save(&state.data_dir.join("security.json"))?;
+    }
+    state
+        .pin_guard
+        .read()
+        .await
+        .record_event("policy", SETTINGS_ACTION)?;
+
+    tracing::info!("자동 잠금 유휴 시간: {}분", req.idle_minutes);
     Ok(Json(ApiResponse {
         success: true,
-        message: "PIN이 설정되었습니다".to_string(),
+        message: match req.idle_minutes {
+            0 => "자동 잠금이 꺼졌습니다".to_string(),
+            mins => format!("{}분 동안 사용하지 않으면 잠급니다", mins),
+        },
     }))
 }
+
+/// 강요 PIN 설정 요청 (진짜 금고의 PIN + 키파일로 확인)
+#[derive(Deserialize)]
+pub struct DuressPinRequest {
+    pub pin: String,
+    pub keyfile: Option<String>, // base64
+    /// 강요 PIN, 비어 있으면 해제
+    pub duress_pin: Option<String>,
+    /// 강요 PIN으로 열면 진짜 금고의 키를 없앰
+    #[serde(default)]
+    pub destroy_real: bool,
+}
+
+/// POST /api/security/duress-pin - 강요 PIN 설정/해제
+///
+/// 설정할 때마다 미끼 금고는 빈 상태로 새로 시작합니다.
+pub async fn set_duress_pin(
+    State(state): State<AppState>,
+    Json(req): Json<DuressPinRequest>,
+) -> Result<Json<ApiResponse>> {
+    let fail = |message: &str| {
+        Ok(Json(ApiResponse {
+            success: false,
+            message: message.to_string(),
+        }))
+    };
+
+    let duress_pin = req.duress_pin.as_deref().filter(|p| !p.is_empty());
+    if let Some(duress_pin) = duress_pin {
+        if let Some(message) = invalid_pin(duress_pin) {
+            return fail(message);
+        }
+        if duress_pin == req.pin {
+            return fail("강요 PIN은 현재 PIN과 달라야 합니다");
+        }
+    }
+    if !state.security.read().await.pin_enabled {
+        return fail("PIN이 설정되지 않았습니다");
+    }
+    let Ok(keyfile) = decode_keyfile(req.keyfile.as_deref()) else {
+        return fail("Invalid keyfile format");
+    };
+    // 진짜 금고를 여는 PIN만 허용 (강요 PIN으로는 바꿀 수 없음)
+    if !state
+        .guarded_verify(SETTINGS_ACTION, {
+            let (pin, keyfile) = (req.pin.clone(), keyfile.clone());
+            move |security| security.verify_vault_pin(&pin, keyfile.as_deref())
+        })
+        .await?
+    {
+        return fail("잘못된 PIN 또는 키파일입니다");
+    }
+
+    {
+        let mut security = state.security.write().await;
+        security.set_duress_pin(
+            &req.pin,
+            keyfile.as_deref(),
+            duress_pin.map(|p| (p, req.destroy_real)),
+        )?;
+        security.save(&state.data_dir.join("security.json"))?;
+    }
+    state
+        .pin_guard
+        .read()
+        .await
+        .record_event("policy", SETTINGS_ACTION)?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        message: match duress_pin {
+            Some(_) => "강요 PIN이 설정되었습니다".to_string(),
+            None => "강요 PIN이 해제되었습니다".to_string(),
+        },
+    }))
+}
+
+/// 감사 로그에서 한 번에 돌려줄 최대 줄 수
+const AUDIT_LIMIT: usize = 200;
+
+/// GET /api/security/audit - 최근 PIN 시도/보안 이벤트 (잠금 해제 상태에서만)
+pub async fn audit_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AuditEvent>>> {
+    if state.is_locked_for(&headers).await {
+        return Err(LazarusError::Permission("잠금 상태입니다".to_string()));
+    }
+    Ok(Json(state.pin_guard.read().await.audit_log(AUDIT_LIMIT)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::guard::FREE_ATTEMPTS;
+    use crate::db::{BackupManager, BackupSchedule, Note};
+    use std::path::{Path, PathBuf};
+
+    /// 두 번째 백업 대상을 설정하고, 금고를 지울 때 함께 지워야 할 WAL 사본을 만듦
+    async fn mirror_and_copies(state: &AppState, dir: &Path) -> (PathBuf, Vec<PathBuf>) {
+        let mirror = dir.join("mirror");
+        std::fs::create_dir(&mirror).unwrap();
+        state
+            .backup
+            .write()
+            .await
+            .set_schedule(BackupSchedule {
+                mirror_dir: Some(mirror.clone()),
+                ..Default::default()
+            })
+            .unwrap();
+
+        let copies = vec![
+            dir.join("notes.lazarus.v1.bak"),
+            dir.join("notes.lazarus.corrupt"),
+            dir.join("notes.lazarus.20260101-000000.corrupt"),
+        ];
+        for copy in &copies {
+            std::fs::copy(dir.join("notes.lazarus"), copy).unwrap();
+        }
+        (mirror, copies)
+    }
+
+    /// 두 번째 대상에 남은 백업 수
+    fn mirrored_backups(mirror: &Path) -> usize {
+        BackupManager::new(mirror.join("notes.lazarus"), mirror.to_path_buf())
+            .list_backups()
+            .unwrap()
+            .len()
+    }
+
+    #[tokio::test]
+    async fn test_throttle_then_wipe_policy() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let crypto = state
+            .security
+            .write()
+            .await
+            .change_pin(None, "123456", None)
+            .unwrap()
+            .crypto;
+        {
+            let mut db = state.db.write().await;
+            let mut secret = Note::new(0, "비밀".to_string(), "취재원".to_string());
+            secret.encrypted = true;
+            db.save_encrypted(&secret, None, Some(&crypto)).unwrap();
+            db.save(
+                &Note::new(0, "공개".to_string(), "장보기".to_string()),
+                None,
+            )
+            .unwrap();
+        }
+        let (mirror, copies) = mirror_and_copies(&state, tmp.path()).await;
+        state.run_backup().await.unwrap().unwrap();
+        assert!(mirrored_backups(&mirror) > 0);
+
+        let wrong = |security: &SecurityConfig| security.verify_pin("000000");
+
+        // 무료 시도를 넘기면 검증 없이 거절 (재시작해도 유지)
+        for _ in 0..FREE_ATTEMPTS {
+            assert!(!state.guarded_verify("unlock", wrong).await.unwrap());
+        }
+        assert!(matches!(
+            state.guarded_verify("unlock", wrong).await,
+            Err(LazarusError::TooManyAttempts { .. })
+        ));
+        let reopened = crate::crypto::PinGuard::open(tmp.path()).unwrap();
+        assert_eq!(reopened.failures(), FREE_ATTEMPTS);
+
+        // 삭제 정책: 연속 실패가 기준에 닿으면 키, 암호화 노트, 백업 삭제
+        state.pin_guard.write().await.reset().unwrap();
+        state.security.write().await.wipe_after = Some(MIN_WIPE_AFTER);
+        for _ in 0..MIN_WIPE_AFTER {
+            assert!(!state.guarded_verify("unlock", wrong).await.unwrap());
+        }
+
+        assert!(!state.security.read().await.pin_enabled);
+        let reloaded = SecurityConfig::load(&tmp.path().join("security.json")).unwrap();
+        assert!(reloaded.header.is_none());
+        assert_eq!(state.db.read().await.count(), 1);
+        assert!(state.backup.read().await.list_backups().unwrap().is_empty());
+        assert_eq!(mirrored_backups(&mirror), 0);
+        assert!(copies.iter().all(|copy| !copy.exists()));
+        assert_eq!(state.pin_guard.read().await.failures(), 0);
+
+        let log = state.pin_guard.read().await.audit_log(20).unwrap();
+        assert_eq!(log[0].event, "wipe");
+        assert!(log.iter().any(|e| e.event == "blocked"));
+    }
+
+    #[tokio::test]
+    async fn test_session_cookie_and_idle_lock() {
+        use axum::body::Body;
+        use axum::http::{Request, StatusCode};
+        use tower::Service;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        state
+            .security
+            .write()
+            .await
+            .change_pin(None, "123456", None)
+            .unwrap();
+
+        let response = unlock(
+            State(state.clone()),
+            Json(PinRequest {
+                pin: "123456".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        let set_cookie = response.headers()[header::SET_COOKIE].to_str().unwrap();
+        assert!(set_cookie.contains("HttpOnly"));
+        let cookie = set_cookie.split(';').next().unwrap().to_string();
+
+        // 키가 메모리에 있어도 쿠키 없는 브라우저는 잠김
+        let get_notes = |cookie: Option<&str>| {
+            let mut request = Request::builder().uri("/api/notes");
+            if let Some(cookie) = cookie {
+                request = request.header(header::COOKIE, cookie);
+            }
+            // Router는 항상 준비 상태라 poll_ready 없이 바로 호출
+            crate::web::router::create_router(state.clone())
+                .call(request.body(Body::empty()).unwrap())
+        };
+        assert_eq!(
+            get_notes(None).await.unwrap().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            get_notes(Some(&cookie)).await.unwrap().status(),
+            StatusCode::OK
+        );
+
+        // 유휴 시간이 지나면 세션과 키 모두 사라짐
+        let now = chrono::Utc::now();
+        assert!(!state.lock_if_idle(now).await);
+        let idle = now + chrono::Duration::minutes(crate::crypto::DEFAULT_IDLE_LOCK_MINS as i64);
+        assert!(state.lock_if_idle(idle).await);
+        assert!(state.crypto.read().await.is_none());
+        assert_eq!(
+            get_notes(Some(&cookie)).await.unwrap().status(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        // 0이면 자동 잠금 끔
+        state.security.write().await.idle_lock_mins = Some(0);
+        let _ = unlock(
+            State(state.clone()),
+            Json(PinRequest {
+                pin: "123456".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(!state.lock_if_idle(idle + chrono::Duration::days(1)).await);
+        assert!(state.crypto.read().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_duress_pin_opens_decoy_vault() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().to_path_buf(), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let security_path = tmp.path().join("security.json");
+        let real = {
+            let mut security = state.security.write().await;
+            let unlocked = security.change_pin(None, "123456", None).unwrap();
+            security.save(&security_path).unwrap();
+            unlocked.crypto
+        };
+        let (real_id, public_id) = {
+            let mut db = state.db.write().await;
+            let mut secret = Note::new(0, "비밀".to_string(), "취재원".to_string());
+            secret.encrypted = true;
+            let real_id = db.save_encrypted(&secret, None, Some(&real)).unwrap();
+            let public = Note::new(0, "공개".to_string(), "장보기".to_string());
+            (real_id, db.save(&public, None).unwrap())
+        };
+        let (mirror, copies) = mirror_and_copies(&state, tmp.path()).await;
+        state.run_backup().await.unwrap().unwrap();
+        assert!(mirrored_backups(&mirror) > 0);
+
+        let spare_len = || {
+            let security = SecurityConfig::load(&security_path).unwrap();
+            let spare = security.header.unwrap().spare.unwrap();
+            (spare.salt.len(), spare.sealed.len())
+        };
+        let before = spare_len();
+
+        let set_duress = |pin: &str, destroy_real: bool| {
+            set_duress_pin(
+                State(state.clone()),
+                Json(DuressPinRequest {
+                    pin: pin.to_string(),
+                    keyfile: None,
+                    duress_pin: Some("654321".to_string()),
+                    destroy_real,
+                }),
+            )
+        };
+        let open = |pin: &str| {
+            unlock(
+                State(state.clone()),
+                Json(PinRequest {
+                    pin: pin.to_string(),
+                }),
+            )
+        };
+
+        assert!(set_duress("123456", false).await.unwrap().success);
+        assert_eq!(spare_len(), before);
+        // 감사 로그에는 자동 잠금 등 다른 보안 설정 변경과 같은 모양으로만 남음
+        let log = state.pin_guard.read().await.audit_log(20).unwrap();
+        let idle = set_idle_lock(
+            State(state.clone()),
+            Json(IdleLockRequest {
+                pin: "123456".to_string(),
+                keyfile: None,
+                idle_minutes: 30,
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(idle.success);
+        let idle_log = state.pin_guard.read().await.audit_log(20).unwrap();
+        let shape = |log: &[crate::crypto::AuditEvent]| {
+            log.iter()
+                .take(2)
+                .map(|e| (e.event.clone(), e.action.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(shape(&log), shape(&idle_log));
+        assert_eq!(log[0].action, SETTINGS_ACTION);
+        assert!(log.iter().all(|e| !e.action.contains("duress")));
+
+        // 강요 PIN: 진짜 암호화 노트는 없는 것처럼 보이고 미끼 노트는 따로 저장
+        open("654321").await.unwrap();
+        let decoy = state.crypto.read().await.clone().unwrap();
+        assert_ne!(decoy.key_id(), real.key_id());
+        let decoy_id = {
+            let mut db = state.db.write().await;
+            assert_eq!(db.list_ids(), vec![public_id]);
+            assert!(db.get_decrypted(real_id, Some(&decoy)).unwrap().is_none());
+            let mut note = Note::new(0, "일기".to_string(), "평범한 하루".to_string());
+            note.encrypted = true;
+            db.save_encrypted(&note, None, Some(&decoy)).unwrap()
+        };
+        // DB 검사/압축 결과에도 숨긴 노트는 세지 않음
+        let check = crate::web::handlers::notes::check_db(State(state.clone()))
+            .await
+            .unwrap();
+        assert_eq!(check.report.notes, 2);
+        let compacted = crate::web::handlers::notes::compact_db(
+            State(state.clone()),
+            axum::extract::Query(crate::web::handlers::notes::CompactParams {
+                keep_last: None,
+                keep_days: None,
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(compacted.record_count, 2);
+        // 미끼 금고가 열려 있는 동안에는 백업을 미룸 (평문이나 두 번째 키로 쓰지 않음)
+        assert!(state.backup_now().await.unwrap().is_none());
+        // 미끼 금고에서는 강요 PIN, 복구 키, PIN 제거 모두 틀린 PIN처럼 거절
+        assert!(!set_duress("654321", false).await.unwrap().success);
+        let recovery = create_recovery_key(
+            State(state.clone()),
+            Json(RecoveryKeyRequest {
+                pin: "654321".to_string(),
+                keyfile: None,
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(!recovery.success);
+        assert_eq!(recovery.message, "잘못된 PIN 또는 키파일입니다");
+        let removed = remove_pin(
+            State(state.clone()),
+            Json(PinRequest {
+                pin: "654321".to_string(),
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(!removed.success);
+        assert_eq!(removed.message, "잘못된 PIN입니다");
+        assert!(SecurityConfig::load(&security_path)
+            .unwrap()
+            .header
+            .is_some());
+
+        // 진짜 PIN: 미끼 노트가 보이지 않음
+        open("123456").await.unwrap();
+        {
+            let db = state.db.read().await;
+            assert_eq!(db.count(), 2);
+            assert!(db.get_decrypted(decoy_id, Some(&real)).unwrap().is_none());
+            assert!(db.get_decrypted(real_id, Some(&real)).unwrap().is_some());
+        }
+
+        // 파기 설정: 강요 PIN으로 열면 진짜 키, 복구 키, 진짜 노트, 백업이 사라짐
+        assert!(set_duress("123456", true).await.unwrap().success);
+        state
+            .security
+            .write()
+            .await
+            .create_recovery_key("123456", None)
+            .unwrap();
+        state.lock_vault().await;
+        open("654321").await.unwrap();
+
+        let reloaded = SecurityConfig::load(&security_path).unwrap();
+        assert!(reloaded.recovery.is_none());
+        assert!(reloaded.unlock("123456", None).unwrap().is_none());
+        // 이후로는 평범한 금고: 다시 열어도 더 지우지 않음
+        let slot = reloaded.unlock("654321", None).unwrap().unwrap();
+        assert!(slot.decoy && !slot.destroy_real);
+        assert_eq!(spare_len(), before);
+
+        state.lock_vault().await;
+        assert_eq!(state.db.read().await.list_ids(), vec![public_id]);
+        assert!(state.backup.read().await.list_backups().unwrap().is_empty());
+        assert_eq!(mirrored_backups(&mirror), 0);
+        assert!(copies.iter().all(|copy| !copy.exists()));
+    }
+}
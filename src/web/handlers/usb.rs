@@ -6,6 +6,8 @@ use axum::{extract::State, http::StatusCode, response::Json};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+use crate::db::Note;
+use crate::search::embed_note;
 use crate::sync::{LazarusUsb, UsbDetector, UsbManifest};
 use crate::web::state::AppState;
 
@@ -239,6 +241,20 @@ pub struct ImportResponse {
     pub message: String,
 }
 
+/// USB에서 받은 노트를 검색/링크 인덱스에 반영 (실패해도 노트는 저장돼 있음)
+async fn index_saved(state: &AppState, notes: &[Note]) {
+    for note in notes {
+        if let Err(e) = state.index_note(note).await {
+            tracing::warn!("가져온 노트 인덱싱 실패: id={}, {}", note.id, e);
+        }
+    }
+    let mut index = state.link_index.write().await;
+    for note in notes {
+        index.register_note(note.id, &note.title);
+        index.update_links(note.id, &note.content);
+    }
+}
+
 /// POST /api/usb/import - USB에서 노트 가져오기
 pub async fn import_from_usb(
     State(state): State<AppState>,
@@ -261,17 +277,41 @@ pub async fn import_from_usb(
 
     match import_notes(usb_path) {
         Ok(notes) => {
-            let count = notes.len();
-            // DB에 저장
+            let total = notes.len();
+            // DB에 저장 (batch 끝의 fsync 실패는 아무것도 남지 않았을 수 있으므로 에러)
             let mut db = state.db.write().await;
-            for note in notes {
-                let _ = db.save(&note, None);
-            }
+            let saved_notes = db
+                .batch(|db| {
+                    let mut saved = Vec::with_capacity(notes.len());
+                    for note in &notes {
+                        match db.save(note, embed_note(note)) {
+                            Ok(id) => saved.push(Note { id, ..note.clone() }),
+                            Err(e) => tracing::warn!("노트 가져오기 실패: {} ({})", note.title, e),
+                        }
+                    }
+                    Ok(saved)
+                })
+                .map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse {
+                            success: false,
+                            message: format!("가져오기 실패: {}", e),
+                        }),
+                    )
+                })?;
+            drop(db);
+            index_saved(&state, &saved_notes).await;
+            let saved = saved_notes.len();
 
             Ok(Json(ImportResponse {
-                success: true,
-                count,
-                message: format!("{} 노트 가져오기 완료", count),
+                success: saved == total,
+                count: saved,
+                message: if saved == total {
+                    format!("{} 노트 가져오기 완료", saved)
+                } else {
+                    format!("{}/{} 노트만 가져왔습니다", saved, total)
+                },
             }))
         }
         Err(e) => Err((
@@ -362,9 +402,13 @@ pub async fn sync_with_usb(
     let save_fn = |note: &crate::db::Note| -> Result<(), crate::sync::SyncError> {
         let rt = tokio::runtime::Handle::current();
         rt.block_on(async {
-            let mut db = state_clone.db.write().await;
-            db.save(note, None)
+            let id = state_clone
+                .db
+                .write()
+                .await
+                .save(note, embed_note(note))
                 .map_err(|e| crate::sync::SyncError::Io(std::io::Error::other(e.to_string())))?;
+            index_saved(&state_clone, &[Note { id, ..note.clone() }]).await;
             Ok(())
         })
     };
@@ -436,3 +480,37 @@ pub async fn sync_with_usb(
         message: format!("Sync Complete: ↑{} ↓{}", total_uploaded, total_downloaded),
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_imported_notes_are_searchable() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let state = AppState::new(tmp.path().join("data"), Vec::new(), 1 << 20)
+            .await
+            .unwrap();
+        let usb = tmp.path().join("usb");
+        std::fs::create_dir(&usb).unwrap();
+        UsbDetector::init_usb(&usb).unwrap();
+        let note = Note::new(1, "Field guide".to_string(), "mushrooms".to_string());
+        crate::sync::export_notes(&usb, &[note]).unwrap();
+
+        let Ok(response) = import_from_usb(
+            State(state.clone()),
+            Json(ImportRequest {
+                usb_path: usb.display().to_string(),
+            }),
+        )
+        .await
+        else {
+            panic!("가져오기 실패");
+        };
+        assert_eq!(response.count, 1);
+
+        let results = state.search.read().await.search("mushrooms", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Field guide");
+    }
+}
@@ -1,6 +1,7 @@
 //! 위키 핸들러 (다중 ZIM 지원)
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path as FsPath, PathBuf};
+use std::time::Duration;
 
 use askama::Template;
 use axum::{
@@ -12,12 +13,17 @@ use serde::Deserialize;
 
 use crate::error::{LazarusError, Result};
 use crate::i18n::all_translations;
-use crate::web::state::AppState;
+use crate::search::zim_index::html_to_text;
+use crate::search::{ZimFullTextIndex, ZimIndexProgress};
+use crate::web::state::{AppState, ZimVerifyStatus};
+use crate::zim::ZimReader;
 
 #[derive(Deserialize)]
 pub struct WikiSearchParams {
     pub q: Option<String>,
     pub zim: Option<String>,
+    /// "title" (기본) 또는 "fulltext"
+    pub mode: Option<String>,
 }
 
 /// 템플릿용 검색 결과 항목
@@ -25,8 +31,28 @@ pub struct WikiSearchEntry {
     pub title: String,
     pub url: String,
     pub url_encoded: String,
+    /// 본문 검색 스니펫 (HTML, 제목 검색이면 빈 문자열)
+    pub snippet: String,
+    /// 결과가 나온 ZIM 이름
+    pub source: String,
+    /// 결과가 나온 ZIM 제목 (메타데이터, 없으면 이름)
+    pub source_title: String,
+    /// 병합 정렬용 점수 (ZIM별로 0~3 범위로 정규화)
+    pub score: f32,
 }
 
+/// 제목 검색 결과 수
+const TITLE_LIMIT: usize = 50;
+
+/// 본문 검색 결과 수
+const FULLTEXT_LIMIT: usize = 30;
+
+/// 모든 ZIM을 검색할 때의 선택 값
+const ALL_ZIMS: &str = "*";
+
+/// 통합 검색 시간 제한 (늦은 ZIM의 결과는 버림)
+const FEDERATED_BUDGET: Duration = Duration::from_secs(2);
+
 /// 위키 검색 템플릿
 #[derive(Template)]
 #[template(path = "wiki_search.html")]
@@ -38,6 +64,9 @@ struct WikiSearchTemplate {
     results: Vec<WikiSearchEntry>,
     zim_options: Vec<(String, bool)>,
     selected_zim: String,
+    fulltext: bool,
+    fulltext_available: bool,
+    federated: bool,
 }
 
 /// 위키 문서 템플릿
@@ -68,7 +97,45 @@ struct WikiManageTemplate {
     lang: String,
     zim_count: usize,
     zim_dir: String,
-    zim_list: Vec<(String, String)>,
+    zim_list: Vec<ZimListItem>,
+}
+
+/// ZIM 목록 항목 (메타데이터 포함)
+#[derive(serde::Serialize)]
+pub struct ZimListItem {
+    pub name: String,
+    pub path: String,
+    /// 메타데이터 제목 (없으면 파일 이름)
+    pub title: String,
+    pub description: String,
+    pub language: String,
+    pub creator: String,
+    pub date: String,
+    pub article_count: u32,
+    pub has_illustration: bool,
+}
+
+/// 로드된 모든 ZIM의 목록과 메타데이터
+async fn collect_zim_list(state: &AppState) -> Vec<ZimListItem> {
+    let zims = state.zims.read().await;
+    let mut items = Vec::with_capacity(zims.len());
+
+    for zim in zims.iter() {
+        let meta = zim.reader.read().await.metadata();
+        items.push(ZimListItem {
+            name: zim.name.clone(),
+            path: zim.path.display().to_string(),
+            title: meta.title.unwrap_or_else(|| zim.name.clone()),
+            description: meta.description.unwrap_or_default(),
+            language: meta.language.unwrap_or_default(),
+            creator: meta.creator.unwrap_or_default(),
+            date: meta.date.unwrap_or_default(),
+            article_count: meta.article_count,
+            has_illustration: meta.has_illustration,
+        });
+    }
+
+    items
 }
 
 /// GET /wiki/search
@@ -94,39 +161,50 @@ pub async fn search_wiki(
         .clone()
         .unwrap_or_else(|| zim_names.first().cloned().unwrap_or_default());
 
-    let selected_zim = state
-        .get_zim_by_name(&selected_name)
-        .await
-        .or(state.get_zim().await)
-        .ok_or_else(|| LazarusError::ZimNotFound {
-            title: "ZIM 파일이 로드되지 않음".to_string(),
-        })?;
-
-    let zim = selected_zim.read().await;
     let query = params.q.clone().unwrap_or_default();
-
-    let results = if query.is_empty() {
-        zim.list_articles(50)?
+    let fulltext = params.mode.as_deref() == Some("fulltext");
+    let federated = selected_name == ALL_ZIMS;
+
+    let (search_results, fulltext_available, selected_name) = if federated {
+        let available = zim_names.iter().any(|name| {
+            ZimFullTextIndex::exists(&ZimFullTextIndex::path_for(&state.zim_dir, name))
+        });
+        let results = federated_search(&state, &query, fulltext).await;
+        (results, available, selected_name)
     } else {
-        zim.search_fuzzy(&query, 50)?
-    };
+        let selected_zim = match state.get_zim_by_name(&selected_name).await {
+            Some(zim) => zim,
+            None => state
+                .get_zim()
+                .await
+                .ok_or_else(|| LazarusError::ZimNotFound {
+                    title: "ZIM 파일이 로드되지 않음".to_string(),
+                })?,
+        };
+        // 알 수 없는 이름이면 첫 번째 ZIM으로 대체되므로 이름도 맞춰줌
+        let selected_name = if zim_names.contains(&selected_name) {
+            selected_name
+        } else {
+            zim_names.first().cloned().unwrap_or_default()
+        };
 
-    let search_results: Vec<WikiSearchEntry> = results
-        .iter()
-        .map(|entry| WikiSearchEntry {
-            title: entry.title.clone(),
-            url: entry.url.clone(),
-            url_encoded: urlencoding::encode(&entry.url).to_string(),
-        })
-        .collect();
+        let zim = selected_zim.read().await;
+        let available =
+            ZimFullTextIndex::exists(&ZimFullTextIndex::path_for(&state.zim_dir, &selected_name));
+        let results = search_one_zim(&zim, &state.zim_dir, &selected_name, &query, fulltext)?;
+        (results, available, selected_name)
+    };
 
     let lang = state.get_lang().await;
     let t = all_translations(lang);
 
-    let zim_options: Vec<(String, bool)> = zim_names
+    let mut zim_options: Vec<(String, bool)> = zim_names
         .iter()
         .map(|name| (name.clone(), name == &selected_name))
         .collect();
+    if zim_names.len() > 1 {
+        zim_options.insert(0, (ALL_ZIMS.to_string(), federated));
+    }
 
     let template = WikiSearchTemplate {
         t,
@@ -136,11 +214,139 @@ pub async fn search_wiki(
         results: search_results,
         zim_options,
         selected_zim: selected_name,
+        fulltext,
+        fulltext_available,
+        federated,
     };
 
     Ok(Html(template.render().unwrap_or_default()).into_response())
 }
 
+/// ZIM 하나에서 검색 (제목 또는 본문)
+fn search_one_zim(
+    zim: &ZimReader,
+    zim_dir: &FsPath,
+    name: &str,
+    query: &str,
+    fulltext: bool,
+) -> Result<Vec<WikiSearchEntry>> {
+    let source_title = zim.metadata().title.unwrap_or_else(|| name.to_string());
+    let index_path = ZimFullTextIndex::path_for(zim_dir, name);
+
+    if fulltext && ZimFullTextIndex::exists(&index_path) {
+        let index = ZimFullTextIndex::open(&index_path)?;
+        let hits = index.search(query, FULLTEXT_LIMIT, |entry| {
+            let entry = zim.read_dir_entry(entry).ok()?;
+            let bytes = zim.read_entry_content(&entry).ok()??;
+            Some(html_to_text(&String::from_utf8_lossy(&bytes)))
+        })?;
+
+        // BM25 점수는 인덱스마다 척도가 달라 최고 점수 기준으로 정규화
+        let top = hits
+            .first()
+            .map(|h| h.score)
+            .unwrap_or(1.0)
+            .max(f32::EPSILON);
+
+        return Ok(hits
+            .into_iter()
+            .map(|hit| WikiSearchEntry {
+                url_encoded: urlencoding::encode(&hit.url).to_string(),
+                score: 3.0 * hit.score / top,
+                title: hit.title,
+                url: hit.url,
+                snippet: hit.snippet,
+                source: name.to_string(),
+                source_title: source_title.clone(),
+            })
+            .collect());
+    }
+
+    let results = if query.is_empty() {
+        zim.list_articles(TITLE_LIMIT)?
+    } else {
+        zim.search_fuzzy(query, TITLE_LIMIT)?
+    };
+
+    let query_lower = query.to_lowercase();
+    Ok(results
+        .into_iter()
+        .enumerate()
+        .map(|(rank, entry)| WikiSearchEntry {
+            score: title_score(&query_lower, &entry.title, rank),
+            url_encoded: urlencoding::encode(&entry.url).to_string(),
+            title: entry.title,
+            url: entry.url,
+            snippet: String::new(),
+            source: name.to_string(),
+            source_title: source_title.clone(),
+        })
+        .collect())
+}
+
+/// 제목 검색 결과 점수 (정확 일치 > 접두사 > 퍼지, 같은 등급이면 순위순)
+fn title_score(query_lower: &str, title: &str, rank: usize) -> f32 {
+    let title_lower = title.to_lowercase();
+    let base = if title_lower == query_lower {
+        3.0
+    } else if title_lower.starts_with(query_lower) {
+        2.0
+    } else {
+        1.0
+    };
+    base - (rank as f32 * 0.001)
+}
+
+/// 로드된 모든 ZIM을 동시에 검색해 점수순으로 병합
+///
+/// 제한 시간 안에 끝나지 않은 ZIM의 결과는 버립니다.
+async fn federated_search(state: &AppState, query: &str, fulltext: bool) -> Vec<WikiSearchEntry> {
+    let targets: Vec<(String, _)> = {
+        let zims = state.zims.read().await;
+        zims.iter()
+            .map(|z| (z.name.clone(), z.reader.clone()))
+            .collect()
+    };
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for (name, reader) in targets {
+        let zim_dir = state.zim_dir.clone();
+        let query = query.to_string();
+        tasks.spawn_blocking(move || {
+            let zim = reader.blocking_read();
+            search_one_zim(&zim, &zim_dir, &name, &query, fulltext).unwrap_or_else(|e| {
+                tracing::warn!("통합 검색 실패: {} - {}", name, e);
+                Vec::new()
+            })
+        });
+    }
+
+    let deadline = tokio::time::Instant::now() + FEDERATED_BUDGET;
+    let mut merged = Vec::new();
+    loop {
+        match tokio::time::timeout_at(deadline, tasks.join_next()).await {
+            Ok(Some(Ok(results))) => merged.extend(results),
+            Ok(Some(Err(e))) => tracing::warn!("통합 검색 작업 실패: {}", e),
+            Ok(None) => break,
+            Err(_) => {
+                tracing::debug!("통합 검색 시간 초과: {}개 ZIM 미완료", tasks.len());
+                break;
+            }
+        }
+    }
+    // 블로킹 작업은 취소할 수 없으므로 남은 작업은 백그라운드에서 끝나게 둠
+    tasks.detach_all();
+
+    merged.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    merged.truncate(TITLE_LIMIT);
+    merged
+}
+
 /// GET /wiki/*path
 pub async fn get_article(
     State(state): State<AppState>,
@@ -278,9 +484,77 @@ fn guess_mime(url: &str) -> &'static str {
     }
 }
 
-/// GET /api/wiki/list
-pub async fn list_zims(State(state): State<AppState>) -> Result<axum::Json<Vec<String>>> {
-    Ok(axum::Json(state.zim_names().await))
+#[derive(Deserialize)]
+pub struct ZimListParams {
+    /// true면 메타데이터 포함 항목 목록
+    #[serde(default)]
+    pub details: bool,
+}
+
+/// GET /api/zim/list - ZIM 이름 목록 (`?details=true`면 메타데이터 포함)
+pub async fn list_zims(
+    State(state): State<AppState>,
+    Query(params): Query<ZimListParams>,
+) -> Result<Response> {
+    if params.details {
+        Ok(axum::Json(collect_zim_list(&state).await).into_response())
+    } else {
+        Ok(axum::Json(state.zim_names().await).into_response())
+    }
+}
+
+/// GET /api/zim/:name/verify
+pub async fn get_verify_status(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<axum::Json<ZimVerifyStatus>> {
+    let jobs = state.zim_verify_jobs.read().await;
+    jobs.get(&name)
+        .map(|job| axum::Json(job.status()))
+        .ok_or(LazarusError::NotFound(name))
+}
+
+/// POST /api/zim/:name/verify
+///
+/// 검사는 백그라운드에서 진행되며, 같은 경로의 GET으로 진행 상황과 보고서를 확인합니다.
+pub async fn verify_zim(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<axum::Json<ZimVerifyStatus>> {
+    if state.get_zim_by_name(&name).await.is_none() {
+        return Err(LazarusError::NotFound(name));
+    }
+    state.start_zim_verify(&name).await?;
+
+    let jobs = state.zim_verify_jobs.read().await;
+    jobs.get(&name)
+        .map(|job| axum::Json(job.status()))
+        .ok_or(LazarusError::NotFound(name))
+}
+
+/// GET /api/zim/:name/illustration
+pub async fn get_illustration(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Response> {
+    let reader = state
+        .get_zim_by_name(&name)
+        .await
+        .ok_or_else(|| LazarusError::NotFound(name.clone()))?;
+
+    let illustration = reader.read().await.illustration()?;
+    match illustration {
+        Some(bytes) => Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "image/png"),
+                (header::CACHE_CONTROL, "max-age=86400"),
+            ],
+            bytes,
+        )
+            .into_response()),
+        None => Err(LazarusError::NotFound(name)),
+    }
 }
 
 /// POST /api/zim/reload
@@ -401,9 +675,58 @@ pub struct ZimDirResult {
     pub path: String,
 }
 
+#[derive(serde::Serialize)]
+pub struct ZimIndexStatus {
+    pub name: String,
+    pub running: bool,
+    pub exists: bool,
+    pub progress: ZimIndexProgress,
+}
+
+/// ZIM 본문 인덱스 상태 조회
+async fn zim_index_status(state: &AppState, name: &str) -> Result<ZimIndexStatus> {
+    let path = ZimFullTextIndex::path_for(&state.zim_dir, name);
+    let exists = ZimFullTextIndex::exists(&path);
+    let progress = if exists {
+        ZimFullTextIndex::open(&path)?.progress()?
+    } else {
+        ZimIndexProgress::default()
+    };
+
+    Ok(ZimIndexStatus {
+        name: name.to_string(),
+        running: state.zim_index_jobs.read().await.contains(name),
+        exists,
+        progress,
+    })
+}
+
+/// GET /api/zim/:name/index
+pub async fn get_index_status(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<axum::Json<ZimIndexStatus>> {
+    if state.get_zim_by_name(&name).await.is_none() {
+        return Err(LazarusError::NotFound(name));
+    }
+    Ok(axum::Json(zim_index_status(&state, &name).await?))
+}
+
+/// POST /api/zim/:name/index
+pub async fn build_index(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<axum::Json<ZimIndexStatus>> {
+    if state.get_zim_by_name(&name).await.is_none() {
+        return Err(LazarusError::NotFound(name));
+    }
+    state.start_zim_indexing(&name).await?;
+    Ok(axum::Json(zim_index_status(&state, &name).await?))
+}
+
 /// GET /wiki/manage
 pub async fn manage_zims(State(state): State<AppState>) -> Result<Html<String>> {
-    let zim_list = state.zim_list().await;
+    let zim_list = collect_zim_list(&state).await;
     let zim_dir = state.zim_dir.display().to_string();
     let lang = state.get_lang().await;
     let t = all_translations(lang);
@@ -419,3 +742,36 @@ pub async fn manage_zims(State(state): State<AppState>) -> Result<Html<String>>
 
     Ok(Html(template.render().unwrap_or_default()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_score_ranking() {
+        let exact = title_score("apple", "Apple", 5);
+        let prefix = title_score("apple", "Apple pie", 0);
+        let fuzzy = title_score("apple", "Appel", 0);
+        assert!(exact > prefix);
+        assert!(prefix > fuzzy);
+        // 같은 등급이면 원래 순위 유지
+        assert!(title_score("apple", "Apple pie", 0) > title_score("apple", "Apple tree", 1));
+    }
+
+    #[test]
+    fn test_search_one_zim_labels_source() {
+        use crate::zim::tests::{build_test_zim, open_test_zim};
+
+        let entries: &[(char, &str, &str, &[u8])] = &[
+            ('C', "Apple", "Apple", b"a"),
+            ('M', "Title", "Title", b"Fruit Encyclopedia"),
+        ];
+        let (dir, zim) = open_test_zim(&build_test_zim(entries, 1));
+
+        let results = search_one_zim(&zim, dir.path(), "fruit", "apple", false).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source, "fruit");
+        assert_eq!(results[0].source_title, "Fruit Encyclopedia");
+        assert_eq!(results[0].score, 3.0);
+    }
+}
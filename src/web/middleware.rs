@@ -12,7 +12,7 @@ use crate::web::state::AppState;
 
 /// PIN 잠금 체크 미들웨어
 ///
-/// PIN이 설정되어 있고 잠금 상태면 401 반환
+/// PIN이 설정되어 있고 잠금 상태이거나 세션 쿠키가 없으면 401 반환
 /// 예외: /api/security/*, /static/*, /
 pub async fn require_unlock(
     State(state): State<AppState>,
@@ -34,9 +34,9 @@ pub async fn require_unlock(
     }
     drop(security); // 락 해제
 
-    // crypto가 있으면 잠금 해제된 상태
-    let crypto = state.crypto.read().await;
-    if crypto.is_some() {
+    // 키가 메모리에 있고 이 브라우저가 잠금을 푼 세션이어야 통과 (유휴 시간 갱신)
+    let unlocked = state.crypto.read().await.is_some();
+    if unlocked && state.has_session(request.headers(), true).await {
         return next.run(request).await;
     }
 
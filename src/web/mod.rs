@@ -5,4 +5,5 @@
 pub mod handlers;
 pub mod middleware;
 pub mod router;
+pub mod session;
 pub mod state;
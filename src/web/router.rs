@@ -20,9 +20,40 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/lang", post(handlers::lang::set_lang))
         //Compaction
         .route("/api/db/compact", post(handlers::notes::compact_db))
+        .route("/api/db/check", get(handlers::notes::check_db))
+        .route("/api/db/repair", post(handlers::notes::repair_db))
         //백업
         .route("/api/backup/info", get(handlers::notes::backup_info))
         .route("/api/backup/now", post(handlers::notes::backup_now))
+        .route("/api/backup/list", get(handlers::notes::backup_list))
+        .route(
+            "/api/backup/schedule",
+            get(handlers::notes::backup_schedule).post(handlers::notes::set_backup_schedule),
+        )
+        .route(
+            "/api/backup/retention",
+            get(handlers::notes::backup_retention).put(handlers::notes::set_backup_retention),
+        )
+        .route(
+            "/api/backup/:name/notes",
+            get(handlers::notes::backup_notes),
+        )
+        .route(
+            "/api/backup/:name/notes/:id",
+            get(handlers::notes::backup_note),
+        )
+        .route(
+            "/api/backup/:name/verify",
+            get(handlers::notes::backup_verify),
+        )
+        .route(
+            "/api/backup/:name/restore_all",
+            post(handlers::notes::backup_restore_all),
+        )
+        .route(
+            "/api/backup/:name/restore",
+            post(handlers::notes::backup_restore_notes),
+        )
         // 그래프 라우트 추가
         .route("/graph", get(handlers::pages::graph_view))
         .route("/api/graph", get(handlers::graph::get_graph_data))
@@ -67,6 +98,24 @@ pub fn create_router(state: AppState) -> Router {
             "/api/security/set-pin-with-keyfile",
             post(handlers::security::set_pin_with_keyfile),
         )
+        .route(
+            "/api/security/recovery-key",
+            post(handlers::security::create_recovery_key),
+        )
+        .route("/api/security/recover", post(handlers::security::recover))
+        .route(
+            "/api/security/wipe-policy",
+            post(handlers::security::set_wipe_policy),
+        )
+        .route(
+            "/api/security/idle-lock",
+            post(handlers::security::set_idle_lock),
+        )
+        .route(
+            "/api/security/duress-pin",
+            post(handlers::security::set_duress_pin),
+        )
+        .route("/api/security/audit", get(handlers::security::audit_log))
         //노트 중복 확인 및 제거, laz
         .route(
             "/api/notes/duplicates",
@@ -85,6 +134,7 @@ pub fn create_router(state: AppState) -> Router {
         .route("/notes/new", get(handlers::pages::notes_new))
         .route("/notes/:id", get(handlers::pages::notes_view))
         .route("/notes/:id/edit", get(handlers::pages::notes_edit))
+        .route("/notes/:id/history", get(handlers::pages::notes_history))
         .route("/search", get(handlers::pages::search))
         .route("/notes/split", get(handlers::pages::notes_split))
         .route(
@@ -105,6 +155,16 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/notes/:id/lock", post(handlers::notes::acquire_lock))
         .route("/api/notes/:id/lock", get(handlers::notes::check_lock))
         .route("/api/notes/:id/unlock", post(handlers::notes::release_lock))
+        .route("/api/notes/:id/history", get(handlers::notes::history))
+        .route(
+            "/api/notes/:id/history/:rev",
+            get(handlers::notes::get_revision),
+        )
+        .route(
+            "/api/notes/:id/history/:rev/restore",
+            post(handlers::notes::restore_revision),
+        )
+        .route("/api/notes/:id/diff", get(handlers::notes::diff))
         .route("/api/search", get(handlers::search::search))
         // === 위키 라우트 ===
         .route("/wiki/manage", get(handlers::wiki::manage_zims))
@@ -118,6 +178,18 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/zim/list", get(handlers::wiki::list_zims))
         .route("/api/zim/dir", get(handlers::wiki::get_zim_dir))
         .route("/api/zim/:name", delete(handlers::wiki::remove_zim))
+        .route(
+            "/api/zim/:name/verify",
+            get(handlers::wiki::get_verify_status).post(handlers::wiki::verify_zim),
+        )
+        .route(
+            "/api/zim/:name/illustration",
+            get(handlers::wiki::get_illustration),
+        )
+        .route(
+            "/api/zim/:name/index",
+            get(handlers::wiki::get_index_status).post(handlers::wiki::build_index),
+        )
         // === 동기화 라우트 ===
         .route("/sync/export", post(handlers::sync::export))
         .route("/sync/import", post(handlers::sync::import))
@@ -0,0 +1,148 @@
+//! 브라우저별 세션 (잠금 해제 상태)
+//!
+//! PIN으로 잠금을 풀면 그 브라우저에만 HttpOnly 쿠키로 세션 토큰을 줍니다.
+//! 같은 네트워크의 다른 기기는 키가 메모리에 올라와 있어도 토큰이 없으면
+//! 잠긴 것으로 취급합니다. 서버에는 토큰의 SHA-256만 두고, 세션은 메모리에만
+//! 있으므로 재시작하면 모두 사라집니다.
+
+use axum::http::{header, HeaderMap, HeaderValue};
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// 세션 쿠키 이름
+pub const SESSION_COOKIE: &str = "lazarus_session";
+
+/// 토큰 크기 (32 bytes)
+const TOKEN_SIZE: usize = 32;
+
+/// 잠금 해제된 세션들 (토큰 해시 → 마지막 요청 시각)
+#[derive(Debug, Default)]
+pub struct SessionStore {
+    sessions: HashMap<String, DateTime<Utc>>,
+}
+
+impl SessionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 새 세션, 반환: 쿠키에 넣을 토큰
+    pub fn create(&mut self, now: DateTime<Utc>) -> String {
+        let mut bytes = [0u8; TOKEN_SIZE];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        let token = to_hex(&bytes);
+        self.sessions.insert(hash_token(&token), now);
+        token
+    }
+
+    /// 유효한 세션이면 마지막 요청 시각 갱신
+    pub fn touch(&mut self, token: &str, now: DateTime<Utc>) -> bool {
+        match self.sessions.get_mut(&hash_token(token)) {
+            Some(last_seen) => {
+                *last_seen = now;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 유효한 세션인지 (갱신하지 않음)
+    pub fn contains(&self, token: &str) -> bool {
+        self.sessions.contains_key(&hash_token(token))
+    }
+
+    /// 오래 쉰 세션 정리, 반환: 정리된 개수
+    pub fn expire_idle(&mut self, now: DateTime<Utc>, timeout: Duration) -> usize {
+        let before = self.sessions.len();
+        self.sessions
+            .retain(|_, last_seen| now - *last_seen < timeout);
+        before - self.sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// 모든 세션 끝내기
+    pub fn clear(&mut self) {
+        self.sessions.clear();
+    }
+}
+
+/// 요청의 쿠키에서 세션 토큰 꺼내기
+pub fn token_from_headers(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|cookies| cookies.split(';'))
+        .find_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            (name == SESSION_COOKIE && !value.is_empty()).then_some(value)
+        })
+}
+
+/// 세션 쿠키 설정 헤더 (브라우저를 닫으면 사라짐)
+pub fn session_cookie(token: &str) -> HeaderValue {
+    HeaderValue::from_str(&format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Strict",
+        SESSION_COOKIE, token
+    ))
+    .expect("hex 토큰은 항상 유효한 헤더 값")
+}
+
+/// 세션 쿠키 삭제 헤더
+pub fn clear_cookie() -> HeaderValue {
+    HeaderValue::from_str(&format!(
+        "{}=; Path=/; HttpOnly; SameSite=Strict; Max-Age=0",
+        SESSION_COOKIE
+    ))
+    .expect("고정 문자열은 항상 유효한 헤더 값")
+}
+
+fn hash_token(token: &str) -> String {
+    to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_idle_expiry() {
+        let now = Utc::now();
+        let mut store = SessionStore::new();
+        let a = store.create(now);
+        let b = store.create(now);
+        assert_ne!(a, b);
+        assert!(store.contains(&a));
+        assert!(!store.contains("deadbeef"));
+
+        let later = now + Duration::minutes(10);
+        assert!(store.touch(&a, later));
+        assert_eq!(
+            store.expire_idle(later + Duration::minutes(6), Duration::minutes(15)),
+            1
+        );
+        assert!(store.contains(&a));
+        assert!(!store.contains(&b));
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::COOKIE,
+            HeaderValue::from_str(&format!("lang=ko; {}={}", SESSION_COOKIE, a)).unwrap(),
+        );
+        assert_eq!(token_from_headers(&headers), Some(a.as_str()));
+        assert!(session_cookie(&a).to_str().unwrap().contains("HttpOnly"));
+    }
+}
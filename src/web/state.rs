@@ -1,19 +1,21 @@
 //! 애플리케이션 상태
 
-use std::collections::HashMap;
+use axum::http::HeaderMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::crypto::{CryptoManager, SecurityConfig};
-use crate::db::{BackupManager, StorageEngine};
-use crate::db::{PackageStore, PostStore, QnaStore};
+use crate::crypto::{CryptoManager, PinGuard, SecurityConfig, Unlocked};
+use crate::db::{BackupManager, BackupSchedule, BackupStatus, Durability, Note, StorageEngine};
+use crate::db::{PackageStore, PostStore, QnaStore, RetentionPolicy};
 use crate::error::Result;
 use crate::i18n::{get_translations, Lang, Translations};
 use crate::links::LinkIndex;
-use crate::search::SearchEngine;
+use crate::search::{embed_note, SearchEngine, ZimFullTextIndex};
 use crate::srs::SrsEngine;
-use crate::zim::ZimReader;
+use crate::web::session::{token_from_headers, SessionStore};
+use crate::zim::{ClusterCache, IntegrityReport, VerifyCounter, VerifyProgress, ZimReader};
 
 /// ZIM 정보
 pub struct ZimInfo {
@@ -22,19 +24,70 @@ pub struct ZimInfo {
     pub reader: Arc<RwLock<ZimReader>>,
 }
 
+/// ZIM 무결성 검사 상태
+#[derive(Clone, serde::Serialize)]
+pub struct ZimVerifyStatus {
+    pub running: bool,
+    pub progress: VerifyProgress,
+    /// 완료되면 채워짐
+    pub report: Option<IntegrityReport>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 실행 중이거나 끝난 ZIM 무결성 검사
+///
+/// 진행 상황은 검사 스레드가 잠금 없이 갱신합니다.
+pub struct ZimVerifyJob {
+    pub running: bool,
+    pub progress: Arc<VerifyCounter>,
+    pub report: Option<IntegrityReport>,
+    pub started_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl ZimVerifyJob {
+    /// 응답용 상태
+    pub fn status(&self) -> ZimVerifyStatus {
+        ZimVerifyStatus {
+            running: self.running,
+            progress: self.progress.get(),
+            report: self.report.clone(),
+            started_at: self.started_at,
+        }
+    }
+}
+
 /// 애플리케이션 상태
+/// 자동 백업 예정 시각 확인 주기 (초)
+const BACKUP_SCHEDULER_TICK_SECS: u64 = 30;
+
+/// 자동 잠금 확인 주기 (초)
+const AUTO_LOCK_TICK_SECS: u64 = 15;
+
+/// 한 주기 동안 벽시계가 단조 시계보다 이만큼(초) 더 가면 절전에서 깨어난 것으로 봄
+const SUSPEND_GAP_SECS: i64 = 60;
+
 #[derive(Clone)]
 pub struct AppState {
     pub db: Arc<RwLock<StorageEngine>>,
     pub search: Arc<RwLock<SearchEngine>>,
+    /// 암호화된 노트 검색 인덱스 (메모리 전용, 잠금 해제 중에만 존재)
+    ///
+    /// 락 순서: search 다음 (둘 다 잡을 때)
+    pub encrypted_search: Arc<RwLock<Option<SearchEngine>>>,
     pub zims: Arc<RwLock<Vec<ZimInfo>>>,
     pub srs: Arc<RwLock<SrsEngine>>,
     pub data_dir: PathBuf,
     pub zim_dir: PathBuf,
     pub version: &'static str,
     pub backup: Arc<RwLock<BackupManager>>,
+    /// 마지막 백업 결과 (설정 화면/`/health`)
+    pub backup_status: Arc<RwLock<BackupStatus>>,
     pub security: Arc<RwLock<SecurityConfig>>,
+    /// PIN 시도 제한 (모든 PIN/복구 키 검증은 `guarded_verify`를 거침)
+    pub pin_guard: Arc<RwLock<PinGuard>>,
     pub crypto: Arc<RwLock<Option<CryptoManager>>>,
+    /// 잠금 해제된 브라우저 세션 (락 순서: security 다음)
+    pub sessions: Arc<RwLock<SessionStore>>,
     pub lang: Arc<RwLock<Lang>>,
     /// 편집 중인 노트 락 (note_id -> timestamp)
     pub edit_locks: Arc<RwLock<HashMap<u64, chrono::DateTime<chrono::Utc>>>>,
@@ -42,11 +95,21 @@ pub struct AppState {
     pub posts: Arc<RwLock<PostStore>>,
     pub qna: Arc<RwLock<QnaStore>>,
     pub packages: Arc<RwLock<PackageStore>>,
+    /// 모든 ZIM 리더가 공유하는 클러스터 캐시
+    pub zim_cache: Arc<ClusterCache>,
+    /// 본문 인덱스를 빌드 중인 ZIM 이름
+    pub zim_index_jobs: Arc<RwLock<HashSet<String>>>,
+    /// ZIM 무결성 검사 상태 (ZIM 이름 -> 상태)
+    pub zim_verify_jobs: Arc<RwLock<HashMap<String, ZimVerifyJob>>>,
 }
 
 impl AppState {
     /// 새 상태 생성
-    pub async fn new(data_dir: PathBuf, zim_paths: Vec<PathBuf>) -> Result<Self> {
+    pub async fn new(
+        data_dir: PathBuf,
+        zim_paths: Vec<PathBuf>,
+        zim_cache_bytes: usize,
+    ) -> Result<Self> {
         let db_path = data_dir.join("notes.lazarus");
         let index_path = data_dir.join("index");
         let srs_path = data_dir.join("srs.jsonl");
@@ -68,9 +131,14 @@ impl AppState {
         let db = StorageEngine::open(&db_path)?;
         let search = SearchEngine::open(&index_path)?;
         let srs = SrsEngine::open(&srs_path)?;
-        let backup = BackupManager::new(&db_path, &backup_dir);
+        let mut backup = BackupManager::new(&db_path, &backup_dir);
         // 보안 설정 로드
         let security = SecurityConfig::load(&security_path)?;
+        if security.pin_enabled {
+            // 잠금 해제 전에는 백업하지 않음 (평문으로 쓰지 않도록)
+            backup.lock();
+        }
+        let pin_guard = PinGuard::open(&data_dir)?;
         tracing::info!(
             "보안 설정: PIN {}",
             if security.pin_enabled {
@@ -80,16 +148,28 @@ impl AppState {
             }
         );
         // 시작 시 자동 백업
-        if let Err(e) = backup.backup() {
-            tracing::warn!("시작 시 백업 실패: {}", e);
+        let started = chrono::Utc::now();
+        let mut backup_status = BackupStatus {
+            last_run: Some(started),
+            next_run: backup.schedule().interval.next_run(started),
+            ..Default::default()
+        };
+        match backup.backup() {
+            Ok(Some(path)) => backup_status.last_backup = backup_file_name(&path),
+            Ok(None) => backup_status.skipped = true,
+            Err(e) => {
+                tracing::warn!("시작 시 백업 실패: {}", e);
+                backup_status.error = Some(e.to_string());
+            }
         }
 
         // 여러 ZIM 파일 로드
+        let zim_cache = Arc::new(ClusterCache::new(zim_cache_bytes));
         let mut zims = Vec::new();
 
         // CLI에서 지정한 ZIM 파일들
         for path in zim_paths {
-            if let Ok(reader) = ZimReader::open(&path) {
+            if let Ok(reader) = ZimReader::open_with_cache(&path, zim_cache.clone()) {
                 let name = path
                     .file_stem()
                     .and_then(|s| s.to_str())
@@ -117,7 +197,7 @@ impl AppState {
 
                     // 이미 로드된 ZIM인지 확인
                     if !zims.iter().any(|z| z.name == name) {
-                        if let Ok(reader) = ZimReader::open(&path) {
+                        if let Ok(reader) = ZimReader::open_with_cache(&path, zim_cache.clone()) {
                             tracing::info!("ZIM 디렉토리에서 로드: {} ({})", name, path.display());
                             zims.push(ZimInfo {
                                 name,
@@ -143,18 +223,25 @@ impl AppState {
             edit_locks: Arc::new(RwLock::new(HashMap::new())),
             db: Arc::new(RwLock::new(db)),
             search: Arc::new(RwLock::new(search)),
+            encrypted_search: Arc::new(RwLock::new(None)),
             zims: Arc::new(RwLock::new(zims)),
             srs: Arc::new(RwLock::new(srs)),
             data_dir,
             zim_dir,
             version: env!("CARGO_PKG_VERSION"),
             backup: Arc::new(RwLock::new(backup)),
+            backup_status: Arc::new(RwLock::new(backup_status)),
             security: Arc::new(RwLock::new(security)),
+            pin_guard: Arc::new(RwLock::new(pin_guard)),
             crypto: Arc::new(RwLock::new(None)), // PIN 입력 전까지 None
+            sessions: Arc::new(RwLock::new(SessionStore::new())),
             link_index: Arc::new(RwLock::new(LinkIndex::new())),
             posts: Arc::new(RwLock::new(posts)),
             qna: Arc::new(RwLock::new(qna)),
             packages: Arc::new(RwLock::new(packages)),
+            zim_cache,
+            zim_index_jobs: Arc::new(RwLock::new(HashSet::new())),
+            zim_verify_jobs: Arc::new(RwLock::new(HashMap::new())),
         })
     }
     /// 현재 언어 가져오기
@@ -202,7 +289,7 @@ impl AppState {
 
     /// ZIM 파일 동적 추가
     pub async fn add_zim(&self, path: PathBuf) -> Result<String> {
-        let reader = ZimReader::open(&path)?;
+        let reader = ZimReader::open_with_cache(&path, self.zim_cache.clone())?;
         let name = path
             .file_stem()
             .and_then(|s| s.to_str())
@@ -231,6 +318,55 @@ impl AppState {
         zims.retain(|z| z.name != name);
         zims.len() < before
     }
+    /// 저장 내구성 모드 설정
+    ///
+    /// group commit이면 주기마다 fsync하는 태스크를 띄웁니다.
+    /// 태스크는 모드가 바뀌면 스스로 끝납니다.
+    pub async fn set_durability(&self, durability: Durability) -> Result<()> {
+        self.db.write().await.set_durability(durability)?;
+
+        let Durability::GroupCommit { interval_ms } = durability else {
+            return Ok(());
+        };
+        let db = self.db.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                {
+                    let db = db.read().await;
+                    if db.durability() != durability {
+                        break;
+                    }
+                    if db.pending_bytes() == 0 {
+                        continue;
+                    }
+                }
+
+                // 쓰기 락은 버퍼를 파일에 쓰는 동안만 잡고, fsync는 락 밖에서
+                let handle = db.write().await.begin_sync();
+                let (file, bytes) = match handle {
+                    Ok(Some(handle)) => handle,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        tracing::error!("group commit 쓰기 실패: {}", e);
+                        continue;
+                    }
+                };
+                match tokio::task::spawn_blocking(move || file.sync_data()).await {
+                    Ok(Ok(())) => db.write().await.mark_synced(bytes),
+                    Ok(Err(e)) => tracing::error!("group commit fsync 실패: {}", e),
+                    Err(e) => tracing::error!("group commit fsync 태스크 실패: {}", e),
+                }
+            }
+        });
+
+        tracing::info!("WAL 내구성 모드: {}", durability);
+        Ok(())
+    }
+
     //시작 시 모든 노트의 링크 인덱스 빌드
     pub async fn build_link_index(&self) -> Result<()> {
         let db = self.db.read().await;
@@ -248,6 +384,577 @@ impl AppState {
         Ok(())
     }
 
+    /// 벡터 없이 저장된 노트의 임베딩을 메모리에 채움 (시작 시)
+    pub async fn build_note_vectors(&self) {
+        let mut db = self.db.write().await;
+        let mut filled = 0usize;
+
+        for id in db.ids_without_vector() {
+            // 암호화된 노트는 키 없이 읽으면 자리표시 노트가 나오고 embed_note가 걸러냄
+            let vector = match db.get_decrypted(id, None) {
+                Ok(Some(note)) => embed_note(&note),
+                _ => None,
+            };
+            if let Some(v) = vector {
+                db.cache_vector(id, v);
+                filled += 1;
+            }
+        }
+
+        if filled > 0 {
+            tracing::info!("노트 임베딩 생성: {}개", filled);
+        }
+    }
+
+    /// 노트를 검색 인덱스에 반영
+    ///
+    /// 암호화된 노트는 디스크 인덱스에서 빼고 메모리 인덱스에만 넣습니다
+    /// (잠금 상태면 잠금 해제 시 다시 빌드됨).
+    pub async fn index_note(&self, note: &Note) -> Result<()> {
+        let mut search = self.search.write().await;
+        let mut encrypted_search = self.encrypted_search.write().await;
+
+        if note.encrypted {
+            search.delete_note(note.id)?;
+            search.commit()?;
+            if let Some(engine) = encrypted_search.as_mut() {
+                engine.index_note(note)?;
+            }
+        } else {
+            if let Some(engine) = encrypted_search.as_mut() {
+                engine.delete_note(note.id)?;
+                engine.commit()?;
+            }
+            search.index_note(note)?;
+        }
+        Ok(())
+    }
+
+    /// 모든 검색 인덱스에서 노트 제거
+    pub async fn unindex_note(&self, id: u64) -> Result<()> {
+        self.search.write().await.delete_note(id)?;
+        if let Some(engine) = self.encrypted_search.write().await.as_mut() {
+            engine.delete_note(id)?;
+            engine.commit()?;
+        }
+        Ok(())
+    }
+
+    /// 잠금 해제 시 암호화된 노트를 복호화해 메모리 인덱스 빌드
+    ///
+    /// 이 키로 열리지 않는 암호화 노트(다른 금고 것)는 DB에서 숨깁니다.
+    pub async fn build_encrypted_index(&self) -> Result<()> {
+        let Some(crypto) = self.crypto.read().await.clone() else {
+            return Ok(());
+        };
+
+        let was_hiding = self.db.write().await.set_hidden(HashSet::new());
+        let mut engine = SearchEngine::open_in_ram()?;
+        let mut count = 0usize;
+        let mut hidden = HashSet::new();
+
+        {
+            let db = self.db.read().await;
+            for id in db.list_ids() {
+                match db.get_decrypted(id, Some(&crypto)) {
+                    Ok(Some(note)) if note.encrypted => {
+                        engine.add_note(&note)?;
+                        count += 1;
+                    }
+                    Ok(_) => {}
+                    Err(crate::error::LazarusError::Decryption) => {
+                        hidden.insert(id);
+                    }
+                    Err(e) => tracing::warn!("암호화 노트 인덱싱 실패: id={}, {}", id, e),
+                }
+            }
+        }
+        engine.commit()?;
+
+        let hiding = !hidden.is_empty();
+        self.db.write().await.set_hidden(hidden);
+        *self.encrypted_search.write().await = Some(engine);
+        if was_hiding || hiding {
+            *self.link_index.write().await = LinkIndex::new();
+            self.build_link_index().await?;
+        }
+        tracing::info!("암호화 노트 메모리 인덱스 빌드: {}개", count);
+        Ok(())
+    }
+
+    /// 잠금 시 메모리 인덱스 폐기 (숨겼던 노트도 되돌림)
+    pub async fn drop_encrypted_index(&self) {
+        *self.encrypted_search.write().await = None;
+        if self.db.write().await.set_hidden(HashSet::new()) {
+            *self.link_index.write().await = LinkIndex::new();
+            if let Err(e) = self.build_link_index().await {
+                tracing::warn!("링크 인덱스 재빌드 실패: {}", e);
+            }
+        }
+    }
+
+    /// 잠금 해제된 키를 메모리에 올림
+    ///
+    /// 미끼 금고면 잠겨 있을 때처럼 백업을 미룹니다 (백업 매니페스트에 두 번째
+    /// 키 식별자가 남거나 평문으로 쓰지 않도록). 강요 PIN 설정에 따라 진짜
+    /// 금고를 파기하는 일은 `destroy_hidden_vault`가 합니다.
+    pub async fn activate_key(&self, unlocked: &Unlocked) -> Result<()> {
+        let mut backup = self.backup.write().await;
+        if unlocked.decoy {
+            backup.lock();
+        } else {
+            backup.set_crypto(Some(unlocked.crypto.clone()));
+        }
+        drop(backup);
+        *self.crypto.write().await = Some(unlocked.crypto.clone());
+        self.build_encrypted_index().await
+    }
+
+    /// 지금 키로 열리지 않는 노트(진짜 금고)와 백업을 지움
+    ///
+    /// 보안 헤더의 진짜 자리는 호출 측에서 먼저 덮어써야 합니다
+    /// (`SecurityConfig::destroy_real_vault`).
+    pub async fn destroy_hidden_vault(&self) -> Result<()> {
+        let purged = {
+            let mut db = self.db.write().await;
+            let purged = db.purge_hidden()?;
+            // 지운 노트의 암호문을 WAL과 그 사본에서도 제거
+            db.compact(&RetentionPolicy::default())?;
+            db.purge_copies()?;
+            purged
+        };
+        self.backup.write().await.delete_all()?;
+
+        *self.link_index.write().await = LinkIndex::new();
+        self.build_link_index().await?;
+        tracing::debug!("노트 {}개 정리", purged);
+        Ok(())
+    }
+
+    /// 잠금 해제한 브라우저용 새 세션, 반환: 쿠키에 넣을 토큰
+    pub async fn start_session(&self) -> String {
+        self.sessions.write().await.create(chrono::Utc::now())
+    }
+
+    /// 요청에 유효한 세션 쿠키가 있는지 (`touch`면 마지막 요청 시각 갱신)
+    pub async fn has_session(&self, headers: &HeaderMap, touch: bool) -> bool {
+        let Some(token) = token_from_headers(headers) else {
+            return false;
+        };
+        let mut sessions = self.sessions.write().await;
+        if touch {
+            sessions.touch(token, chrono::Utc::now())
+        } else {
+            sessions.contains(token)
+        }
+    }
+
+    /// 이 브라우저 기준 잠금 상태 (PIN이 있고 키가 없거나 세션이 없음)
+    pub async fn is_locked_for(&self, headers: &HeaderMap) -> bool {
+        if !self.security.read().await.pin_enabled {
+            return false;
+        }
+        self.crypto.read().await.is_none() || !self.has_session(headers, false).await
+    }
+
+    /// 금고 잠금: 모든 세션을 끝내고 키를 메모리에서 지움 (Drop에서 0으로 덮어씀)
+    ///
+    /// PIN이 남아 있으면 다시 열 때까지 백업을 미루고, PIN을 없앤 뒤라면
+    /// 평문으로 백업합니다.
+    pub async fn lock_vault(&self) {
+        let pin_enabled = self.security.read().await.pin_enabled;
+        self.sessions.write().await.clear();
+        *self.crypto.write().await = None;
+        self.drop_encrypted_index().await;
+        let mut backup = self.backup.write().await;
+        if pin_enabled {
+            backup.lock();
+        } else {
+            backup.set_crypto(None);
+        }
+    }
+
+    /// 유휴 세션 정리, 남은 세션이 없으면 잠금. 반환: 잠갔는지
+    pub async fn lock_if_idle(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let Some(timeout) = self.security.read().await.idle_timeout() else {
+            return false;
+        };
+        let idle = {
+            let mut sessions = self.sessions.write().await;
+            sessions.expire_idle(now, timeout);
+            sessions.is_empty()
+        };
+        if !idle || self.crypto.read().await.is_none() {
+            return false;
+        }
+
+        self.lock_vault().await;
+        tracing::info!("유휴 시간 초과로 자동 잠금");
+        true
+    }
+
+    /// 자동 잠금 태스크 시작 (유휴 시간 초과, 절전 복귀)
+    ///
+    /// 단조 시계는 절전 중에 멈추고 벽시계는 계속 가므로, 한 주기 사이에 둘의
+    /// 차이가 크게 벌어지면 절전에서 깨어난 것으로 보고 바로 잠급니다.
+    pub fn start_auto_lock(&self) {
+        let state = self.clone();
+        tokio::spawn(async move {
+            let mut ticker =
+                tokio::time::interval(std::time::Duration::from_secs(AUTO_LOCK_TICK_SECS));
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut last_wall = chrono::Utc::now();
+            let mut last_mono = std::time::Instant::now();
+
+            loop {
+                ticker.tick().await;
+                let now = chrono::Utc::now();
+                let mono = std::time::Instant::now();
+                if resumed_from_suspend(now - last_wall, mono - last_mono)
+                    && state.crypto.read().await.is_some()
+                {
+                    state.lock_vault().await;
+                    tracing::info!("절전에서 깨어나 잠금");
+                } else {
+                    state.lock_if_idle(now).await;
+                }
+                last_wall = now;
+                last_mono = mono;
+            }
+        });
+    }
+
+    /// 검색 인덱스가 비어 있으면 DB에서 다시 빌드 (스키마 변경 후 첫 실행 등)
+    pub async fn rebuild_search_index_if_empty(&self) -> Result<()> {
+        {
+            let db = self.db.read().await;
+            let search = self.search.read().await;
+            if search.num_docs() > 0 || db.count() == 0 {
+                return Ok(());
+            }
+        }
+        self.rebuild_search_index().await
+    }
+
+    /// 검색 인덱스를 비우고 DB에서 다시 빌드 (암호화 노트 제외)
+    pub async fn rebuild_search_index(&self) -> Result<()> {
+        let db = self.db.read().await;
+        let mut search = self.search.write().await;
+        search.clear()?;
+
+        let mut count = 0usize;
+        for id in db.list_ids() {
+            if let Ok(Some(note)) = db.get_decrypted(id, None) {
+                if !note.encrypted {
+                    search.add_note(&note)?;
+                    count += 1;
+                }
+            }
+        }
+        search.commit()?;
+        search.mark_upgraded()?;
+
+        tracing::info!("검색 인덱스 재빌드: {}개 노트", count);
+        Ok(())
+    }
+
+    /// 데이터 디렉토리 백업
+    ///
+    /// 모든 저장소 락을 잡은 채로 찍어서 노트/SRS/게시판/Q&A/패키지/보안 설정이
+    /// 같은 시점이 되게 합니다. 락 순서: security → db → srs → posts → qna →
+    /// packages → backup. 파일 I/O는 blocking 스레드에서 합니다.
+    pub async fn backup_now(&self) -> Result<Option<PathBuf>> {
+        let stores = (
+            self.security.clone().read_owned().await,
+            self.db.clone().read_owned().await,
+            self.srs.clone().read_owned().await,
+            self.posts.clone().read_owned().await,
+            self.qna.clone().read_owned().await,
+            self.packages.clone().read_owned().await,
+        );
+        let backup = self.backup.clone().read_owned().await;
+        tokio::task::spawn_blocking(move || {
+            let _stores = stores;
+            backup.backup()
+        })
+        .await
+        .map_err(|e| crate::error::LazarusError::Io(e.into()))?
+    }
+
+    /// 백업 실행 후 결과 기록 (수동/자동 공통)
+    ///
+    /// 변경이 없으면 건너뛰고, 두 번째 대상이 설정돼 있으면 그쪽에도 복사합니다.
+    pub async fn run_backup(&self) -> Result<Option<PathBuf>> {
+        let result = self.backup_now().await;
+        let (schedule, mirror_error) = {
+            let backup = self.backup.clone().read_owned().await;
+            let schedule = backup.schedule().clone();
+            let mirror_error = match (&result, &schedule.mirror_dir) {
+                (Ok(_), Some(dir)) => {
+                    let target = dir.clone();
+                    tokio::task::spawn_blocking(move || backup.mirror(&target))
+                        .await
+                        .map_err(|e| crate::error::LazarusError::Io(e.into()))
+                        .and_then(|mirrored| mirrored)
+                        .err()
+                        .map(|e| {
+                            tracing::warn!("백업 복사 실패 ({}): {}", dir.display(), e);
+                            e.to_string()
+                        })
+                }
+                _ => None,
+            };
+            (schedule, mirror_error)
+        };
+
+        let now = chrono::Utc::now();
+        let mut status = self.backup_status.write().await;
+        status.last_run = Some(now);
+        status.next_run = schedule.interval.next_run(now);
+        status.mirror_error = mirror_error;
+        status.skipped = matches!(result, Ok(None));
+        match &result {
+            Ok(Some(path)) => {
+                status.last_backup = backup_file_name(path);
+                status.error = None;
+            }
+            Ok(None) => status.error = None,
+            Err(e) => {
+                tracing::error!("백업 실패: {}", e);
+                status.error = Some(e.to_string());
+            }
+        }
+        result
+    }
+
+    /// 예정 시각이 지났으면 백업 (실행했는지 반환)
+    pub async fn run_backup_if_due(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        let interval = self.backup.read().await.schedule().interval;
+        let last_run = self.backup_status.read().await.last_run;
+        let due = match last_run {
+            Some(last) => interval.next_run(last).is_some_and(|next| next <= now),
+            None => interval.next_run(now).is_some(),
+        };
+        if due {
+            // 결과는 backup_status에 남음
+            let _ = self.run_backup().await;
+        }
+        due
+    }
+
+    /// 자동 백업 설정 변경 (다음 실행 시각도 다시 계산)
+    pub async fn set_backup_schedule(&self, schedule: BackupSchedule) -> Result<()> {
+        let interval = schedule.interval;
+        self.backup.write().await.set_schedule(schedule)?;
+
+        let mut status = self.backup_status.write().await;
+        let last = status.last_run.unwrap_or_else(chrono::Utc::now);
+        status.next_run = interval.next_run(last);
+        tracing::info!("자동 백업 주기: {}", interval);
+        Ok(())
+    }
+
+    /// 자동 백업 태스크 시작
+    ///
+    /// 주기마다 설정을 다시 읽으므로 설정을 바꿔도 태스크를 다시 띄울 필요가 없습니다.
+    pub fn start_backup_scheduler(&self) {
+        let state = self.clone();
+        tokio::spawn(async move {
+            let mut ticker =
+                tokio::time::interval(std::time::Duration::from_secs(BACKUP_SCHEDULER_TICK_SECS));
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+            loop {
+                ticker.tick().await;
+                state.run_backup_if_due(chrono::Utc::now()).await;
+            }
+        });
+    }
+
+    /// PIN/복구 키 검증 (시도 제한, 감사 로그, 삭제 정책 포함)
+    ///
+    /// 대기 시간 중이면 검증하지 않고 `TooManyAttempts`. 검증은 한 번에 하나씩만
+    /// 하므로 동시에 여러 번 찔러 봐도 빨라지지 않습니다. 락 순서: pin_guard →
+    /// security → (금고 삭제 시) 나머지.
+    pub async fn guarded_verify<F>(&self, action: &str, verify: F) -> Result<bool>
+    where
+        F: FnOnce(&SecurityConfig) -> Result<bool> + Send + 'static,
+    {
+        let verified = self
+            .guarded_verify_with(action, move |security| Ok(verify(security)?.then_some(())))
+            .await?;
+        Ok(verified.is_some())
+    }
+
+    /// `guarded_verify`와 같지만 검증 결과(잠금 해제한 키 등)를 돌려줌
+    ///
+    /// 검증(Argon2)은 보안 설정 사본으로 blocking 스레드에서 합니다. 그동안
+    /// pin_guard는 잡고 있어 다른 검증은 기다립니다.
+    pub async fn guarded_verify_with<F, T>(&self, action: &str, verify: F) -> Result<Option<T>>
+    where
+        F: FnOnce(&SecurityConfig) -> Result<Option<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut guard = self.pin_guard.write().await;
+        let now = chrono::Utc::now();
+        if let Some(retry_after_secs) = guard.retry_after(now) {
+            guard.record_blocked(action)?;
+            return Err(crate::error::LazarusError::TooManyAttempts { retry_after_secs });
+        }
+
+        let security = self.security.read().await.clone();
+        let verified = tokio::task::spawn_blocking(move || verify(&security))
+            .await
+            .map_err(|e| crate::error::LazarusError::Io(e.into()))??;
+        if verified.is_some() {
+            guard.record_success(action)?;
+            return Ok(verified);
+        }
+
+        let failures = guard.record_failure(action, now)?;
+        let wipe_after = self.security.read().await.wipe_after;
+        if wipe_after.is_some_and(|limit| failures >= limit) {
+            tracing::warn!("PIN {}회 연속 실패: 삭제 정책에 따라 금고 삭제", failures);
+            self.wipe_vault().await?;
+            guard.record_event("wipe", action)?;
+            guard.reset()?;
+        }
+        Ok(None)
+    }
+
+    /// 금고 삭제
+    ///
+    /// 데이터 키를 감싼 헤더와 복구 키를 지워 암호화 노트를 다시는 못 열게 하고,
+    /// 암호문 노트와 백업(보안 설정 사본이 들어 있음)도 지웁니다. 암호화하지 않은
+    /// 노트는 남고, PIN 잠금은 풀린 상태가 됩니다.
+    pub async fn wipe_vault(&self) -> Result<()> {
+        {
+            let mut security = self.security.write().await;
+            security.remove_pin();
+            security.save(&self.data_dir.join("security.json"))?;
+        }
+        self.lock_vault().await;
+
+        let wiped = {
+            let mut db = self.db.write().await;
+            let encrypted: Vec<u64> = db
+                .list_ids()
+                .into_iter()
+                .filter(
+                    |id| matches!(db.get_decrypted(*id, None), Ok(Some(note)) if note.encrypted),
+                )
+                .collect();
+            for id in &encrypted {
+                db.delete(*id)?;
+            }
+            // 삭제된 노트의 암호문을 WAL과 그 사본에서도 제거
+            db.compact(&RetentionPolicy::default())?;
+            db.purge_copies()?;
+            encrypted.len()
+        };
+
+        self.backup.write().await.delete_all()?;
+
+        *self.link_index.write().await = LinkIndex::new();
+        self.build_link_index().await?;
+
+        tracing::warn!("금고 삭제 완료: 암호화 노트 {}개", wiped);
+        Ok(())
+    }
+
+    /// 백업에서 데이터 디렉토리 전체 복원
+    ///
+    /// 되돌릴 수 있도록 먼저 현재 상태를 백업하고, 복원 후 모든 저장소를 다시
+    /// 열고 검색/링크 인덱스를 다시 빌드합니다. 보안 설정이 바뀌었으면 잠급니다.
+    pub async fn restore_backup(&self, name: &str) -> Result<()> {
+        if let Err(e) = self.backup_now().await {
+            tracing::warn!("복원 전 백업 실패: {}", e);
+        }
+
+        let key_changed = {
+            let mut security = self.security.write().await;
+            let mut db = self.db.write().await;
+            let mut srs = self.srs.write().await;
+            let mut posts = self.posts.write().await;
+            let mut qna = self.qna.write().await;
+            let mut packages = self.packages.write().await;
+            let mut backup = self.backup.write().await;
+
+            let path = backup.find(name)?;
+            db.flush()?;
+            backup.restore(&path)?;
+
+            let durability = db.durability();
+            let mut reopened = StorageEngine::open(db.path())?;
+            reopened.set_durability(durability)?;
+            *db = reopened;
+            *srs = SrsEngine::open(self.data_dir.join("srs.jsonl"))?;
+            *posts = PostStore::open(&self.data_dir)
+                .map_err(|e| crate::error::LazarusError::DbInit(e.to_string()))?;
+            *qna = QnaStore::open(&self.data_dir)
+                .map_err(|e| crate::error::LazarusError::DbInit(e.to_string()))?;
+            *packages = PackageStore::open(&self.data_dir)
+                .map_err(|e| crate::error::LazarusError::DbInit(e.to_string()))?;
+
+            let restored = SecurityConfig::load(&self.data_dir.join("security.json"))?;
+            let key_changed =
+                serde_json::to_string(&*security)? != serde_json::to_string(&restored)?;
+            *security = restored;
+            if key_changed {
+                // 지금 키로는 복원된 데이터를 못 열 수 있으므로 잠금
+                self.sessions.write().await.clear();
+                *self.crypto.write().await = None;
+                if security.pin_enabled {
+                    backup.lock();
+                } else {
+                    backup.set_crypto(None);
+                }
+            }
+            key_changed
+        };
+
+        if key_changed {
+            self.drop_encrypted_index().await;
+        }
+        *self.link_index.write().await = LinkIndex::new();
+        self.build_link_index().await?;
+        self.build_note_vectors().await;
+        self.rebuild_search_index().await?;
+        self.build_encrypted_index().await?;
+
+        tracing::info!("백업에서 전체 복원: {}", name);
+        Ok(())
+    }
+
+    /// 디스크 인덱스에 남아 있는 암호화 노트 제거 (예전 버전은 평문으로 인덱싱했음)
+    ///
+    /// 인덱스 버전 파일을 남겨 한 번만 실행합니다.
+    pub async fn purge_encrypted_from_index(&self) -> Result<()> {
+        let db = self.db.read().await;
+        let mut search = self.search.write().await;
+        if !search.needs_upgrade() {
+            return Ok(());
+        }
+        let mut purged = 0usize;
+
+        for id in db.list_ids() {
+            // 키 없이 읽으면 암호화된 노트는 자리표시 노트로 나옴
+            if let Ok(Some(note)) = db.get_decrypted(id, None) {
+                if note.encrypted {
+                    search.delete_note(id)?;
+                    purged += 1;
+                }
+            }
+        }
+
+        if purged > 0 {
+            search.commit()?;
+            search.merge_segments()?;
+            tracing::info!("디스크 인덱스에서 암호화 노트 {}개 제거", purged);
+        }
+        search.mark_upgraded()
+    }
+
     /// ZIM 디렉토리 새로고침
     pub async fn reload_zims(&self) -> Result<Vec<String>> {
         let mut added = Vec::new();
@@ -269,7 +976,9 @@ impl AppState {
                     };
 
                     if !exists {
-                        if let Ok(reader) = ZimReader::open(&path) {
+                        if let Ok(reader) =
+                            ZimReader::open_with_cache(&path, self.zim_cache.clone())
+                        {
                             tracing::info!("ZIM 새로고침으로 로드: {}", name);
                             let mut zims = self.zims.write().await;
                             zims.push(ZimInfo {
@@ -286,4 +995,190 @@ impl AppState {
 
         Ok(added)
     }
+
+    /// ZIM 본문 인덱스 빌드 시작 (이미 실행 중이면 false)
+    ///
+    /// 배치마다 커밋하므로 중간에 종료돼도 다음 실행에서 이어집니다.
+    pub async fn start_zim_indexing(&self, name: &str) -> Result<bool> {
+        if self.get_zim_by_name(name).await.is_none() {
+            return Err(crate::error::LazarusError::ZimNotFound {
+                title: name.to_string(),
+            });
+        }
+
+        if !self.zim_index_jobs.write().await.insert(name.to_string()) {
+            return Ok(false);
+        }
+
+        let state = self.clone();
+        let name = name.to_string();
+        let path = ZimFullTextIndex::path_for(&self.zim_dir, &name);
+
+        tokio::spawn(async move {
+            tracing::info!("ZIM 본문 인덱싱 시작: {}", name);
+
+            loop {
+                // ZIM이 제거되면 중단
+                let Some(reader) = state.get_zim_by_name(&name).await else {
+                    break;
+                };
+                let path = path.clone();
+
+                let result = tokio::task::spawn_blocking(move || {
+                    let zim = reader.blocking_read();
+                    ZimFullTextIndex::open(&path)?.index_batch(&zim)
+                })
+                .await;
+
+                match result {
+                    Ok(Ok(progress)) if progress.done => {
+                        tracing::info!(
+                            "ZIM 본문 인덱싱 완료: {} ({}개 문서)",
+                            name,
+                            progress.indexed_docs
+                        );
+                        break;
+                    }
+                    Ok(Ok(_)) => continue,
+                    Ok(Err(e)) => {
+                        tracing::warn!("ZIM 본문 인덱싱 실패: {} - {}", name, e);
+                        break;
+                    }
+                    Err(e) => {
+                        tracing::warn!("ZIM 본문 인덱싱 작업 중단: {} - {}", name, e);
+                        break;
+                    }
+                }
+            }
+
+            state.zim_index_jobs.write().await.remove(&name);
+        });
+
+        Ok(true)
+    }
+
+    /// 시작 시 미완료 본문 인덱스 이어서 빌드
+    pub async fn resume_zim_indexing(&self) {
+        for name in self.zim_names().await {
+            let path = ZimFullTextIndex::path_for(&self.zim_dir, &name);
+            if !ZimFullTextIndex::exists(&path) {
+                continue;
+            }
+
+            let unfinished = ZimFullTextIndex::open(&path)
+                .and_then(|index| index.progress())
+                .map(|p| !p.done)
+                .unwrap_or(false);
+
+            if unfinished {
+                if let Err(e) = self.start_zim_indexing(&name).await {
+                    tracing::warn!("ZIM 본문 인덱싱 재개 실패: {} - {}", name, e);
+                }
+            }
+        }
+    }
+
+    /// ZIM 무결성 검사 시작 (이미 실행 중이면 false)
+    ///
+    /// 공유 리더를 몇 시간씩 잡고 있지 않도록 같은 파일을 따로 열어 검사합니다.
+    pub async fn start_zim_verify(&self, name: &str) -> Result<bool> {
+        let path = {
+            let zims = self.zims.read().await;
+            zims.iter()
+                .find(|z| z.name == name)
+                .map(|z| z.path.clone())
+                .ok_or_else(|| crate::error::LazarusError::ZimNotFound {
+                    title: name.to_string(),
+                })?
+        };
+
+        let progress = Arc::new(VerifyCounter::default());
+        {
+            let mut jobs = self.zim_verify_jobs.write().await;
+            if jobs.get(name).map(|j| j.running).unwrap_or(false) {
+                return Ok(false);
+            }
+            jobs.insert(
+                name.to_string(),
+                ZimVerifyJob {
+                    running: true,
+                    progress: progress.clone(),
+                    report: None,
+                    started_at: chrono::Utc::now(),
+                },
+            );
+        }
+
+        let jobs = self.zim_verify_jobs.clone();
+        let name = name.to_string();
+
+        tokio::spawn(async move {
+            tracing::info!("ZIM 무결성 검사 시작: {}", name);
+
+            let result = tokio::task::spawn_blocking(move || {
+                let zim = ZimReader::open(&path)?;
+                Ok::<_, crate::error::LazarusError>(zim.verify(|p| progress.set(&p)))
+            })
+            .await;
+
+            let result = match result {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            };
+            let mut jobs = jobs.write().await;
+            if let Some(job) = jobs.get_mut(&name) {
+                job.running = false;
+                match result {
+                    Ok(report) => {
+                        tracing::info!(
+                            "ZIM 무결성 검사 완료: {} ({})",
+                            name,
+                            if report.is_ok() {
+                                "정상"
+                            } else {
+                                "손상 발견"
+                            }
+                        );
+                        job.report = Some(report);
+                    }
+                    Err(e) => tracing::warn!("ZIM 무결성 검사 중단: {} - {}", name, e),
+                }
+            }
+        });
+
+        Ok(true)
+    }
+}
+
+/// 백업 경로에서 표시용 이름
+/// 벽시계 경과가 단조 시계 경과보다 `SUSPEND_GAP_SECS` 이상 길면 절전 복귀
+fn resumed_from_suspend(wall: chrono::Duration, mono: std::time::Duration) -> bool {
+    let mono = chrono::Duration::from_std(mono).unwrap_or(chrono::Duration::MAX);
+    wall - mono >= chrono::Duration::seconds(SUSPEND_GAP_SECS)
+}
+
+fn backup_file_name(path: &std::path::Path) -> Option<String> {
+    path.file_name().map(|n| n.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resumed_from_suspend() {
+        let tick = std::time::Duration::from_secs(AUTO_LOCK_TICK_SECS);
+        let wall = chrono::Duration::seconds(AUTO_LOCK_TICK_SECS as i64);
+        assert!(!resumed_from_suspend(wall, tick));
+        // 바쁜 시스템에서 틱이 조금 늦는 건 절전이 아님
+        assert!(!resumed_from_suspend(
+            wall + chrono::Duration::seconds(5),
+            tick
+        ));
+        // 절전 중에는 단조 시계만 멈춤
+        assert!(resumed_from_suspend(
+            wall + chrono::Duration::hours(2),
+            tick
+        ));
+    }
 }
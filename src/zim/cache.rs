@@ -0,0 +1,220 @@
+//! 압축 해제된 클러스터 LRU 캐시
+//!
+//! 위키 문서 하나가 같은 클러스터의 이미지를 수십 개 불러오므로,
+//! 압축 해제 결과를 바이트 단위 용량 안에서 재사용합니다.
+//! 모든 ZimReader가 하나의 캐시를 공유합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+/// 기본 캐시 용량 (2MB)
+pub const DEFAULT_CACHE_BYTES: usize = 2 * 1024 * 1024;
+
+/// 캐시 키: (리더 ID, 클러스터 번호)
+type CacheKey = (u64, u32);
+
+/// 캐시 항목
+struct CacheEntry {
+    data: Arc<Vec<u8>>,
+    /// 마지막 사용 시점 (LRU 순서)
+    tick: u64,
+}
+
+/// 잠금 안쪽 상태
+#[derive(Default)]
+struct CacheInner {
+    entries: HashMap<CacheKey, CacheEntry>,
+    /// tick -> key (가장 작은 tick이 가장 오래된 항목)
+    order: BTreeMap<u64, CacheKey>,
+    next_tick: u64,
+    bytes: usize,
+}
+
+/// 캐시 통계 (/health 노출용)
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+    pub bytes: usize,
+    pub capacity: usize,
+}
+
+/// 클러스터 LRU 캐시
+pub struct ClusterCache {
+    capacity: usize,
+    inner: Mutex<CacheInner>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ClusterCache {
+    /// 바이트 단위 용량으로 캐시 생성 (0이면 캐시 비활성화)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(CacheInner::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// 캐시에서 클러스터 가져오기
+    pub fn get(&self, reader_id: u64, cluster_num: u32) -> Option<Arc<Vec<u8>>> {
+        let mut inner = self.inner.lock().unwrap();
+        let tick = inner.next_tick;
+
+        let found = match inner.entries.get_mut(&(reader_id, cluster_num)) {
+            Some(entry) => {
+                let old_tick = entry.tick;
+                entry.tick = tick;
+                Some((old_tick, entry.data.clone()))
+            }
+            None => None,
+        };
+
+        match found {
+            Some((old_tick, data)) => {
+                inner.next_tick += 1;
+                inner.order.remove(&old_tick);
+                inner.order.insert(tick, (reader_id, cluster_num));
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(data)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// 클러스터 저장 (용량을 넘으면 오래된 항목부터 제거)
+    pub fn insert(&self, reader_id: u64, cluster_num: u32, data: Arc<Vec<u8>>) {
+        let size = data.len();
+        if size > self.capacity {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        let key = (reader_id, cluster_num);
+
+        if let Some(old) = inner.entries.remove(&key) {
+            inner.order.remove(&old.tick);
+            inner.bytes -= old.data.len();
+        }
+
+        while inner.bytes + size > self.capacity {
+            let Some((_, oldest)) = inner.order.pop_first() else {
+                break;
+            };
+            if let Some(evicted) = inner.entries.remove(&oldest) {
+                inner.bytes -= evicted.data.len();
+            }
+        }
+
+        let tick = inner.next_tick;
+        inner.next_tick += 1;
+        inner.order.insert(tick, key);
+        inner.entries.insert(key, CacheEntry { data, tick });
+        inner.bytes += size;
+    }
+
+    /// 특정 리더의 항목 모두 제거 (ZIM 언로드 시)
+    pub fn remove_reader(&self, reader_id: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        let keys: Vec<CacheKey> = inner
+            .entries
+            .keys()
+            .filter(|(id, _)| *id == reader_id)
+            .copied()
+            .collect();
+
+        for key in keys {
+            if let Some(entry) = inner.entries.remove(&key) {
+                inner.order.remove(&entry.tick);
+                inner.bytes -= entry.data.len();
+            }
+        }
+    }
+
+    /// 통계 조회
+    pub fn stats(&self) -> CacheStats {
+        let inner = self.inner.lock().unwrap();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: inner.entries.len(),
+            bytes: inner.bytes,
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl Default for ClusterCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_BYTES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blob(len: usize) -> Arc<Vec<u8>> {
+        Arc::new(vec![0u8; len])
+    }
+
+    #[test]
+    fn test_hit_and_miss_counters() {
+        let cache = ClusterCache::new(1024);
+        assert!(cache.get(1, 0).is_none());
+        cache.insert(1, 0, blob(100));
+        assert!(cache.get(1, 0).is_some());
+        // 다른 리더의 같은 클러스터 번호는 별개
+        assert!(cache.get(2, 0).is_none());
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.bytes, 100);
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = ClusterCache::new(300);
+        cache.insert(1, 0, blob(100));
+        cache.insert(1, 1, blob(100));
+        cache.insert(1, 2, blob(100));
+
+        // 0번을 최근에 사용 → 1번이 가장 오래됨
+        assert!(cache.get(1, 0).is_some());
+        cache.insert(1, 3, blob(100));
+
+        assert!(cache.get(1, 1).is_none());
+        assert!(cache.get(1, 0).is_some());
+        assert!(cache.get(1, 3).is_some());
+        assert!(cache.stats().bytes <= 300);
+    }
+
+    #[test]
+    fn test_oversized_cluster_not_cached() {
+        let cache = ClusterCache::new(50);
+        cache.insert(1, 0, blob(100));
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn test_remove_reader() {
+        let cache = ClusterCache::new(1024);
+        cache.insert(1, 0, blob(10));
+        cache.insert(2, 0, blob(10));
+        cache.remove_reader(1);
+
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.bytes, 10);
+    }
+}
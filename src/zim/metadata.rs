@@ -0,0 +1,195 @@
+//! ZIM 메타데이터 (M 네임스페이스)
+//!
+//! Kiwix ZIM은 제목, 설명, 언어, 날짜, 아이콘 등을 `M/` 엔트리로 담습니다.
+
+use serde::Serialize;
+
+use super::{EntryType, ZimReader};
+use crate::error::Result;
+
+/// 48x48 아이콘 엔트리 (신형 / 구형)
+const ILLUSTRATION_PATHS: &[(char, &str)] = &[
+    ('M', "Illustration_48x48@1"),
+    ('-', "favicon"),
+    ('I', "favicon.png"),
+];
+
+/// ZIM 메타데이터
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ZimMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub creator: Option<String>,
+    pub publisher: Option<String>,
+    pub date: Option<String>,
+    pub name: Option<String>,
+    pub tags: Option<String>,
+    /// HTML 문서 수 (`M/Counter`가 없으면 본문 네임스페이스 엔트리 수)
+    pub article_count: u32,
+    /// 아이콘 존재 여부
+    pub has_illustration: bool,
+}
+
+impl ZimReader {
+    /// 메타데이터 읽기 (처음 한 번만 읽고 캐시)
+    pub fn metadata(&self) -> ZimMetadata {
+        self.metadata
+            .get_or_init(|| {
+                self.read_metadata().unwrap_or_else(|e| {
+                    tracing::warn!("ZIM 메타데이터 읽기 실패: {}", e);
+                    ZimMetadata {
+                        article_count: self.header.article_count,
+                        ..Default::default()
+                    }
+                })
+            })
+            .clone()
+    }
+
+    /// M 네임스페이스 텍스트 값 하나 읽기
+    pub fn metadata_value(&self, key: &str) -> Result<Option<String>> {
+        let Some(entry) = self.find_by_path('M', key)? else {
+            return Ok(None);
+        };
+        let value = self
+            .read_entry_content(&entry)?
+            .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+            .filter(|v| !v.is_empty());
+        Ok(value)
+    }
+
+    /// 48x48 아이콘 (PNG) 읽기
+    pub fn illustration(&self) -> Result<Option<Vec<u8>>> {
+        for (namespace, url) in ILLUSTRATION_PATHS {
+            if let Some(entry) = self.find_by_path(*namespace, url)? {
+                if entry.entry_type != EntryType::Deleted {
+                    if let Some(bytes) = self.read_entry_content(&entry)? {
+                        return Ok(Some(bytes));
+                    }
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// 본문 네임스페이스 엔트리 수 (구형 `A`, 없으면 신형 `C`)
+    ///
+    /// 엔트리는 네임스페이스 순으로 정렬되어 있으므로 경계만 이진 탐색합니다.
+    /// 리다이렉트도 포함한 근사치입니다.
+    fn count_articles(&self) -> Result<u32> {
+        for namespace in ['A', 'C'] {
+            let start = self.namespace_start(namespace)?;
+            let end = self.namespace_start((namespace as u8 + 1) as char)?;
+            if end > start {
+                return Ok(end - start);
+            }
+        }
+        Ok(0)
+    }
+
+    /// 네임스페이스가 `namespace` 이상인 첫 엔트리 인덱스
+    fn namespace_start(&self, namespace: char) -> Result<u32> {
+        let mut low: u32 = 0;
+        let mut high: u32 = self.header.article_count;
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.read_dir_entry(mid)?.namespace < namespace {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+
+    fn read_metadata(&self) -> Result<ZimMetadata> {
+        let article_count = self
+            .metadata_value("Counter")?
+            .and_then(|counter| html_count(&counter));
+        let article_count = match article_count {
+            Some(count) => count,
+            None => self.count_articles()?,
+        };
+
+        let has_illustration = ILLUSTRATION_PATHS
+            .iter()
+            .any(|(ns, url)| matches!(self.find_by_path(*ns, url), Ok(Some(_))));
+
+        Ok(ZimMetadata {
+            title: self.metadata_value("Title")?,
+            description: self.metadata_value("Description")?,
+            language: self.metadata_value("Language")?,
+            creator: self.metadata_value("Creator")?,
+            publisher: self.metadata_value("Publisher")?,
+            date: self.metadata_value("Date")?,
+            name: self.metadata_value("Name")?,
+            tags: self.metadata_value("Tags")?,
+            article_count,
+            has_illustration,
+        })
+    }
+}
+
+/// `M/Counter` ("text/html=123;image/png=45")에서 HTML 문서 수 추출
+fn html_count(counter: &str) -> Option<u32> {
+    counter.split(';').find_map(|pair| {
+        let (mime, count) = pair.split_once('=')?;
+        if mime.trim().starts_with("text/html") {
+            count.trim().parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zim::tests::{build_test_zim, open_test_zim};
+
+    #[test]
+    fn test_html_count() {
+        assert_eq!(html_count("image/png=10;text/html=42"), Some(42));
+        assert_eq!(html_count("image/png=10"), None);
+    }
+
+    #[test]
+    fn test_metadata_from_m_namespace() {
+        let entries: &[(char, &str, &str, &[u8])] = &[
+            ('C', "Main_Page", "Main Page", b"<p>hi</p>"),
+            ('M', "Counter", "Counter", b"text/html=1;image/png=1"),
+            ('M', "Date", "Date", b"2026-01-15"),
+            (
+                'M',
+                "Illustration_48x48@1",
+                "Illustration_48x48@1",
+                b"\x89PNG",
+            ),
+            ('M', "Language", "Language", b"eng"),
+            ('M', "Title", "Title", b"Wikipedia"),
+        ];
+        let (_dir, zim) = open_test_zim(&build_test_zim(entries, 5));
+
+        let meta = zim.metadata();
+        assert_eq!(meta.title.as_deref(), Some("Wikipedia"));
+        assert_eq!(meta.language.as_deref(), Some("eng"));
+        assert_eq!(meta.date.as_deref(), Some("2026-01-15"));
+        assert_eq!(meta.description, None);
+        assert_eq!(meta.article_count, 1);
+        assert!(meta.has_illustration);
+        assert_eq!(zim.illustration().unwrap().unwrap(), b"\x89PNG");
+    }
+
+    #[test]
+    fn test_article_count_without_counter() {
+        let entries: &[(char, &str, &str, &[u8])] = &[
+            ('C', "Main_Page", "Main Page", b"<p>hi</p>"),
+            ('C', "Other", "Other", b"<p>other</p>"),
+            ('M', "Language", "Language", b"eng"),
+            ('M', "Title", "Title", b"Wikipedia"),
+        ];
+        let (_dir, zim) = open_test_zim(&build_test_zim(entries, 5));
+        assert_eq!(zim.metadata().article_count, 2);
+    }
+}
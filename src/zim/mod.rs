@@ -2,12 +2,26 @@
 //!
 //! 80GB 위키백과를 메모리 효율적으로 읽기 위한 Mmap 기반 리더
 
+mod cache;
+mod metadata;
+mod verify;
+
+pub use cache::{CacheStats, ClusterCache, DEFAULT_CACHE_BYTES};
+pub use metadata::ZimMetadata;
+pub use verify::{IntegrityReport, UnreadableEntry, VerifyCounter, VerifyPhase, VerifyProgress};
+
 use memmap2::Mmap;
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 
 use crate::error::{LazarusError, Result};
 
+/// 리더 ID 발급기 (클러스터 캐시 키 구분용)
+static NEXT_READER_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Levenshtein 거리 계산 (두 문자열 간 편집 거리)
 fn levenshtein_distance(s1: &str, s2: &str) -> usize {
     let s1_chars: Vec<char> = s1.chars().collect();
@@ -138,11 +152,22 @@ pub struct ZimReader {
     mmap: Mmap,
     /// 파싱된 헤더
     pub header: ZimHeader,
+    /// 캐시 키로 쓰는 리더 ID
+    id: u64,
+    /// 공유 클러스터 캐시
+    cache: Arc<ClusterCache>,
+    /// M 네임스페이스 메타데이터 (지연 로드)
+    metadata: OnceLock<ZimMetadata>,
 }
 
 impl ZimReader {
-    /// ZIM 파일 열기
+    /// ZIM 파일 열기 (전용 기본 캐시 사용)
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::open_with_cache(path, Arc::new(ClusterCache::default()))
+    }
+
+    /// 공유 클러스터 캐시와 함께 ZIM 파일 열기
+    pub fn open_with_cache<P: AsRef<Path>>(path: P, cache: Arc<ClusterCache>) -> Result<Self> {
         let path = path.as_ref().to_path_buf();
 
         let file = File::open(&path).map_err(|e| LazarusError::ZimOpen {
@@ -164,7 +189,14 @@ impl ZimReader {
             header.cluster_count
         );
 
-        Ok(Self { path, mmap, header })
+        Ok(Self {
+            path,
+            mmap,
+            header,
+            id: NEXT_READER_ID.fetch_add(1, Ordering::Relaxed),
+            cache,
+            metadata: OnceLock::new(),
+        })
     }
 
     /// 파일 크기
@@ -176,10 +208,22 @@ impl ZimReader {
     pub fn data(&self) -> &[u8] {
         &self.mmap
     }
+
+    /// 클러스터 캐시 통계
+    pub fn cache_stats(&self) -> CacheStats {
+        self.cache.stats()
+    }
+}
+
+impl Drop for ZimReader {
+    fn drop(&mut self) {
+        // 언로드된 ZIM의 클러스터가 공유 캐시를 차지하지 않도록 정리
+        self.cache.remove_reader(self.id);
+    }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     #[test]
@@ -187,6 +231,217 @@ mod tests {
         // ZIM 헤더는 80바이트
         assert!(std::mem::size_of::<ZimHeader>() <= 128);
     }
+
+    /// 테스트용 최소 ZIM 생성 (모든 블롭을 한 클러스터에 담음)
+    ///
+    /// `entries`는 (네임스페이스, URL, 제목, 내용) 목록이며 URL 순으로 정렬되어 있어야 합니다.
+    pub(crate) fn build_test_zim(
+        entries: &[(char, &str, &str, &[u8])],
+        compression: u8,
+    ) -> Vec<u8> {
+        // 클러스터 본문: 오프셋 테이블 + 블롭
+        let table_len = (entries.len() + 1) * 4;
+        let mut cluster = Vec::new();
+        let mut offset = table_len;
+        for (_, _, _, content) in entries {
+            cluster.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += content.len();
+        }
+        cluster.extend_from_slice(&(offset as u32).to_le_bytes());
+        for (_, _, _, content) in entries {
+            cluster.extend_from_slice(content);
+        }
+
+        let cluster_body = match compression {
+            4 => {
+                let mut out = Vec::new();
+                lzma_rs::xz_compress(&mut &cluster[..], &mut out).unwrap();
+                out
+            }
+            5 => zstd::encode_all(&cluster[..], 3).unwrap(),
+            _ => cluster,
+        };
+
+        let mime_list = b"text/html\0\0";
+        let mut dirents = Vec::new();
+        let mut dirent_offsets = Vec::new();
+        for (i, (ns, url, title, _)) in entries.iter().enumerate() {
+            dirent_offsets.push(dirents.len());
+            dirents.extend_from_slice(&0u16.to_le_bytes()); // mime
+            dirents.push(0); // param len
+            dirents.push(*ns as u8);
+            dirents.extend_from_slice(&0u32.to_le_bytes()); // revision
+            dirents.extend_from_slice(&0u32.to_le_bytes()); // cluster
+            dirents.extend_from_slice(&(i as u32).to_le_bytes()); // blob
+            dirents.extend_from_slice(url.as_bytes());
+            dirents.push(0);
+            dirents.extend_from_slice(title.as_bytes());
+            dirents.push(0);
+        }
+
+        // 제목순 정렬된 URL 인덱스
+        let mut title_order: Vec<u32> = (0..entries.len() as u32).collect();
+        title_order.sort_by_key(|&i| {
+            let (ns, _, title, _) = entries[i as usize];
+            (ns, title.to_string())
+        });
+
+        let mime_list_pos = 80u64;
+        let url_ptr_pos = mime_list_pos + mime_list.len() as u64;
+        let title_ptr_pos = url_ptr_pos + entries.len() as u64 * 8;
+        let cluster_ptr_pos = title_ptr_pos + entries.len() as u64 * 4;
+        let dirent_pos = cluster_ptr_pos + 8;
+        let cluster_pos = dirent_pos + dirents.len() as u64;
+        let checksum_pos = cluster_pos + 1 + cluster_body.len() as u64;
+
+        let mut out = Vec::new();
+        out.extend_from_slice(&ZIM_MAGIC.to_le_bytes());
+        out.extend_from_slice(&6u16.to_le_bytes());
+        out.extend_from_slice(&1u16.to_le_bytes());
+        out.extend_from_slice(&[0u8; 16]);
+        out.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&url_ptr_pos.to_le_bytes());
+        out.extend_from_slice(&title_ptr_pos.to_le_bytes());
+        out.extend_from_slice(&cluster_ptr_pos.to_le_bytes());
+        out.extend_from_slice(&mime_list_pos.to_le_bytes());
+        out.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes()); // main page
+        out.extend_from_slice(&0xFFFF_FFFFu32.to_le_bytes()); // layout page
+        out.extend_from_slice(&checksum_pos.to_le_bytes());
+        out.extend_from_slice(mime_list);
+        for off in &dirent_offsets {
+            out.extend_from_slice(&(dirent_pos + *off as u64).to_le_bytes());
+        }
+        for idx in &title_order {
+            out.extend_from_slice(&idx.to_le_bytes());
+        }
+        out.extend_from_slice(&cluster_pos.to_le_bytes());
+        out.extend_from_slice(&dirents);
+        out.push(compression);
+        out.extend_from_slice(&cluster_body);
+        out.extend_from_slice(&[0u8; 16]); // checksum 자리
+        out
+    }
+
+    /// 바이트를 임시 파일로 쓰고 ZimReader로 열기
+    pub(crate) fn open_test_zim(bytes: &[u8]) -> (tempfile::TempDir, ZimReader) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.zim");
+        std::fs::write(&path, bytes).unwrap();
+        let reader = ZimReader::open(&path).unwrap();
+        (dir, reader)
+    }
+
+    const SAMPLE: &[(char, &str, &str, &[u8])] = &[
+        ('C', "Apple", "Apple", b"<p>apple</p>"),
+        ('C', "Banana", "Banana", b"<p>banana</p>"),
+        ('C', "Cherry", "Cherry", b"<p>cherry</p>"),
+    ];
+
+    #[test]
+    fn test_read_blob_lzma_cluster() {
+        let (_dir, zim) = open_test_zim(&build_test_zim(SAMPLE, 4));
+        assert_eq!(zim.read_blob(0, 1).unwrap(), b"<p>banana</p>");
+        assert_eq!(
+            zim.get_content_fast("Cherry").unwrap().unwrap(),
+            b"<p>cherry</p>"
+        );
+        assert_eq!(
+            zim.get_content('C', "Apple").unwrap().unwrap(),
+            b"<p>apple</p>"
+        );
+    }
+
+    #[test]
+    fn test_read_blob_all_compressions_agree() {
+        for compression in [1u8, 4, 5] {
+            let (_dir, zim) = open_test_zim(&build_test_zim(SAMPLE, compression));
+            for (i, (_, _, _, content)) in SAMPLE.iter().enumerate() {
+                assert_eq!(&zim.read_blob(0, i as u32).unwrap()[..], *content);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cluster_cache_shared_between_readers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cached.zim");
+        std::fs::write(&path, build_test_zim(SAMPLE, 5)).unwrap();
+
+        let cache = Arc::new(ClusterCache::new(1024 * 1024));
+        let a = ZimReader::open_with_cache(&path, cache.clone()).unwrap();
+        let b = ZimReader::open_with_cache(&path, cache.clone()).unwrap();
+
+        a.read_blob(0, 0).unwrap();
+        a.read_blob(0, 1).unwrap();
+        b.read_blob(0, 2).unwrap();
+
+        let stats = cache.stats();
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.entries, 2);
+
+        // 리더가 내려가면 해당 항목도 정리
+        drop(a);
+        assert_eq!(cache.stats().entries, 1);
+    }
+
+    const TITLED: &[(char, &str, &str, &[u8])] = &[
+        ('C', "Apple", "Apple", b"a"),
+        ('C', "Banana", "Banana", b"b"),
+        ('C', "Band", "Band", b"c"),
+        ('C', "Bandana", "Bandana", b"d"),
+        ('C', "Cherry", "Cherry", b"e"),
+        ('C', "banana.png", "banana.png", b"f"),
+    ];
+
+    #[test]
+    fn test_search_prefix_uses_title_order() {
+        let (_dir, zim) = open_test_zim(&build_test_zim(TITLED, 1));
+
+        let titles: Vec<String> = zim
+            .search("Ban", 10)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect();
+        assert_eq!(titles, vec!["Banana", "Band", "Bandana"]);
+
+        // 소문자 입력도 대문자 제목과 일치
+        let titles: Vec<String> = zim
+            .search("band", 10)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect();
+        assert_eq!(titles, vec!["Band", "Bandana"]);
+
+        assert_eq!(zim.search("Ban", 2).unwrap().len(), 2);
+        assert!(zim.search("Zebra", 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_fuzzy_falls_back_to_scan() {
+        let (_dir, zim) = open_test_zim(&build_test_zim(TITLED, 1));
+
+        let results = zim.search_fuzzy("Chery", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].title, "Cherry");
+    }
+
+    #[test]
+    fn test_corrupt_lzma_cluster_is_error() {
+        let mut bytes = build_test_zim(SAMPLE, 4);
+        // 체크섬 바로 앞 XZ 스트림 꼬리를 망가뜨림
+        let len = bytes.len();
+        bytes[len - 20] ^= 0xFF;
+        bytes[len - 30] ^= 0xFF;
+        let (_dir, zim) = open_test_zim(&bytes);
+        assert!(matches!(
+            zim.read_blob(0, 0),
+            Err(LazarusError::ZimDecompress)
+        ));
+    }
 }
 
 /// Directory Entry 타입
@@ -254,8 +509,9 @@ impl ZimReader {
                 (Some(cluster), Some(blob), None, 16)
             }
             EntryType::Redirect => {
-                let redirect = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
-                (None, None, Some(redirect), 8)
+                // mime(2) + param(1) + ns(1) + revision(4) 다음에 대상 인덱스
+                let redirect = u32::from_le_bytes([data[8], data[9], data[10], data[11]]);
+                (None, None, Some(redirect), 12)
             }
             EntryType::Deleted => (None, None, None, 4),
         };
@@ -263,7 +519,11 @@ impl ZimReader {
         // URL 읽기 (null-terminated)
         let url = read_null_terminated(&data[url_start..]);
         let title_start = url_start + url.len() + 1;
-        let title = read_null_terminated(&data[title_start..]);
+        let mut title = read_null_terminated(&data[title_start..]);
+        // 제목이 비어 있으면 URL이 제목 (ZIM 규격)
+        if title.is_empty() {
+            title = url.clone();
+        }
 
         Ok(DirEntry {
             mime_type,
@@ -330,6 +590,27 @@ impl ZimReader {
         Ok(None)
     }
 
+    /// (네임스페이스, URL)로 엔트리 찾기 (이진 탐색)
+    ///
+    /// URL 포인터 리스트는 네임스페이스, URL 순으로 정렬되어 있습니다.
+    pub fn find_by_path(&self, namespace: char, url: &str) -> Result<Option<DirEntry>> {
+        let mut low: u32 = 0;
+        let mut high: u32 = self.header.article_count;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let entry = self.read_dir_entry(mid)?;
+
+            match (entry.namespace, entry.url.as_str()).cmp(&(namespace, url)) {
+                std::cmp::Ordering::Equal => return Ok(Some(entry)),
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+            }
+        }
+
+        Ok(None)
+    }
+
     /// URL로 콘텐츠 가져오기 (이진 탐색 버전)
     pub fn get_content_fast(&self, target_url: &str) -> Result<Option<Vec<u8>>> {
         // 먼저 이진 탐색으로 시도
@@ -347,6 +628,11 @@ impl ZimReader {
         Ok(None)
     }
 
+    /// 엔트리 콘텐츠 읽기 (리다이렉트 따라감)
+    pub fn read_entry_content(&self, entry: &DirEntry) -> Result<Option<Vec<u8>>> {
+        self.get_content_from_entry(entry)
+    }
+
     /// 엔트리에서 콘텐츠 가져오기
     fn get_content_from_entry(&self, entry: &DirEntry) -> Result<Option<Vec<u8>>> {
         match entry.entry_type {
@@ -384,6 +670,20 @@ fn read_null_terminated(data: &[u8]) -> String {
     String::from_utf8_lossy(&data[..end]).to_string()
 }
 
+/// XZ(LZMA2) 스트림 압축 해제
+///
+/// 구버전 Kiwix ZIM은 클러스터를 XZ로 압축합니다. mmap 슬라이스를 그대로
+/// 스트리밍 디코더에 넘기므로 압축된 클러스터를 따로 복사하지 않습니다.
+fn decompress_xz(data: &[u8]) -> Result<Vec<u8>> {
+    let mut input = std::io::BufReader::new(data);
+    let mut output = Vec::new();
+    lzma_rs::xz_decompress(&mut input, &mut output).map_err(|e| {
+        tracing::warn!("XZ 클러스터 압축 해제 실패: {}", e);
+        LazarusError::ZimDecompress
+    })?;
+    Ok(output)
+}
+
 /// 클러스터 압축 타입
 #[derive(Debug, Clone, Copy)]
 pub enum ClusterCompression {
@@ -394,8 +694,25 @@ pub enum ClusterCompression {
 }
 
 impl ZimReader {
-    /// 클러스터에서 블롭 데이터 읽기
-    pub fn read_blob(&self, cluster_num: u32, blob_num: u32) -> Result<Vec<u8>> {
+    /// 클러스터가 확장(8바이트) 오프셋을 쓰는지 확인
+    fn cluster_is_extended(&self, cluster_num: u32) -> bool {
+        let cluster_offset = self.get_cluster_offset(cluster_num) as usize;
+        (self.mmap[cluster_offset] & 0x10) != 0
+    }
+
+    /// 클러스터 압축 해제 (캐시 우선)
+    fn decompress_cluster(&self, cluster_num: u32) -> Result<Arc<Vec<u8>>> {
+        if let Some(data) = self.cache.get(self.id, cluster_num) {
+            return Ok(data);
+        }
+
+        let data = Arc::new(self.decompress_cluster_uncached(cluster_num)?);
+        self.cache.insert(self.id, cluster_num, data.clone());
+        Ok(data)
+    }
+
+    /// 클러스터 압축 해제 (캐시 미사용)
+    fn decompress_cluster_uncached(&self, cluster_num: u32) -> Result<Vec<u8>> {
         let cluster_offset = self.get_cluster_offset(cluster_num) as usize;
 
         // 클러스터 정보 바이트
@@ -407,9 +724,6 @@ impl ZimReader {
             other => ClusterCompression::Unknown(other),
         };
 
-        let extended = (info_byte & 0x10) != 0;
-        let offset_size: usize = if extended { 8 } else { 4 };
-
         // 다음 클러스터 오프셋으로 클러스터 크기 계산
         let next_cluster_offset = if cluster_num + 1 < self.header.cluster_count {
             self.get_cluster_offset(cluster_num + 1) as usize
@@ -420,19 +734,22 @@ impl ZimReader {
         let cluster_data = &self.mmap[cluster_offset + 1..next_cluster_offset];
 
         // 압축 해제
-        let decompressed = match compression {
-            ClusterCompression::None => cluster_data.to_vec(),
+        match compression {
+            ClusterCompression::None => Ok(cluster_data.to_vec()),
             ClusterCompression::Zstd => zstd::decode_all(std::io::Cursor::new(cluster_data))
-                .map_err(|e| LazarusError::ZimDecompress)?,
-            ClusterCompression::Lzma => {
-                // XZ/LZMA 지원 (나중에 추가)
-                return Err(LazarusError::ZimDecompress);
-            }
+                .map_err(|e| LazarusError::ZimDecompress),
+            ClusterCompression::Lzma => decompress_xz(cluster_data),
             ClusterCompression::Unknown(t) => {
                 tracing::warn!("알 수 없는 압축 타입: {}", t);
-                return Err(LazarusError::ZimDecompress);
+                Err(LazarusError::ZimDecompress)
             }
-        };
+        }
+    }
+
+    /// 클러스터에서 블롭 데이터 읽기
+    pub fn read_blob(&self, cluster_num: u32, blob_num: u32) -> Result<Vec<u8>> {
+        let decompressed = self.decompress_cluster(cluster_num)?;
+        let extended = self.cluster_is_extended(cluster_num);
 
         // 블롭 오프셋 테이블 읽기
         let blob_offset = if extended {
@@ -527,69 +844,137 @@ impl ZimReader {
     }
 }
 
+/// Fuzzy 전체 스캔에 허용하는 최대 시간
+const FUZZY_SCAN_BUDGET: Duration = Duration::from_millis(1500);
+
+/// 검색 결과로 보여줄 HTML 문서인지 확인
+pub(crate) fn is_searchable_article(entry: &DirEntry) -> bool {
+    (entry.namespace == 'A' || entry.namespace == 'C')
+        && entry.entry_type == EntryType::Content
+        && !entry.url.ends_with(".png")
+        && !entry.url.ends_with(".jpg")
+        && !entry.url.ends_with(".css")
+        && !entry.url.ends_with(".js")
+}
+
+/// 제목 정렬이 대소문자를 구분하므로 흔한 표기 변형을 모두 시도
+fn title_prefix_variants(query: &str) -> Vec<String> {
+    let mut variants = vec![query.to_string()];
+
+    let mut chars = query.chars();
+    if let Some(first) = chars.next() {
+        let capitalized: String = first.to_uppercase().chain(chars).collect();
+        variants.push(capitalized);
+    }
+    variants.push(query.to_lowercase());
+
+    let mut seen = std::collections::HashSet::new();
+    variants.retain(|v| seen.insert(v.clone()));
+    variants
+}
+
 impl ZimReader {
-    /// 제목으로 검색 (부분 일치)
-    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<DirEntry>> {
-        let query_lower = query.to_lowercase();
-        let mut results = Vec::new();
+    /// 제목 포인터 리스트에서 특정 위치의 URL 인덱스 가져오기
+    fn get_title_index(&self, pos: u32) -> u32 {
+        let offset = self.header.title_ptr_pos as usize + (pos as usize * 4);
+        u32::from_le_bytes(self.mmap[offset..offset + 4].try_into().unwrap())
+    }
 
-        for i in 0..self.header.article_count {
-            if let Ok(entry) = self.read_dir_entry(i) {
-                // HTML 문서만 검색
-                if (entry.namespace == 'A' || entry.namespace == 'C')
-                    && entry.entry_type == EntryType::Content
-                    && !entry.url.ends_with(".png")
-                    && !entry.url.ends_with(".jpg")
-                    && !entry.url.ends_with(".css")
-                    && !entry.url.ends_with(".js")
-                {
-                    let title_lower = entry.title.to_lowercase();
-                    let url_lower = entry.url.to_lowercase();
+    /// 제목순 위치의 엔트리 읽기
+    fn read_entry_by_title_pos(&self, pos: u32) -> Result<DirEntry> {
+        self.read_dir_entry(self.get_title_index(pos))
+    }
 
-                    if title_lower.contains(&query_lower) || url_lower.contains(&query_lower) {
+    /// (네임스페이스, 제목)이 target 이상인 첫 제목 위치 (이진 탐색)
+    fn title_lower_bound(&self, namespace: char, target: &str) -> Result<u32> {
+        let mut low: u32 = 0;
+        let mut high: u32 = self.header.article_count;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let entry = self.read_entry_by_title_pos(mid)?;
+            if (entry.namespace, entry.title.as_str()) < (namespace, target) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        Ok(low)
+    }
+
+    /// 제목 접두사 검색 (제목 포인터 리스트 이진 탐색)
+    ///
+    /// 결과는 제목순으로 정렬됩니다.
+    pub fn search_prefix(&self, query: &str, limit: usize) -> Result<Vec<DirEntry>> {
+        let mut results: Vec<DirEntry> = Vec::new();
+        if query.is_empty() || limit == 0 {
+            return Ok(results);
+        }
+
+        for namespace in ['A', 'C'] {
+            for prefix in title_prefix_variants(query) {
+                let mut pos = self.title_lower_bound(namespace, &prefix)?;
+                let mut found = 0;
+
+                while pos < self.header.article_count && found < limit {
+                    let entry = self.read_entry_by_title_pos(pos)?;
+                    if entry.namespace != namespace || !entry.title.starts_with(&prefix) {
+                        break;
+                    }
+                    pos += 1;
+
+                    if is_searchable_article(&entry) && !results.iter().any(|r| r.url == entry.url)
+                    {
                         results.push(entry);
-                        if results.len() >= limit {
-                            break;
-                        }
+                        found += 1;
                     }
                 }
             }
         }
 
+        results.sort_by(|a, b| a.title.cmp(&b.title));
+        results.truncate(limit);
         Ok(results)
     }
 
+    /// 제목으로 검색 (접두사 일치)
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<DirEntry>> {
+        self.search_prefix(query, limit)
+    }
+
     /// Fuzzy 검색 (오타 허용)
+    ///
+    /// 접두사 검색 결과가 없을 때만 전체 스캔하며, 스캔은 시간 제한 안에서 멈춥니다.
     pub fn search_fuzzy(&self, query: &str, limit: usize) -> Result<Vec<DirEntry>> {
-        // 먼저 정확한 검색 시도
+        // 먼저 접두사 검색 시도
         let exact_results = self.search(query, limit)?;
         if !exact_results.is_empty() {
             return Ok(exact_results);
         }
 
         // 결과 없으면 fuzzy 검색
+        let started = Instant::now();
         let mut results = Vec::new();
 
         for i in 0..self.header.article_count {
+            if started.elapsed() > FUZZY_SCAN_BUDGET {
+                tracing::debug!("Fuzzy 검색 시간 초과: {}개 엔트리 검사", i);
+                break;
+            }
+
             if let Ok(entry) = self.read_dir_entry(i) {
-                if (entry.namespace == 'A' || entry.namespace == 'C')
-                    && entry.entry_type == EntryType::Content
-                    && !entry.url.ends_with(".png")
-                    && !entry.url.ends_with(".jpg")
-                    && !entry.url.ends_with(".css")
-                    && !entry.url.ends_with(".js")
-                {
-                    // Edit distance 2까지 허용
-                    if fuzzy_match(query, &entry.title, 2) {
-                        results.push(entry);
-                        if results.len() >= limit {
-                            break;
-                        }
+                // Edit distance 2까지 허용
+                if is_searchable_article(&entry) && fuzzy_match(query, &entry.title, 2) {
+                    results.push(entry);
+                    if results.len() >= limit {
+                        break;
                     }
                 }
             }
         }
 
+        results.sort_by(|a, b| a.title.cmp(&b.title));
         Ok(results)
     }
 }
@@ -0,0 +1,383 @@
+//! ZIM 무결성 검사
+//!
+//! 싸구려 USB로 옮긴 ZIM은 조용히 깨지곤 합니다. 파일 끝의 MD5 체크섬을
+//! 확인하고, 모든 클러스터를 실제로 압축 해제해 보고, 읽을 수 없는
+//! 엔트리를 찾아냅니다. 손상된 파일에서도 패닉하지 않도록 모든 오프셋을
+//! 먼저 범위 검사합니다.
+
+use md5::{Digest, Md5};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+use super::{EntryType, ZimReader};
+
+/// 해시 계산 단위 (진행 상황 보고 간격)
+const HASH_CHUNK: usize = 8 * 1024 * 1024;
+
+/// 보고서에 담는 읽을 수 없는 엔트리 최대 개수
+const MAX_REPORTED_ENTRIES: usize = 1000;
+
+/// 검사 단계
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerifyPhase {
+    Checksum,
+    Clusters,
+    Entries,
+    Done,
+}
+
+/// 진행 상황
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyProgress {
+    pub phase: VerifyPhase,
+    pub processed: u64,
+    pub total: u64,
+}
+
+/// 검사 스레드와 상태 조회가 잠금 없이 나누는 진행 상황
+#[derive(Debug, Default)]
+pub struct VerifyCounter {
+    phase: AtomicU8,
+    processed: AtomicU64,
+    total: AtomicU64,
+}
+
+impl VerifyCounter {
+    /// 진행 상황 기록 (검사 스레드)
+    pub fn set(&self, progress: &VerifyProgress) {
+        self.phase.store(progress.phase as u8, Ordering::Relaxed);
+        self.processed.store(progress.processed, Ordering::Relaxed);
+        self.total.store(progress.total, Ordering::Relaxed);
+    }
+
+    /// 지금 진행 상황
+    pub fn get(&self) -> VerifyProgress {
+        let phase = match self.phase.load(Ordering::Relaxed) {
+            0 => VerifyPhase::Checksum,
+            1 => VerifyPhase::Clusters,
+            2 => VerifyPhase::Entries,
+            _ => VerifyPhase::Done,
+        };
+        VerifyProgress {
+            phase,
+            processed: self.processed.load(Ordering::Relaxed),
+            total: self.total.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// 읽을 수 없는 엔트리
+#[derive(Debug, Clone, Serialize)]
+pub struct UnreadableEntry {
+    pub index: u32,
+    pub namespace: String,
+    pub url: String,
+    pub reason: String,
+}
+
+/// 무결성 보고서
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntegrityReport {
+    /// 체크섬 일치 여부 (파일에 체크섬이 없으면 None)
+    pub checksum_ok: Option<bool>,
+    pub expected_md5: Option<String>,
+    pub actual_md5: Option<String>,
+    pub clusters_checked: u32,
+    pub bad_clusters: Vec<u32>,
+    pub entries_checked: u32,
+    pub unreadable_count: u32,
+    /// 앞쪽 최대 1000개만 기록
+    pub unreadable_entries: Vec<UnreadableEntry>,
+}
+
+impl IntegrityReport {
+    /// 문제 없음
+    pub fn is_ok(&self) -> bool {
+        self.checksum_ok != Some(false)
+            && self.bad_clusters.is_empty()
+            && self.unreadable_count == 0
+    }
+
+    fn push_unreadable(&mut self, index: u32, namespace: char, url: &str, reason: &str) {
+        self.unreadable_count += 1;
+        if self.unreadable_entries.len() < MAX_REPORTED_ENTRIES {
+            self.unreadable_entries.push(UnreadableEntry {
+                index,
+                namespace: namespace.to_string(),
+                url: url.to_string(),
+                reason: reason.to_string(),
+            });
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+impl ZimReader {
+    /// 전체 무결성 검사 (오래 걸리므로 블로킹 스레드에서 호출)
+    pub fn verify<F>(&self, mut on_progress: F) -> IntegrityReport
+    where
+        F: FnMut(VerifyProgress),
+    {
+        let mut report = IntegrityReport::default();
+        let file_len = self.mmap.len();
+
+        // 1. MD5 체크섬
+        let checksum_pos = self.header.checksum_pos as usize;
+        if checksum_pos > 0 && checksum_pos + 16 <= file_len {
+            let mut hasher = Md5::new();
+            let mut done = 0;
+            for chunk in self.mmap[..checksum_pos].chunks(HASH_CHUNK) {
+                hasher.update(chunk);
+                done += chunk.len();
+                on_progress(VerifyProgress {
+                    phase: VerifyPhase::Checksum,
+                    processed: done as u64,
+                    total: checksum_pos as u64,
+                });
+            }
+            let actual = hasher.finalize();
+            let expected = &self.mmap[checksum_pos..checksum_pos + 16];
+
+            report.checksum_ok = Some(actual.as_slice() == expected);
+            report.expected_md5 = Some(to_hex(expected));
+            report.actual_md5 = Some(to_hex(&actual));
+        }
+
+        // 2. 클러스터 압축 해제 (블롭 개수 기록)
+        let cluster_count = self.header.cluster_count;
+        let cluster_table_ok =
+            self.header.cluster_ptr_pos as usize + cluster_count as usize * 8 <= file_len;
+        let mut blob_counts: Vec<Option<u32>> = vec![None; cluster_count as usize];
+
+        for cluster in 0..cluster_count {
+            report.clusters_checked += 1;
+            match self.check_cluster(cluster, cluster_table_ok) {
+                Some(blobs) => blob_counts[cluster as usize] = Some(blobs),
+                None => report.bad_clusters.push(cluster),
+            }
+
+            if cluster % 256 == 0 || cluster + 1 == cluster_count {
+                on_progress(VerifyProgress {
+                    phase: VerifyPhase::Clusters,
+                    processed: cluster as u64 + 1,
+                    total: cluster_count as u64,
+                });
+            }
+        }
+
+        // 3. 디렉토리 엔트리
+        let article_count = self.header.article_count;
+        let url_table_ok =
+            self.header.url_ptr_pos as usize + article_count as usize * 8 <= file_len;
+
+        for index in 0..article_count {
+            report.entries_checked += 1;
+            self.check_entry(index, url_table_ok, &blob_counts, &mut report);
+
+            if index % 1024 == 0 || index + 1 == article_count {
+                on_progress(VerifyProgress {
+                    phase: VerifyPhase::Entries,
+                    processed: index as u64 + 1,
+                    total: article_count as u64,
+                });
+            }
+        }
+
+        on_progress(VerifyProgress {
+            phase: VerifyPhase::Done,
+            processed: 0,
+            total: 0,
+        });
+
+        report
+    }
+
+    /// 클러스터 하나 검사 (정상이면 블롭 개수)
+    fn check_cluster(&self, cluster: u32, table_ok: bool) -> Option<u32> {
+        if !table_ok {
+            return None;
+        }
+
+        let start = self.get_cluster_offset(cluster) as usize;
+        let end = if cluster + 1 < self.header.cluster_count {
+            self.get_cluster_offset(cluster + 1) as usize
+        } else {
+            self.header.checksum_pos as usize
+        };
+        if start >= end || end > self.mmap.len() {
+            return None;
+        }
+
+        let data = self.decompress_cluster_uncached(cluster).ok()?;
+        let offset_size = if self.cluster_is_extended(cluster) {
+            8
+        } else {
+            4
+        };
+        if data.len() < offset_size {
+            return None;
+        }
+
+        // 첫 오프셋 = 오프셋 테이블 크기
+        let first = if offset_size == 8 {
+            u64::from_le_bytes(data[..8].try_into().unwrap()) as usize
+        } else {
+            u32::from_le_bytes(data[..4].try_into().unwrap()) as usize
+        };
+        if first < offset_size || first > data.len() || first % offset_size != 0 {
+            return None;
+        }
+
+        // 오프셋은 단조 증가하고 데이터 안에 있어야 함
+        let mut prev = first;
+        for chunk in data[offset_size..first].chunks(offset_size) {
+            let off = if offset_size == 8 {
+                u64::from_le_bytes(chunk.try_into().unwrap()) as usize
+            } else {
+                u32::from_le_bytes(chunk.try_into().unwrap()) as usize
+            };
+            if off < prev || off > data.len() {
+                return None;
+            }
+            prev = off;
+        }
+
+        Some((first / offset_size - 1) as u32)
+    }
+
+    /// 엔트리 하나 검사
+    fn check_entry(
+        &self,
+        index: u32,
+        table_ok: bool,
+        blob_counts: &[Option<u32>],
+        report: &mut IntegrityReport,
+    ) {
+        if !table_ok {
+            report.push_unreadable(index, '?', "", "URL 포인터 테이블이 파일 밖을 가리킴");
+            return;
+        }
+
+        let offset = self.get_url_offset(index) as usize;
+        // 가장 긴 고정 헤더(16바이트) + URL/제목 종료 문자
+        if offset + 18 > self.mmap.len() {
+            report.push_unreadable(index, '?', "", "엔트리 오프셋이 파일 밖을 가리킴");
+            return;
+        }
+
+        let entry = match self.read_dir_entry(index) {
+            Ok(e) => e,
+            Err(e) => {
+                report.push_unreadable(index, '?', "", &e.to_string());
+                return;
+            }
+        };
+
+        match entry.entry_type {
+            EntryType::Content => {
+                let cluster = entry.cluster_number.unwrap_or(u32::MAX);
+                let blob = entry.blob_number.unwrap_or(u32::MAX);
+                match blob_counts.get(cluster as usize) {
+                    None => report.push_unreadable(
+                        index,
+                        entry.namespace,
+                        &entry.url,
+                        "존재하지 않는 클러스터",
+                    ),
+                    Some(None) => report.push_unreadable(
+                        index,
+                        entry.namespace,
+                        &entry.url,
+                        "손상된 클러스터",
+                    ),
+                    Some(Some(count)) if blob >= *count => report.push_unreadable(
+                        index,
+                        entry.namespace,
+                        &entry.url,
+                        "존재하지 않는 블롭",
+                    ),
+                    Some(Some(_)) => {}
+                }
+            }
+            EntryType::Redirect => {
+                if entry.redirect_index.unwrap_or(u32::MAX) >= self.header.article_count {
+                    report.push_unreadable(
+                        index,
+                        entry.namespace,
+                        &entry.url,
+                        "리다이렉트 대상이 범위를 벗어남",
+                    );
+                }
+            }
+            EntryType::Deleted => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::zim::tests::{build_test_zim, open_test_zim};
+
+    const ENTRIES: &[(char, &str, &str, &[u8])] = &[
+        ('C', "Apple", "Apple", b"<p>apple</p>"),
+        ('C', "Banana", "Banana", b"<p>banana</p>"),
+    ];
+
+    /// 빌더가 비워 둔 체크섬 자리에 실제 MD5 기록
+    fn with_checksum(mut bytes: Vec<u8>) -> Vec<u8> {
+        let pos = bytes.len() - 16;
+        let digest = Md5::digest(&bytes[..pos]);
+        bytes[pos..].copy_from_slice(&digest);
+        bytes
+    }
+
+    #[test]
+    fn test_verify_clean_file() {
+        let (_dir, zim) = open_test_zim(&with_checksum(build_test_zim(ENTRIES, 4)));
+
+        let mut phases = Vec::new();
+        let counter = VerifyCounter::default();
+        let report = zim.verify(|p| {
+            if phases.last() != Some(&p.phase) {
+                phases.push(p.phase);
+            }
+            counter.set(&p);
+            assert_eq!(counter.get().phase, p.phase);
+        });
+
+        assert!(report.is_ok());
+        assert_eq!(report.checksum_ok, Some(true));
+        assert_eq!(report.clusters_checked, 1);
+        assert_eq!(report.entries_checked, 2);
+        assert_eq!(
+            phases,
+            vec![
+                VerifyPhase::Checksum,
+                VerifyPhase::Clusters,
+                VerifyPhase::Entries,
+                VerifyPhase::Done
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_corrupt_cluster() {
+        let mut bytes = with_checksum(build_test_zim(ENTRIES, 5));
+        // zstd 프레임 매직 넘버 손상
+        let magic = [0x28, 0xB5, 0x2F, 0xFD];
+        let pos = bytes.windows(4).position(|w| w == magic).unwrap();
+        bytes[pos] ^= 0xFF;
+        let (_dir, zim) = open_test_zim(&bytes);
+
+        let report = zim.verify(|_| {});
+        assert_eq!(report.checksum_ok, Some(false));
+        assert_eq!(report.bad_clusters, vec![0]);
+        assert_eq!(report.unreadable_count, 2);
+        assert_eq!(report.unreadable_entries[0].url, "Apple");
+    }
+}